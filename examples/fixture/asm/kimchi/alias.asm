@@ -0,0 +1,15 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (6,0)
+(1,0) -> (4,1) -> (5,0)
+(2,0) -> (5,1)
+(2,2) -> (3,0)
+(3,2) -> (4,0)
+(5,2) -> (6,1)
@@ -0,0 +1,167 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<4,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<8,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<16,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<32,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<64,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<128,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,0,-1,0,3>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<4,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<8,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<16,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<32,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<64,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<128,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,0,-1,0,1000>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (83,0)
+(1,0) -> (40,1) -> (41,0)
+(2,0) -> (3,0) -> (9,0)
+(2,2) -> (3,1)
+(3,2) -> (4,0)
+(5,0) -> (6,0) -> (8,0)
+(5,2) -> (6,1)
+(6,2) -> (7,0)
+(8,2) -> (9,1)
+(9,2) -> (14,0)
+(10,0) -> (11,0) -> (13,0)
+(10,2) -> (11,1)
+(11,2) -> (12,0)
+(13,2) -> (14,1)
+(14,2) -> (19,0)
+(15,0) -> (16,0) -> (18,0)
+(15,2) -> (16,1)
+(16,2) -> (17,0)
+(18,2) -> (19,1)
+(19,2) -> (24,0)
+(20,0) -> (21,0) -> (23,0)
+(20,2) -> (21,1)
+(21,2) -> (22,0)
+(23,2) -> (24,1)
+(24,2) -> (29,0)
+(25,0) -> (26,0) -> (28,0)
+(25,2) -> (26,1)
+(26,2) -> (27,0)
+(28,2) -> (29,1)
+(29,2) -> (34,0)
+(30,0) -> (31,0) -> (33,0)
+(30,2) -> (31,1)
+(31,2) -> (32,0)
+(33,2) -> (34,1)
+(34,2) -> (39,0)
+(35,0) -> (36,0) -> (38,0)
+(35,2) -> (36,1)
+(36,2) -> (37,0)
+(38,2) -> (39,1)
+(39,2) -> (40,0)
+(41,2) -> (80,1) -> (81,0)
+(42,0) -> (43,0) -> (49,0)
+(42,2) -> (43,1)
+(43,2) -> (44,0)
+(45,0) -> (46,0) -> (48,0)
+(45,2) -> (46,1)
+(46,2) -> (47,0)
+(48,2) -> (49,1)
+(49,2) -> (54,0)
+(50,0) -> (51,0) -> (53,0)
+(50,2) -> (51,1)
+(51,2) -> (52,0)
+(53,2) -> (54,1)
+(54,2) -> (59,0)
+(55,0) -> (56,0) -> (58,0)
+(55,2) -> (56,1)
+(56,2) -> (57,0)
+(58,2) -> (59,1)
+(59,2) -> (64,0)
+(60,0) -> (61,0) -> (63,0)
+(60,2) -> (61,1)
+(61,2) -> (62,0)
+(63,2) -> (64,1)
+(64,2) -> (69,0)
+(65,0) -> (66,0) -> (68,0)
+(65,2) -> (66,1)
+(66,2) -> (67,0)
+(68,2) -> (69,1)
+(69,2) -> (74,0)
+(70,0) -> (71,0) -> (73,0)
+(70,2) -> (71,1)
+(71,2) -> (72,0)
+(73,2) -> (74,1)
+(74,2) -> (79,0)
+(75,0) -> (76,0) -> (78,0)
+(75,2) -> (76,1)
+(76,2) -> (77,0)
+(78,2) -> (79,1)
+(79,2) -> (80,0)
+(81,2) -> (82,0)
+(82,2) -> (83,1)
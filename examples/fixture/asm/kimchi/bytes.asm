@@ -0,0 +1,20 @@
+@ noname.0.7.0
+
+c0 = 1835626081
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,0,0,-109>
+DoubleGeneric<1,0,0,0,-105>
+DoubleGeneric<1,0,0,0,-110>
+DoubleGeneric<1,0,0,0,-97>
+DoubleGeneric<1,0,-1,0,c0>
+DoubleGeneric<1,-1>
+(0,0) -> (10,0)
+(1,0) -> (5,0)
+(2,0) -> (6,0)
+(3,0) -> (7,0)
+(4,0) -> (8,0)
+(9,2) -> (10,1)
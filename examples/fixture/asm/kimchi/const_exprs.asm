@@ -0,0 +1,32 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,5>
+DoubleGeneric<1,0,-1,0,10>
+DoubleGeneric<1,0,-1,0,7>
+DoubleGeneric<1,0,0,0,-5>
+DoubleGeneric<1,0,0,0,-10>
+DoubleGeneric<1,0,0,0,-7>
+DoubleGeneric<1,0,0,0,-2>
+DoubleGeneric<1>
+DoubleGeneric<1,0,0,0,-1>
+Lookup
+Lookup
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,7>
+DoubleGeneric<1,-1>
+(0,0) -> (15,0)
+(1,0) -> (2,0)
+(2,2) -> (3,0)
+(3,2) -> (4,0)
+(4,2) -> (13,0)
+(5,0) -> (11,2) -> (12,4) -> (12,6)
+(6,0) -> (11,4)
+(7,0) -> (11,6)
+(8,0) -> (11,0) -> (11,5) -> (12,0)
+(9,0) -> (11,1) -> (12,3) -> (12,5)
+(10,0) -> (11,3)
+(12,2) -> (13,1)
+(13,2) -> (14,0)
+(14,2) -> (15,1)
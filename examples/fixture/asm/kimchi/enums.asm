@@ -0,0 +1,210 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,0,0,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,0,0,-2>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,2>
+DoubleGeneric<1,-1>
+(0,0) -> (113,0)
+(1,0) -> (5,0) -> (14,0) -> (26,0) -> (42,0) -> (50,0) -> (59,0) -> (77,0) -> (89,0)
+(2,0) -> (35,1) -> (68,0)
+(3,0) -> (23,1) -> (37,1) -> (71,0) -> (101,0)
+(4,0) -> (6,0) -> (43,0) -> (90,0)
+(5,1) -> (6,1)
+(6,2) -> (7,1) -> (11,1)
+(7,2) -> (10,0)
+(8,0) -> (11,0) -> (22,0)
+(8,1) -> (9,0)
+(9,2) -> (10,1)
+(11,2) -> (12,0)
+(13,0) -> (15,0) -> (51,0)
+(14,1) -> (15,1)
+(15,2) -> (16,1) -> (20,1)
+(16,2) -> (19,0)
+(17,0) -> (20,0) -> (22,1) -> (23,0)
+(17,1) -> (18,0)
+(18,2) -> (19,1)
+(20,2) -> (21,0)
+(22,2) -> (34,0)
+(23,2) -> (24,0)
+(25,0) -> (27,0) -> (60,0) -> (78,0)
+(26,1) -> (27,1)
+(27,2) -> (28,1) -> (32,1)
+(28,2) -> (31,0)
+(29,0) -> (32,0) -> (34,1) -> (35,0) -> (37,0)
+(29,1) -> (30,0)
+(30,2) -> (31,1)
+(32,2) -> (33,0)
+(34,2) -> (39,0)
+(35,2) -> (36,0)
+(37,2) -> (38,0)
+(39,1) -> (40,0)
+(40,2) -> (41,0)
+(42,1) -> (43,1)
+(43,2) -> (44,1) -> (48,1)
+(44,2) -> (47,0)
+(45,0) -> (48,0) -> (58,0) -> (71,1) -> (72,0)
+(45,1) -> (46,0)
+(46,2) -> (47,1)
+(48,2) -> (49,0)
+(50,1) -> (51,1)
+(51,2) -> (52,1) -> (56,1)
+(52,2) -> (55,0)
+(53,0) -> (56,0) -> (58,1) -> (68,1) -> (69,0)
+(53,1) -> (54,0)
+(54,2) -> (55,1)
+(56,2) -> (57,0)
+(58,2) -> (67,0)
+(59,1) -> (60,1)
+(60,2) -> (61,1) -> (65,1)
+(61,2) -> (64,0)
+(62,0) -> (65,0) -> (67,1)
+(62,1) -> (63,0)
+(63,2) -> (64,1)
+(65,2) -> (66,0)
+(68,2) -> (74,1)
+(69,1) -> (70,0)
+(71,2) -> (75,0)
+(72,1) -> (73,0)
+(73,2) -> (74,0)
+(74,2) -> (75,1)
+(75,2) -> (76,1)
+(76,2) -> (88,0) -> (106,0)
+(77,1) -> (78,1)
+(78,2) -> (79,1) -> (83,1)
+(79,2) -> (82,0)
+(80,0) -> (83,0) -> (85,0) -> (87,0) -> (107,1) -> (108,0)
+(80,1) -> (81,0)
+(81,2) -> (82,1)
+(83,2) -> (84,0)
+(85,1) -> (86,0)
+(86,2) -> (87,1) -> (100,0) -> (102,0)
+(88,2) -> (107,0)
+(89,1) -> (90,1)
+(90,2) -> (91,1) -> (95,1)
+(91,2) -> (94,0)
+(92,0) -> (95,0) -> (97,0) -> (99,0) -> (100,1) -> (103,1) -> (104,0)
+(92,1) -> (93,0)
+(93,2) -> (94,1)
+(95,2) -> (96,0)
+(97,1) -> (98,0)
+(98,2) -> (99,1) -> (102,1)
+(101,2) -> (103,0)
+(103,2) -> (106,1)
+(104,1) -> (105,0)
+(106,2) -> (110,1)
+(107,2) -> (111,0)
+(108,1) -> (109,0)
+(109,2) -> (110,0)
+(110,2) -> (111,1)
+(111,2) -> (112,0)
+(112,2) -> (113,1)
@@ -0,0 +1,31 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,0,-1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (14,0)
+(1,0) -> (4,1) -> (7,1) -> (10,1)
+(2,0) -> (5,0) -> (8,0)
+(2,1) -> (5,1) -> (8,1) -> (11,0)
+(2,2) -> (3,0)
+(3,1) -> (6,1) -> (9,1) -> (12,0)
+(3,2) -> (4,0)
+(5,2) -> (6,0)
+(6,2) -> (7,0)
+(8,2) -> (9,0)
+(9,2) -> (10,0)
+(11,2) -> (13,0)
+(12,2) -> (13,1)
+(13,2) -> (14,1)
@@ -0,0 +1,30 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (3,1) -> (9,0) -> (9,1) -> (11,0) -> (11,1) -> (12,0) -> (12,1)
+(1,0) -> (4,0) -> (4,1)
+(1,1) -> (5,0) -> (5,1)
+(1,2) -> (2,0)
+(2,1) -> (6,0) -> (6,1)
+(2,2) -> (3,0)
+(4,2) -> (7,0)
+(5,2) -> (7,1)
+(6,2) -> (8,1)
+(7,2) -> (8,0)
+(8,2) -> (10,0)
+(9,2) -> (10,1)
+(11,2) -> (13,0)
+(12,2) -> (13,1)
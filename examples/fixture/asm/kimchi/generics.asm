@@ -0,0 +1,14 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (3,1) -> (4,1) -> (5,0) -> (5,1)
+(1,2) -> (2,0)
+(2,2) -> (3,0) -> (4,0)
+(4,2) -> (6,0)
+(5,2) -> (6,1)
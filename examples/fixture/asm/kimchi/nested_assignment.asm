@@ -0,0 +1,289 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,0,0,-2>
+DoubleGeneric<1,0,0,0,-1>
+Lookup
+Lookup
+DoubleGeneric<1,0,0,0,-3>
+Lookup
+Lookup
+DoubleGeneric<1,0,0,0,-4>
+Lookup
+Lookup
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,0,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,0,0,-5>
+Lookup
+Lookup
+DoubleGeneric<1,0,0,0,-6>
+Lookup
+Lookup
+DoubleGeneric<1,0,0,0,-7>
+Lookup
+Lookup
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,0,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,0,0,-2>
+DoubleGeneric<1,-1>
+(0,0) -> (160,0)
+(1,0) -> (6,1) -> (9,1) -> (12,1) -> (14,0) -> (22,0) -> (31,0) -> (73,1) -> (76,1) -> (79,1) -> (81,0) -> (89,0) -> (98,0)
+(2,0) -> (5,1) -> (5,2) -> (5,4) -> (6,3) -> (6,4) -> (6,5) -> (6,6) -> (8,1) -> (8,2) -> (8,4) -> (8,6) -> (9,3) -> (9,4) -> (9,5) -> (9,6) -> (11,1) -> (11,2) -> (11,4) -> (12,3) -> (12,4) -> (12,5) -> (12,6) -> (15,0) -> (72,1) -> (73,3) -> (73,5) -> (75,1) -> (75,2) -> (75,4) -> (75,6) -> (76,3) -> (76,4) -> (76,5) -> (76,6) -> (78,1) -> (79,3) -> (79,5) -> (82,0)
+(3,0) -> (5,0) -> (5,5) -> (6,0) -> (8,5) -> (11,5) -> (32,0) -> (72,5) -> (75,5) -> (78,5) -> (99,0)
+(4,0) -> (5,3) -> (8,3) -> (11,3) -> (11,6) -> (23,0) -> (72,3) -> (75,3) -> (78,3) -> (90,0)
+(5,6) -> (13,1) -> (41,1) -> (62,1)
+(6,2) -> (13,0)
+(7,0) -> (8,0) -> (9,0)
+(10,0) -> (11,0) -> (12,0)
+(13,2) -> (43,0) -> (51,0) -> (59,0)
+(14,1) -> (15,1)
+(15,2) -> (16,1) -> (20,1)
+(16,2) -> (19,0)
+(17,0) -> (20,0) -> (30,0) -> (43,1) -> (44,0) -> (46,0) -> (48,1) -> (49,0)
+(17,1) -> (18,0)
+(18,2) -> (19,1)
+(20,2) -> (21,0)
+(22,1) -> (23,1)
+(23,2) -> (24,1) -> (28,1)
+(24,2) -> (27,0)
+(25,0) -> (28,0) -> (30,1) -> (51,1) -> (52,0) -> (54,0) -> (56,1) -> (57,0)
+(25,1) -> (26,0)
+(26,2) -> (27,1)
+(28,2) -> (29,0)
+(30,2) -> (39,0)
+(31,1) -> (32,1)
+(32,2) -> (33,1) -> (37,1)
+(33,2) -> (36,0)
+(34,0) -> (37,0) -> (39,1) -> (41,0) -> (42,0) -> (59,1) -> (60,0) -> (64,0) -> (66,1) -> (67,0)
+(34,1) -> (35,0)
+(35,2) -> (36,1)
+(37,2) -> (38,0)
+(39,2) -> (40,0)
+(42,2) -> (48,0) -> (56,0) -> (66,0)
+(43,2) -> (72,2) -> (73,4) -> (73,6) -> (108,1) -> (121,1)
+(44,1) -> (45,0)
+(46,1) -> (47,0)
+(48,2) -> (78,2) -> (79,4) -> (79,6) -> (113,1) -> (129,1)
+(49,1) -> (50,0)
+(51,2) -> (72,4) -> (109,1) -> (134,1)
+(52,1) -> (53,0)
+(54,1) -> (55,0)
+(56,2) -> (78,4) -> (114,1) -> (142,1)
+(57,1) -> (58,0)
+(59,2) -> (63,0)
+(60,1) -> (61,0)
+(61,2) -> (62,0)
+(62,2) -> (63,1)
+(63,2) -> (72,6) -> (111,1) -> (147,1)
+(64,1) -> (65,0)
+(66,2) -> (70,0)
+(67,1) -> (68,0)
+(68,2) -> (69,0)
+(69,2) -> (70,1)
+(70,2) -> (78,6) -> (116,1) -> (155,1)
+(71,0) -> (72,0) -> (73,0)
+(74,0) -> (75,0) -> (76,0)
+(77,0) -> (78,0) -> (79,0)
+(79,2) -> (80,0)
+(80,2) -> (123,0) -> (136,0) -> (149,0)
+(81,1) -> (82,1)
+(82,2) -> (83,1) -> (87,1)
+(83,2) -> (86,0)
+(84,0) -> (87,0) -> (97,0) -> (108,0) -> (113,0) -> (118,1) -> (119,0) -> (123,1) -> (124,0) -> (126,1) -> (127,0)
+(84,1) -> (85,0)
+(85,2) -> (86,1)
+(87,2) -> (88,0)
+(89,1) -> (90,1)
+(90,2) -> (91,1) -> (95,1)
+(91,2) -> (94,0)
+(92,0) -> (95,0) -> (97,1) -> (109,0) -> (114,0) -> (131,1) -> (132,0) -> (136,1) -> (137,0) -> (139,1) -> (140,0)
+(92,1) -> (93,0)
+(93,2) -> (94,1)
+(95,2) -> (96,0)
+(97,2) -> (106,0)
+(98,1) -> (99,1)
+(99,2) -> (100,1) -> (104,1)
+(100,2) -> (103,0)
+(101,0) -> (104,0) -> (106,1) -> (111,0) -> (116,0) -> (144,1) -> (145,0) -> (149,1) -> (150,0) -> (152,1) -> (153,0)
+(101,1) -> (102,0)
+(102,2) -> (103,1)
+(104,2) -> (105,0)
+(106,2) -> (107,0)
+(108,2) -> (110,0)
+(109,2) -> (110,1)
+(110,2) -> (112,0)
+(111,2) -> (112,1)
+(112,2) -> (118,0) -> (131,0) -> (144,0)
+(113,2) -> (115,0)
+(114,2) -> (115,1)
+(115,2) -> (117,0)
+(116,2) -> (117,1)
+(117,2) -> (126,0) -> (139,0) -> (152,0)
+(118,2) -> (122,0)
+(119,1) -> (120,0)
+(120,2) -> (121,0)
+(121,2) -> (122,1)
+(122,2) -> (157,0)
+(124,1) -> (125,0)
+(126,2) -> (130,0)
+(127,1) -> (128,0)
+(128,2) -> (129,0)
+(129,2) -> (130,1)
+(131,2) -> (135,0)
+(132,1) -> (133,0)
+(133,2) -> (134,0)
+(134,2) -> (135,1)
+(135,2) -> (157,1)
+(137,1) -> (138,0)
+(139,2) -> (143,0)
+(140,1) -> (141,0)
+(141,2) -> (142,0)
+(142,2) -> (143,1)
+(144,2) -> (148,0)
+(145,1) -> (146,0)
+(146,2) -> (147,0)
+(147,2) -> (148,1)
+(148,2) -> (158,1)
+(149,2) -> (159,0)
+(150,1) -> (151,0)
+(152,2) -> (156,0)
+(153,1) -> (154,0)
+(154,2) -> (155,0)
+(155,2) -> (156,1)
+(157,2) -> (158,0)
+(158,2) -> (160,1)
@@ -0,0 +1,29 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<2,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (13,0)
+(1,0) -> (6,0) -> (7,0)
+(2,2) -> (8,0)
+(3,2) -> (8,1)
+(4,2) -> (9,1)
+(5,2) -> (10,1)
+(6,2) -> (11,1)
+(7,2) -> (12,1)
+(8,2) -> (9,0)
+(9,2) -> (10,0)
+(10,2) -> (11,0)
+(11,2) -> (12,0)
+(12,2) -> (13,1)
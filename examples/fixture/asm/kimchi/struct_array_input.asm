@@ -0,0 +1,39 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,0,0,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (19,0)
+(1,0) -> (14,0)
+(2,0) -> (15,1)
+(3,0) -> (7,0) -> (8,0) -> (13,0)
+(4,0) -> (16,0)
+(5,0) -> (17,1)
+(6,0) -> (10,0) -> (11,0)
+(7,2) -> (8,1)
+(8,2) -> (9,0)
+(10,2) -> (11,1)
+(11,2) -> (12,0)
+(14,1) -> (16,1)
+(14,2) -> (15,0)
+(15,2) -> (18,0)
+(16,2) -> (17,0)
+(17,2) -> (18,1)
+(18,2) -> (19,1)
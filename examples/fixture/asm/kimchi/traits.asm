@@ -0,0 +1,37 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<3,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<7,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<7,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<7,0,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (16,0)
+(1,0) -> (3,0)
+(2,0) -> (4,1)
+(3,2) -> (4,0)
+(4,2) -> (5,0)
+(5,2) -> (9,0)
+(6,0) -> (10,0)
+(6,2) -> (7,1)
+(7,0) -> (11,0)
+(7,2) -> (8,0)
+(8,2) -> (9,1)
+(9,2) -> (15,0)
+(10,2) -> (11,1)
+(11,2) -> (14,0)
+(12,2) -> (13,1)
+(13,2) -> (14,1)
+(14,2) -> (15,1)
+(15,2) -> (16,1)
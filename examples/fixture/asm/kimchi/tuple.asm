@@ -0,0 +1,13 @@
+@ noname.0.7.0
+
+DoubleGeneric<1>
+DoubleGeneric<1>
+DoubleGeneric<1,0,-1,0,1>
+DoubleGeneric<0,0,-1,1>
+DoubleGeneric<1,1,-1>
+DoubleGeneric<1,-1>
+(0,0) -> (5,0)
+(1,0) -> (2,0)
+(2,2) -> (3,0)
+(3,2) -> (4,0)
+(4,2) -> (5,1)
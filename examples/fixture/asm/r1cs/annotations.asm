@@ -0,0 +1,37 @@
+@ noname.0.7.0
+
+v_4 == (v_3) * (v_3 + -1)
+0 == (v_4) * (1)
+v_6 == (v_5) * (v_5 + -1)
+0 == (v_6) * (1)
+v_8 == (v_7) * (v_7 + -1)
+0 == (v_8) * (1)
+v_10 == (v_9) * (v_9 + -1)
+0 == (v_10) * (1)
+v_12 == (v_11) * (v_11 + -1)
+0 == (v_12) * (1)
+v_14 == (v_13) * (v_13 + -1)
+0 == (v_14) * (1)
+v_16 == (v_15) * (v_15 + -1)
+0 == (v_16) * (1)
+v_18 == (v_17) * (v_17 + -1)
+0 == (v_18) * (1)
+v_2 == (v_3 + 2 * v_5 + 4 * v_7 + 8 * v_9 + 16 * v_11 + 32 * v_13 + 64 * v_15 + 128 * v_17) * (1)
+v_21 == (v_20) * (v_20 + -1)
+0 == (v_21) * (1)
+v_23 == (v_22) * (v_22 + -1)
+0 == (v_23) * (1)
+v_25 == (v_24) * (v_24 + -1)
+0 == (v_25) * (1)
+v_27 == (v_26) * (v_26 + -1)
+0 == (v_27) * (1)
+v_29 == (v_28) * (v_28 + -1)
+0 == (v_29) * (1)
+v_31 == (v_30) * (v_30 + -1)
+0 == (v_31) * (1)
+v_33 == (v_32) * (v_32 + -1)
+0 == (v_33) * (1)
+v_35 == (v_34) * (v_34 + -1)
+0 == (v_35) * (1)
+v_2 + 3 == (v_20 + 2 * v_22 + 4 * v_24 + 8 * v_26 + 16 * v_28 + 32 * v_30 + 64 * v_32 + 128 * v_34) * (1)
+v_2 + v_19 + 1003 == (v_1) * (1)
@@ -0,0 +1,7 @@
+@ noname.0.7.0
+
+109 == (v_2) * (1)
+105 == (v_3) * (1)
+110 == (v_4) * (1)
+97 == (v_5) * (1)
+v_6 + 1835626081 == (v_1) * (1)
@@ -0,0 +1,19 @@
+@ noname.0.7.0
+
+0 == (v_4) * (1)
+v_6 == (v_5) * (-1 * v_3 + v_4)
+-1 * v_7 + 1 == (v_6) * (1)
+v_8 == (v_7) * (-1 * v_3 + v_4)
+0 == (v_8) * (1)
+1 == (v_9) * (1)
+v_11 == (v_10) * (-1 * v_3 + v_9)
+-1 * v_12 + 1 == (v_11) * (1)
+v_13 == (v_12) * (-1 * v_3 + v_9)
+0 == (v_13) * (1)
+2 == (v_14) * (1)
+v_16 == (v_15) * (-1 * v_3 + v_14)
+-1 * v_17 + 1 == (v_16) * (1)
+v_18 == (v_17) * (-1 * v_3 + v_14)
+0 == (v_18) * (1)
+1 == (v_7 + v_12 + v_17) * (1)
+v_2 + 5 * v_7 + 10 * v_12 + 7 * v_17 + 29 == (v_1) * (1)
@@ -0,0 +1,58 @@
+@ noname.0.7.0
+
+0 == (v_5) * (1)
+v_7 == (v_6) * (-1 * v_2 + v_5)
+-1 * v_8 + 1 == (v_7) * (1)
+v_9 == (v_8) * (-1 * v_2 + v_5)
+0 == (v_9) * (1)
+1 == (v_10) * (1)
+v_12 == (v_11) * (-1 * v_2 + v_10)
+-1 * v_13 + 1 == (v_12) * (1)
+v_14 == (v_13) * (-1 * v_2 + v_10)
+0 == (v_14) * (1)
+v_15 == (v_13) * (v_4)
+0 == (v_15) * (1)
+2 == (v_16) * (1)
+v_18 == (v_17) * (-1 * v_2 + v_16)
+-1 * v_19 + 1 == (v_18) * (1)
+v_20 == (v_19) * (-1 * v_2 + v_16)
+0 == (v_20) * (1)
+v_21 == (v_19) * (v_3)
+0 == (v_21) * (1)
+v_22 == (v_19) * (v_4)
+0 == (v_22) * (1)
+0 == (-1 * v_8 + -1 * v_13 + -1 * v_19 + 1) * (1)
+0 == (v_24) * (1)
+v_26 == (v_25) * (-1 * v_2 + v_24)
+-1 * v_27 + 1 == (v_26) * (1)
+v_28 == (v_27) * (-1 * v_2 + v_24)
+0 == (v_28) * (1)
+1 == (v_29) * (1)
+v_31 == (v_30) * (-1 * v_2 + v_29)
+-1 * v_32 + 1 == (v_31) * (1)
+v_33 == (v_32) * (-1 * v_2 + v_29)
+0 == (v_33) * (1)
+2 == (v_34) * (1)
+v_36 == (v_35) * (-1 * v_2 + v_34)
+-1 * v_37 + 1 == (v_36) * (1)
+v_38 == (v_37) * (-1 * v_2 + v_34)
+0 == (v_38) * (1)
+v_39 == (v_3) * (v_32)
+v_40 == (v_4) * (v_27)
+v_41 == (-1 * v_27 + 1) * (v_39)
+2 == (v_42) * (1)
+v_44 == (v_43) * (-1 * v_2 + v_42)
+-1 * v_45 + 1 == (v_44) * (1)
+v_46 == (v_45) * (-1 * v_2 + v_42)
+0 == (v_46) * (1)
+0 == (v_47) * (1)
+v_49 == (v_48) * (-1 * v_2 + v_47)
+-1 * v_50 + 1 == (v_49) * (1)
+v_51 == (v_50) * (-1 * v_2 + v_47)
+0 == (v_51) * (1)
+v_52 == (-1 * v_45 + 1) * (v_50)
+v_53 == (-1 * v_45 + 1) * (-1 * v_50 + 1)
+v_54 == (v_4 + 1) * (v_50)
+v_55 == (v_23 + v_40 + v_41 + 1) * (v_45)
+v_56 == (-1 * v_45 + 1) * (v_23 + v_40 + v_41 + v_54)
+v_55 + v_56 + 2 == (v_1) * (1)
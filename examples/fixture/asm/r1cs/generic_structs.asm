@@ -0,0 +1,5 @@
+@ noname.0.7.0
+
+v_1 == (v_2 + v_3 + v_4) * (1)
+2 * v_1 == (2 * v_2 + 2 * v_3 + 2 * v_4) * (1)
+2 * v_1 == (2 * v_1) * (1)
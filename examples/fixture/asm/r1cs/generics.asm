@@ -0,0 +1,4 @@
+@ noname.0.7.0
+
+v_1 == (v_2 + v_3 + v_4) * (1)
+2 * v_1 == (v_1 + v_2 + v_3 + v_4) * (1)
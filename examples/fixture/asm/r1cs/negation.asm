@@ -0,0 +1,3 @@
+@ noname.0.7.0
+
+1 == (v_1 + -1 * v_2) * (1)
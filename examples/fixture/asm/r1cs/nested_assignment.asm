@@ -0,0 +1,104 @@
+@ noname.0.7.0
+
+0 == (v_4) * (1)
+v_6 == (v_5) * (-1 * v_2 + v_4)
+-1 * v_7 + 1 == (v_6) * (1)
+v_8 == (v_7) * (-1 * v_2 + v_4)
+0 == (v_8) * (1)
+1 == (v_9) * (1)
+v_11 == (v_10) * (-1 * v_2 + v_9)
+-1 * v_12 + 1 == (v_11) * (1)
+v_13 == (v_12) * (-1 * v_2 + v_9)
+0 == (v_13) * (1)
+2 == (v_14) * (1)
+v_16 == (v_15) * (-1 * v_2 + v_14)
+-1 * v_17 + 1 == (v_16) * (1)
+v_18 == (v_17) * (-1 * v_2 + v_14)
+0 == (v_18) * (1)
+1 == (v_7 + v_12 + v_17) * (1)
+v_19 == (v_17) * (v_3)
+0 == (v_20) * (1)
+v_22 == (v_21) * (-1 * v_2 + v_20)
+-1 * v_23 + 1 == (v_22) * (1)
+v_24 == (v_23) * (-1 * v_2 + v_20)
+0 == (v_24) * (1)
+1 == (v_25) * (1)
+v_27 == (v_26) * (-1 * v_2 + v_25)
+-1 * v_28 + 1 == (v_27) * (1)
+v_29 == (v_28) * (-1 * v_2 + v_25)
+0 == (v_29) * (1)
+2 == (v_30) * (1)
+v_32 == (v_31) * (-1 * v_2 + v_30)
+-1 * v_33 + 1 == (v_32) * (1)
+v_34 == (v_33) * (-1 * v_2 + v_30)
+0 == (v_34) * (1)
+1 == (v_23 + v_28 + v_33) * (1)
+v_35 == (v_33) * (v_3)
+v_36 == (v_3 + v_19) * (v_23)
+v_37 == (v_33) * (v_23)
+v_38 == (v_3 + v_19) * (v_28)
+v_39 == (v_33) * (v_28)
+v_40 == (v_3 + v_19) * (v_33)
+v_41 == (-1 * v_33 + 1) * (v_3)
+v_42 == (v_33) * (v_33)
+0 == (v_43) * (1)
+v_45 == (v_44) * (-1 * v_2 + v_43)
+-1 * v_46 + 1 == (v_45) * (1)
+v_47 == (v_46) * (-1 * v_2 + v_43)
+0 == (v_47) * (1)
+1 == (v_48) * (1)
+v_50 == (v_49) * (-1 * v_2 + v_48)
+-1 * v_51 + 1 == (v_50) * (1)
+v_52 == (v_51) * (-1 * v_2 + v_48)
+0 == (v_52) * (1)
+2 == (v_53) * (1)
+v_55 == (v_54) * (-1 * v_2 + v_53)
+-1 * v_56 + 1 == (v_55) * (1)
+v_57 == (v_56) * (-1 * v_2 + v_53)
+0 == (v_57) * (1)
+1 == (v_46 + v_51 + v_56) * (1)
+v_58 == (v_46) * (v_36)
+v_59 == (v_51) * (v_38)
+v_60 == (v_56) * (v_40 + v_41)
+v_61 == (v_46) * (v_37)
+v_62 == (v_51) * (v_39)
+v_63 == (v_56) * (-1 * v_33 + v_42 + 1)
+0 == (v_64) * (1)
+v_66 == (v_65) * (-1 * v_2 + v_64)
+-1 * v_67 + 1 == (v_66) * (1)
+v_68 == (v_67) * (-1 * v_2 + v_64)
+0 == (v_68) * (1)
+1 == (v_69) * (1)
+v_71 == (v_70) * (-1 * v_2 + v_69)
+-1 * v_72 + 1 == (v_71) * (1)
+v_73 == (v_72) * (-1 * v_2 + v_69)
+0 == (v_73) * (1)
+2 == (v_74) * (1)
+v_76 == (v_75) * (-1 * v_2 + v_74)
+-1 * v_77 + 1 == (v_76) * (1)
+v_78 == (v_77) * (-1 * v_2 + v_74)
+0 == (v_78) * (1)
+1 == (v_67 + v_72 + v_77) * (1)
+v_79 == (v_67) * (v_36)
+v_80 == (v_72) * (v_38)
+v_81 == (v_77) * (v_40 + v_41)
+v_82 == (v_67) * (v_37)
+v_83 == (v_72) * (v_39)
+v_84 == (v_77) * (-1 * v_33 + v_42 + 1)
+v_85 == (v_79 + v_80 + v_81) * (v_67)
+v_86 == (-1 * v_67 + 1) * (v_36)
+v_87 == (v_61 + v_62 + v_63 + 1) * (v_67)
+v_88 == (v_82 + v_83 + v_84) * (v_67)
+v_89 == (-1 * v_67 + 1) * (v_37)
+v_90 == (v_79 + v_80 + v_81) * (v_72)
+v_91 == (-1 * v_72 + 1) * (v_38)
+v_92 == (v_61 + v_62 + v_63 + 1) * (v_72)
+v_93 == (v_82 + v_83 + v_84) * (v_72)
+v_94 == (-1 * v_72 + 1) * (v_39)
+v_95 == (v_79 + v_80 + v_81) * (v_77)
+v_96 == (-1 * v_77 + 1) * (v_40 + v_41)
+v_97 == (v_61 + v_62 + v_63 + 1) * (v_77)
+v_98 == (v_82 + v_83 + v_84) * (v_77)
+v_99 == (-1 * v_77 + 1) * (-1 * v_33 + v_42 + 1)
+2 == (v_97) * (1)
+v_85 + v_86 + v_90 + v_91 + v_95 + v_96 == (v_1) * (1)
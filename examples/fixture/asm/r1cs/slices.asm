@@ -0,0 +1,3 @@
+@ noname.0.7.0
+
+4 * v_2 + 2 * v_3 + 2 * v_4 + 2 * v_5 + 2 * v_6 == (v_1) * (1)
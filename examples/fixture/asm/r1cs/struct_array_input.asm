@@ -0,0 +1,10 @@
+@ noname.0.7.0
+
+v_8 == (v_4) * (v_4 + -1)
+0 == (v_8) * (1)
+v_9 == (v_7) * (v_7 + -1)
+0 == (v_9) * (1)
+1 == (v_4) * (1)
+v_11 == (v_2) * (v_10)
+v_12 == (v_5) * (v_10)
+v_3 + v_6 + v_11 + v_12 == (v_1) * (1)
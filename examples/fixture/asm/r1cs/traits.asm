@@ -0,0 +1,3 @@
+@ noname.0.7.0
+
+3 * v_2 + v_3 + 2 * v_4 + 14 * v_5 + v_6 + 7 * v_7 + 2 == (v_1) * (1)
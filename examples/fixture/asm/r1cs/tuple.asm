@@ -0,0 +1,4 @@
+@ noname.0.7.0
+
+v_5 == (v_2 + 1) * (v_4)
+v_3 + v_5 == (v_1) * (1)
@@ -0,0 +1,88 @@
+@ noname.0.7.0
+
+v_4 == (v_3) * (v_3 + -1)
+0 == (v_4) * (1)
+v_6 == (v_5) * (v_5 + -1)
+0 == (v_6) * (1)
+v_8 == (v_7) * (v_7 + -1)
+0 == (v_8) * (1)
+v_10 == (v_9) * (v_9 + -1)
+0 == (v_10) * (1)
+v_12 == (v_11) * (v_11 + -1)
+0 == (v_12) * (1)
+v_14 == (v_13) * (v_13 + -1)
+0 == (v_14) * (1)
+v_16 == (v_15) * (v_15 + -1)
+0 == (v_16) * (1)
+v_18 == (v_17) * (v_17 + -1)
+0 == (v_18) * (1)
+v_2 == (v_3 + 2 * v_5 + 4 * v_7 + 8 * v_9 + 16 * v_11 + 32 * v_13 + 64 * v_15 + 128 * v_17) * (1)
+v_21 == (v_20) * (v_20 + -1)
+0 == (v_21) * (1)
+v_23 == (v_22) * (v_22 + -1)
+0 == (v_23) * (1)
+v_25 == (v_24) * (v_24 + -1)
+0 == (v_25) * (1)
+v_27 == (v_26) * (v_26 + -1)
+0 == (v_27) * (1)
+v_29 == (v_28) * (v_28 + -1)
+0 == (v_29) * (1)
+v_31 == (v_30) * (v_30 + -1)
+0 == (v_31) * (1)
+v_33 == (v_32) * (v_32 + -1)
+0 == (v_33) * (1)
+v_35 == (v_34) * (v_34 + -1)
+0 == (v_35) * (1)
+v_19 == (v_20 + 2 * v_22 + 4 * v_24 + 8 * v_26 + 16 * v_28 + 32 * v_30 + 64 * v_32 + 128 * v_34) * (1)
+v_37 == (v_36) * (v_36 + -1)
+0 == (v_37) * (1)
+v_39 == (v_38) * (v_38 + -1)
+0 == (v_39) * (1)
+v_41 == (v_40) * (v_40 + -1)
+0 == (v_41) * (1)
+v_43 == (v_42) * (v_42 + -1)
+0 == (v_43) * (1)
+v_45 == (v_44) * (v_44 + -1)
+0 == (v_45) * (1)
+v_47 == (v_46) * (v_46 + -1)
+0 == (v_47) * (1)
+v_49 == (v_48) * (v_48 + -1)
+0 == (v_49) * (1)
+v_51 == (v_50) * (v_50 + -1)
+0 == (v_51) * (1)
+v_2 + v_19 == (v_36 + 2 * v_38 + 4 * v_40 + 8 * v_42 + 16 * v_44 + 32 * v_46 + 64 * v_48 + 128 * v_50) * (1)
+12 == (v_2 + v_19) * (1)
+v_53 == (v_52) * (v_52 + -1)
+0 == (v_53) * (1)
+v_55 == (v_54) * (v_54 + -1)
+0 == (v_55) * (1)
+v_57 == (v_56) * (v_56 + -1)
+0 == (v_57) * (1)
+v_59 == (v_58) * (v_58 + -1)
+0 == (v_59) * (1)
+v_61 == (v_60) * (v_60 + -1)
+0 == (v_61) * (1)
+v_63 == (v_62) * (v_62 + -1)
+0 == (v_63) * (1)
+v_65 == (v_64) * (v_64 + -1)
+0 == (v_65) * (1)
+v_67 == (v_66) * (v_66 + -1)
+0 == (v_67) * (1)
+v_69 == (v_68) * (v_68 + -1)
+0 == (v_69) * (1)
+v_71 == (v_70) * (v_70 + -1)
+0 == (v_71) * (1)
+v_73 == (v_72) * (v_72 + -1)
+0 == (v_73) * (1)
+v_75 == (v_74) * (v_74 + -1)
+0 == (v_75) * (1)
+v_77 == (v_76) * (v_76 + -1)
+0 == (v_77) * (1)
+v_79 == (v_78) * (v_78 + -1)
+0 == (v_79) * (1)
+v_81 == (v_80) * (v_80 + -1)
+0 == (v_81) * (1)
+v_83 == (v_82) * (v_82 + -1)
+0 == (v_83) * (1)
+2 * v_2 + 2 * v_19 == (v_52 + 2 * v_54 + 4 * v_56 + 8 * v_58 + 16 * v_60 + 32 * v_62 + 64 * v_64 + 128 * v_66 + 256 * v_68 + 512 * v_70 + 1024 * v_72 + 2048 * v_74 + 4096 * v_76 + 8192 * v_78 + 16384 * v_80 + 32768 * v_82) * (1)
+2 * v_2 + 2 * v_19 == (v_1) * (1)
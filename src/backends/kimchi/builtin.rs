@@ -1,17 +1,19 @@
 use std::sync::Arc;
 
-use ark_ff::Zero;
+use ark_ff::{Field, Zero};
 use kimchi::circuits::polynomials::poseidon::{POS_ROWS_PER_HASH, ROUNDS_PER_ROW};
 use kimchi::mina_poseidon::constants::{PlonkSpongeConstantsKimchi, SpongeConstants};
 use kimchi::mina_poseidon::permutation::full_round;
+use num_bigint::BigUint;
 
-use super::{KimchiCellVar, KimchiVesta, VestaField};
+use super::{ArrayTable, KimchiCellVar, KimchiVesta, VestaField, RUNTIME_TABLE_ID_OFFSET};
 use crate::backends::kimchi::NUM_REGISTERS;
 use crate::backends::Backend;
 
 use crate::{
     circuit_writer::{CircuitWriter, GateKind, VarInfo},
     constants::Span,
+    constraints::field,
     error::{ErrorKind, Result},
     parser::types::TyKind,
     var::{ConstOrCell, Value, Var},
@@ -178,3 +180,347 @@ pub fn poseidon(
 
     Ok(Some(Var::new(vars, span)))
 }
+
+/// Returns the bitwise xor of two vars of at most `bitlen` bits.
+/// It uses a chain of `Xor16` gates, each one xoring the next 16 bits of the inputs
+/// (via lookups on 4-bit chunks),
+/// followed by a row that checks that nothing is left of the inputs and of the output.
+pub fn xor(
+    compiler: &mut CircuitWriter<KimchiVesta>,
+    lhs: &KimchiCellVar,
+    rhs: &KimchiCellVar,
+    bitlen: usize,
+    span: Span,
+) -> KimchiCellVar {
+    // within an `if`/`else` branch, the operands only have to fit in `bitlen` bits if the branch is active
+    // (so we xor `condition * lhs` and `condition * rhs` instead, which are zero otherwise)
+    let lhs = when_active(compiler, lhs, span);
+    let rhs = when_active(compiler, rhs, span);
+
+    // the result is computed out of circuit
+    let out = compiler.backend.new_internal_var(
+        Value::Hint(Arc::new(move |backend, env| {
+            let lhs: BigUint = backend.compute_var(env, &lhs)?.into();
+            let rhs: BigUint = backend.compute_var(env, &rhs)?.into();
+            Ok(to_field(lhs ^ rhs))
+        })),
+        span,
+    );
+
+    // the last row must only contain zeros
+    // (important: this is done before the xor rows, as it might add a generic gate)
+    let zero = compiler.backend.add_constant(
+        Some("encoding zero to check what's left after a xor"),
+        VestaField::zero(),
+        span,
+    );
+
+    let num_xors = (bitlen + 15) / 16;
+    for ii in 0..num_xors {
+        // what's left to xor of the inputs and of the output
+        let remaining = |compiler: &mut CircuitWriter<KimchiVesta>, var| {
+            if ii == 0 {
+                var
+            } else {
+                bits_of(compiler, var, 16 * ii, None, span)
+            }
+        };
+        let mut vars = vec![
+            Some(remaining(compiler, lhs)),
+            Some(remaining(compiler, rhs)),
+            Some(remaining(compiler, out)),
+        ];
+
+        // the 4-bit chunks of the next 16 bits
+        for var in [lhs, rhs, out] {
+            for chunk in 0..4 {
+                let offset = 16 * ii + 4 * chunk;
+                vars.push(Some(bits_of(compiler, var, offset, Some(4), span)));
+            }
+        }
+
+        compiler.backend.add_gate(
+            "uses a xor16 gate to constrain the xor of 16 bits",
+            GateKind::Xor16,
+            vars,
+            vec![],
+            span,
+        );
+    }
+
+    compiler.backend.add_gate(
+        "uses a zero gate to check that the xor covered all the bits",
+        GateKind::Zero,
+        vec![Some(zero), Some(zero), Some(zero)],
+        vec![],
+        span,
+    );
+
+    out
+}
+
+/// The outputs of a 64-bit rotation.
+pub struct Rot64Outputs {
+    /// The 64 lowest bits of `word << rot`.
+    pub shifted: KimchiCellVar,
+    /// The bits shifted out, `word >> (64 - rot)`.
+    pub excess: KimchiCellVar,
+    /// The rotation of the word, `shifted + excess`.
+    pub rotated: KimchiCellVar,
+}
+
+/// Rotates a 64-bit word to the left by `rot` bits, with `0 < rot < 64`.
+/// It uses a `Rot64` gate, which constrains `word * 2^rot = excess * 2^64 + shifted`,
+/// `rotated = shifted + excess` and decomposes `excess - 2^rot + 2^64` to check that it fits in 64 bits,
+/// followed by the `RangeCheck0` gates of the shifted value, of the excess and of the word.
+pub fn rot64(
+    compiler: &mut CircuitWriter<KimchiVesta>,
+    word: &KimchiCellVar,
+    rot: usize,
+    span: Span,
+) -> Rot64Outputs {
+    assert!(rot > 0 && rot < 64);
+
+    // within an `if`/`else` branch, the word only has to fit in 64 bits if the branch is active
+    // (so we rotate `condition * word` instead, which is `word` if the branch is active)
+    let word = when_active(compiler, word, span);
+
+    // the two highest limbs of a range check must be zero to check 64 bits
+    // (important: this is done before the rotation rows, as it might add a generic gate)
+    let zero = compiler.backend.add_constant(
+        Some("encoding zero for the highest limbs of a 64-bit range check"),
+        VestaField::zero(),
+        span,
+    );
+
+    // the outputs are computed out of circuit, from the word
+    // (and so are the chunks of the values that are range checked)
+    let new_hint = |compiler: &mut CircuitWriter<KimchiVesta>,
+                    compute: fn(BigUint, usize) -> BigUint,
+                    chunk: Option<(usize, usize)>| {
+        compiler.backend.new_internal_var(
+            Value::Hint(Arc::new(move |backend, env| {
+                let word: BigUint = backend.compute_var(env, &word)?.into();
+                let mut res = compute(word, rot);
+                if let Some((offset, len)) = chunk {
+                    res = (res >> offset) & ((BigUint::from(1u32) << len) - 1u32);
+                }
+                Ok(to_field(res))
+            })),
+            span,
+        )
+    };
+
+    // the 4 12-bit limbs and the 8 crumbs of the lowest 64 bits of a value
+    let limbs = |compiler: &mut CircuitWriter<KimchiVesta>, compute| {
+        [(52, 12), (40, 12), (28, 12), (16, 12)]
+            .into_iter()
+            .chain((0..16).step_by(2).rev().map(|offset| (offset, 2)))
+            .map(|chunk| Some(new_hint(compiler, compute, Some(chunk))))
+            .collect::<Vec<_>>()
+    };
+
+    let shifted_value: fn(BigUint, usize) -> BigUint = |word, rot| lowest_64_bits(word << rot);
+    let excess_value: fn(BigUint, usize) -> BigUint = |word, rot| word >> (64 - rot);
+    let rotated_value: fn(BigUint, usize) -> BigUint =
+        |word, rot| lowest_64_bits(&word << rot) | (word >> (64 - rot));
+    let bound_value: fn(BigUint, usize) -> BigUint = |word, rot| {
+        (word >> (64 - rot)) + (BigUint::from(1u32) << 64) - (BigUint::from(1u32) << rot)
+    };
+    let word_value: fn(BigUint, usize) -> BigUint = |word, _| word;
+
+    let shifted = new_hint(compiler, shifted_value, None);
+    let excess = new_hint(compiler, excess_value, None);
+    let rotated = new_hint(compiler, rotated_value, None);
+
+    let mut vars = vec![Some(word), Some(rotated), Some(excess)];
+    vars.extend(limbs(compiler, bound_value));
+    compiler.backend.add_gate(
+        "uses a rot64 gate to constrain the rotation of a 64-bit word",
+        GateKind::Rot64,
+        vars,
+        vec![VestaField::from(2u64).pow([rot as u64])],
+        span,
+    );
+
+    // important: the shifted value must be range checked on the row following the rotation
+    for (var, value, note) in [
+        (
+            shifted,
+            shifted_value,
+            "uses a range check gate to constrain the shifted value to 64 bits",
+        ),
+        (
+            excess,
+            excess_value,
+            "uses a range check gate to constrain the excess to 64 bits",
+        ),
+        (
+            word,
+            word_value,
+            "uses a range check gate to constrain the rotated word to 64 bits",
+        ),
+    ] {
+        let mut vars = vec![Some(var), Some(zero), Some(zero)];
+        vars.extend(limbs(compiler, value));
+        compiler.backend.add_gate(
+            note,
+            GateKind::RangeCheck0,
+            vars,
+            vec![VestaField::zero()],
+            span,
+        );
+    }
+
+    Rot64Outputs {
+        shifted,
+        excess,
+        rotated,
+    }
+}
+
+/// Returns the element at `idx` in an array of elements made of `elem_len` cells each.
+/// Each cell position of the elements gets its own runtime table,
+/// mapping the index of each element to its cell at that position.
+/// Looking up every (index, cell) pair binds the table to the cells of the array,
+/// and looking up (`idx`, result) then proves that the result is the cell at `idx`
+/// (which also proves that `idx` is in bounds).
+pub fn array_get(
+    compiler: &mut CircuitWriter<KimchiVesta>,
+    array: &Var<VestaField, KimchiCellVar>,
+    idx: &ConstOrCell<VestaField, KimchiCellVar>,
+    elem_len: usize,
+    span: Span,
+) -> Var<VestaField, KimchiCellVar> {
+    let to_cell = |compiler: &mut CircuitWriter<KimchiVesta>, var: &ConstOrCell<_, _>| match var {
+        ConstOrCell::Const(cst) => compiler.backend.add_constant(None, *cst, span),
+        ConstOrCell::Cell(cvar) => *cvar,
+    };
+
+    // within an `if`/`else` branch, the index only has to be in bounds if the branch is active
+    // (so we look up `condition * idx` instead, which is the first element otherwise)
+    let idx = match compiler.condition {
+        None => to_cell(compiler, idx),
+        Some(condition) => {
+            let idx = field::mul(compiler, &condition, idx, span)[0];
+            to_cell(compiler, &idx)
+        }
+    };
+    let len = array.len() / elem_len;
+
+    let mut cvars = Vec::with_capacity(elem_len);
+    for jj in 0..elem_len {
+        let cells: Vec<_> = (0..len)
+            .map(|ii| to_cell(compiler, &array[ii * elem_len + jj]))
+            .collect();
+
+        // register the table
+        let id = RUNTIME_TABLE_ID_OFFSET + compiler.backend.runtime_tables.len() as i32;
+        compiler.backend.runtime_tables.push(ArrayTable {
+            id,
+            cells: cells.clone(),
+        });
+        let id = compiler.backend.add_constant(
+            Some("encoding the id of a runtime table"),
+            VestaField::from(id as u64),
+            span,
+        );
+
+        // the result is computed out of circuit
+        // (an index out of bounds gives a zero, which the lookup will reject)
+        let res = {
+            let cells = cells.clone();
+            compiler.backend.new_internal_var(
+                Value::Hint(Arc::new(move |backend, env| {
+                    let idx: BigUint = backend.compute_var(env, &idx)?.into();
+                    match usize::try_from(idx).ok().and_then(|idx| cells.get(idx)) {
+                        Some(cell) => backend.compute_var(env, cell),
+                        None => Ok(VestaField::zero()),
+                    }
+                })),
+                span,
+            )
+        };
+
+        // the (index, cell) pairs to look up, followed by the (idx, result) pair
+        let mut lookups = Vec::with_capacity(len + 1);
+        for (ii, cell) in cells.into_iter().enumerate() {
+            let position = compiler.backend.add_constant(
+                Some("encoding an index of a runtime table"),
+                VestaField::from(ii as u64),
+                span,
+            );
+            lookups.push((position, cell));
+        }
+        lookups.push((idx, res));
+
+        // a lookup gate performs 3 lookups in the table of id w0,
+        // the last one is padded with the first lookup
+        for chunk in lookups.chunks(3) {
+            let mut vars = vec![Some(id)];
+            for (position, cell) in chunk.iter().chain(std::iter::repeat(&lookups[0])).take(3) {
+                vars.push(Some(*position));
+                vars.push(Some(*cell));
+            }
+
+            compiler.backend.add_gate(
+                "uses a lookup gate to look up cells in a runtime table",
+                GateKind::Lookup,
+                vars,
+                vec![],
+                span,
+            );
+        }
+
+        cvars.push(ConstOrCell::Cell(res));
+    }
+
+    Var::new(cvars, span)
+}
+
+/// Creates a variable holding the bits of `var` starting at `offset`
+/// (only `len` of them if specified).
+fn bits_of(
+    compiler: &mut CircuitWriter<KimchiVesta>,
+    var: KimchiCellVar,
+    offset: usize,
+    len: Option<usize>,
+    span: Span,
+) -> KimchiCellVar {
+    compiler.backend.new_internal_var(
+        Value::Hint(Arc::new(move |backend, env| {
+            let value: BigUint = backend.compute_var(env, &var)?.into();
+            let mut res = value >> offset;
+            if let Some(len) = len {
+                res &= (BigUint::from(1u32) << len) - 1u32;
+            }
+            Ok(to_field(res))
+        })),
+        span,
+    )
+}
+
+/// Returns `condition * var` within an `if`/`else` branch, and `var` otherwise.
+fn when_active(
+    compiler: &mut CircuitWriter<KimchiVesta>,
+    var: &KimchiCellVar,
+    span: Span,
+) -> KimchiCellVar {
+    match compiler.condition {
+        None => *var,
+        Some(condition) => {
+            match field::mul(compiler, &condition, &ConstOrCell::Cell(*var), span)[0] {
+                ConstOrCell::Cell(cvar) => cvar,
+                ConstOrCell::Const(cst) => compiler.backend.add_constant(None, cst, span),
+            }
+        }
+    }
+}
+
+fn lowest_64_bits(value: BigUint) -> BigUint {
+    value & ((BigUint::from(1u32) << 64) - 1u32)
+}
+
+fn to_field(value: BigUint) -> VestaField {
+    VestaField::try_from(value).unwrap_or_else(|_| unreachable!("the value fits in the field"))
+}
@@ -10,6 +10,7 @@ use std::{
 };
 
 use itertools::{izip, Itertools};
+use kimchi::circuits::lookup::runtime_tables::{RuntimeTable, RuntimeTableCfg};
 use kimchi::circuits::polynomials::generic::{GENERIC_COEFFS, GENERIC_REGISTERS};
 use serde::{Deserialize, Serialize};
 
@@ -17,13 +18,14 @@ use crate::{
     backends::kimchi::asm::parse_coeffs,
     circuit_writer::{
         writer::{AnnotatedCell, Cell, PendingGate},
-        DebugInfo, Gate, GateKind, Wiring,
+        CircuitWriter, DebugInfo, Gate, GateKind, Wiring,
     },
     compiler::Sources,
     constants::Span,
+    constraints::bitwise::{self, Shift},
     error::{Error, ErrorKind, Result},
     helpers::PrettyField,
-    var::{Value, Var},
+    var::{ConstOrCell, Value, Var},
     witness::WitnessEnv,
 };
 
@@ -37,6 +39,10 @@ pub type VestaField = kimchi::mina_curves::pasta::Fp;
 /// Number of columns in the execution trace.
 pub const NUM_REGISTERS: usize = kimchi::circuits::wires::COLUMNS;
 
+/// The id of the first runtime table
+/// (the ids before it are used by the fixed tables of kimchi, e.g. the xor table).
+pub const RUNTIME_TABLE_ID_OFFSET: i32 = 2;
+
 use super::{Backend, BackendField, BackendVar};
 
 impl BackendField for VestaField {}
@@ -52,6 +58,26 @@ pub struct GeneratedWitness {
     pub full_public_inputs: Vec<VestaField>,
     /// contains the public outputs, which are also part of the all_witness
     pub public_outputs: Vec<VestaField>,
+    /// contains the values of the runtime tables
+    pub runtime_tables: Vec<RuntimeTable<VestaField>>,
+}
+
+/// A runtime table used to look up the cells of an array at an index only known at proving time.
+/// Its first column contains the indexes of the cells, and its second column the cells themselves.
+#[derive(Debug, Clone)]
+pub(crate) struct ArrayTable {
+    pub(crate) id: i32,
+    pub(crate) cells: Vec<KimchiCellVar>,
+}
+
+impl ArrayTable {
+    /// The configuration of the table, which fixes its first column.
+    pub(crate) fn cfg(&self) -> RuntimeTableCfg<VestaField> {
+        RuntimeTableCfg {
+            id: self.id,
+            first_column: (0..self.cells.len() as u64).map(VestaField::from).collect(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -90,6 +116,9 @@ pub struct KimchiVesta {
     /// A vector of debug information that maps to each row of the created circuit.
     pub(crate) debug_info: Vec<DebugInfo>,
 
+    /// The message of the assertion being compiled, if any.
+    pub(crate) assert_message: Option<String>,
+
     /// Once this is set, you can generate a witness (and can't modify the circuit?)
     // Note: I don't think we need this, but it acts as a nice redundant failsafe.
     pub(crate) finalized: bool,
@@ -100,6 +129,9 @@ pub struct KimchiVesta {
     /// Indexes used by the private inputs
     /// (this is useful to check that they appear in the circuit)
     pub(crate) private_input_cell_vars: Vec<KimchiCellVar>,
+
+    /// The runtime tables created by the circuit generation.
+    pub(crate) runtime_tables: Vec<ArrayTable>,
 }
 
 impl Witness {
@@ -143,9 +175,11 @@ impl KimchiVesta {
             double_generic_gate_optimization,
             pending_generic_gate: None,
             debug_info: vec![],
+            assert_message: None,
             finalized: false,
             public_input_size: 0,
             private_input_cell_vars: vec![],
+            runtime_tables: vec![],
         }
     }
 
@@ -176,6 +210,8 @@ impl KimchiVesta {
         let debug_info = DebugInfo {
             span,
             note: note.to_string(),
+            message: self.assert_message.clone(),
+            second_message: None,
         };
         self.debug_info.push(debug_info.clone());
 
@@ -232,6 +268,10 @@ impl KimchiVesta {
             // TODO: what to do with the label and span?
 
             self.add_gate(label, GateKind::DoubleGeneric, vars, coeffs, span);
+
+            // the pending gate makes the second half of the row, and keeps its own assertion message
+            let debug_info = self.debug_info.last_mut().expect("a gate was just added");
+            debug_info.second_message = generic_gate.message;
         } else {
             // otherwise queue it
             self.pending_generic_gate = Some(PendingGate {
@@ -239,6 +279,7 @@ impl KimchiVesta {
                 coeffs,
                 vars,
                 span,
+                message: self.assert_message.clone(),
             });
         }
     }
@@ -269,6 +310,46 @@ impl Backend for KimchiVesta {
         builtin::poseidon
     }
 
+    fn xor(
+        compiler: &mut CircuitWriter<Self>,
+        lhs: &KimchiCellVar,
+        rhs: &KimchiCellVar,
+        bitlen: usize,
+        span: Span,
+    ) -> KimchiCellVar {
+        builtin::xor(compiler, lhs, rhs, bitlen, span)
+    }
+
+    fn shift(
+        compiler: &mut CircuitWriter<Self>,
+        var: &KimchiCellVar,
+        shift: Shift,
+        bitlen: usize,
+        span: Span,
+    ) -> KimchiCellVar {
+        // the rotation gate only works on 64-bit words
+        if bitlen != 64 {
+            return bitwise::shift_from_bits(compiler, var, shift, bitlen, span);
+        }
+
+        match shift {
+            Shift::Left(amount) => builtin::rot64(compiler, var, amount, span).shifted,
+            // rotating the lowest bits to the highest ones shifts the rest out
+            Shift::Right(amount) => builtin::rot64(compiler, var, 64 - amount, span).excess,
+            Shift::RotateLeft(amount) => builtin::rot64(compiler, var, amount, span).rotated,
+        }
+    }
+
+    fn array_get(
+        compiler: &mut CircuitWriter<Self>,
+        array: &Var<VestaField, KimchiCellVar>,
+        idx: &ConstOrCell<VestaField, KimchiCellVar>,
+        elem_len: usize,
+        span: Span,
+    ) -> Var<VestaField, KimchiCellVar> {
+        builtin::array_get(compiler, array, idx, elem_len, span)
+    }
+
     fn new_internal_var(&mut self, val: Value<KimchiVesta>, span: Span) -> KimchiCellVar {
         // create new var
         let var = KimchiCellVar::new(self.next_variable, span);
@@ -314,6 +395,7 @@ impl Backend for KimchiVesta {
         // TODO: the current tests pass even this is commented out. Add a test case for this one.
         // important: there might still be a pending generic gate
         if let Some(pending) = self.pending_generic_gate.take() {
+            self.assert_message = pending.message;
             self.add_gate(
                 pending.label,
                 GateKind::DoubleGeneric,
@@ -321,6 +403,7 @@ impl Backend for KimchiVesta {
                 pending.coeffs,
                 pending.span,
             );
+            self.assert_message = None;
         }
 
         // for sanity check, we make sure that every cellvar created has ended up in a gate
@@ -436,13 +519,31 @@ impl Backend for KimchiVesta {
             public_outputs.push(val);
         }
 
+        // compute the runtime tables
+        let mut runtime_tables = Vec::with_capacity(self.runtime_tables.len());
+        for table in &self.runtime_tables {
+            let data = table
+                .cells
+                .iter()
+                .map(|cell| self.compute_var(witness_env, cell))
+                .collect::<Result<_>>()?;
+            runtime_tables.push(RuntimeTable { id: table.id, data });
+        }
+
         // sanity check the witness
         for (row, (gate, witness_row, debug_info)) in
             izip!(self.gates.iter(), &witness, &self.debug_info).enumerate()
         {
+            let invalid_witness = |message: &Option<String>| {
+                let kind = match message {
+                    Some(message) => ErrorKind::InvalidWitnessWithMessage(row, message.clone()),
+                    None => ErrorKind::InvalidWitness(row),
+                };
+                Error::new("runtime", kind, debug_info.span)
+            };
+
             let is_not_public_input = row >= self.public_input_size;
             if is_not_public_input {
-                #[allow(clippy::single_match)]
                 match gate.typ {
                     // only check the generic gate
                     crate::circuit_writer::GateKind::DoubleGeneric => {
@@ -457,12 +558,26 @@ impl Backend for KimchiVesta {
                             c(0) * w[0] + c(1) * w[1] + c(2) * w[2] + c(3) * w[0] * w[1] + c(4);
                         let sum2 =
                             c(5) * w[3] + c(6) * w[4] + c(7) * w[5] + c(8) * w[3] * w[4] + c(9);
-                        if sum1 != Self::Field::zero() || sum2 != Self::Field::zero() {
-                            return Err(Error::new(
-                                "runtime",
-                                ErrorKind::InvalidWitness(row),
-                                debug_info.span,
-                            ));
+                        if sum1 != Self::Field::zero() {
+                            return Err(invalid_witness(&debug_info.message));
+                        }
+                        if sum2 != Self::Field::zero() {
+                            return Err(invalid_witness(&debug_info.second_message));
+                        }
+                    }
+                    // check that the looked up pairs are in the runtime table
+                    crate::circuit_writer::GateKind::Lookup => {
+                        let table = runtime_tables
+                            .iter()
+                            .find(|table| VestaField::from(table.id as u64) == witness_row[0])
+                            .expect("a lookup gate uses an unknown runtime table");
+                        for pair in witness_row[1..7].chunks(2) {
+                            let found = table.data.iter().enumerate().any(|(ii, value)| {
+                                VestaField::from(ii as u64) == pair[0] && *value == pair[1]
+                            });
+                            if !found {
+                                return Err(invalid_witness(&debug_info.message));
+                            }
                         }
                     }
                     // for all other gates, we trust the gadgets
@@ -487,6 +602,7 @@ impl Backend for KimchiVesta {
             all_witness: Witness(witness),
             full_public_inputs,
             public_outputs,
+            runtime_tables,
         })
     }
 
@@ -715,6 +831,10 @@ impl Backend for KimchiVesta {
         );
     }
 
+    fn set_assert_message(&mut self, message: Option<String>) {
+        self.assert_message = message;
+    }
+
     fn add_public_input(&mut self, val: Value<Self>, span: Span) -> KimchiCellVar {
         // create the var
         let cvar = self.new_internal_var(val, span);
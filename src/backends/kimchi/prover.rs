@@ -99,9 +99,22 @@ impl KimchiVesta {
             }
         }
 
+        // runtime tables (their content is only known at proving time)
+        let runtime_tables_cfg = if self.runtime_tables.is_empty() {
+            None
+        } else {
+            Some(
+                self.runtime_tables
+                    .iter()
+                    .map(|table| table.cfg())
+                    .collect(),
+            )
+        };
+
         // create constraint system
         let cs = ConstraintSystem::create(gates)
             .public(self.public_input_size)
+            .runtime(runtime_tables_cfg)
             .build()
             .into_diagnostic()
             .wrap_err("kimchi: could not create a constraint system with the given circuit and public input size")?;
@@ -180,6 +193,7 @@ impl ProverIndex {
             sources,
             public_inputs,
             private_inputs,
+            debug,
         )?;
 
         if debug {
@@ -198,10 +212,14 @@ impl ProverIndex {
         }
 
         // create proof
-        let proof =
-            ProverProof::create::<BaseSponge, ScalarSponge>(&GROUP_MAP, witness, &[], &self.index)
-                .into_diagnostic()
-                .wrap_err("kimchi: could not create a proof with the given inputs")?;
+        let proof = ProverProof::create::<BaseSponge, ScalarSponge>(
+            &GROUP_MAP,
+            witness,
+            &generated_witness.runtime_tables,
+            &self.index,
+        )
+        .into_diagnostic()
+        .wrap_err("kimchi: could not create a proof with the given inputs")?;
 
         // return proof + public output
         Ok((
@@ -291,6 +309,7 @@ mod tests {
             &sources,
             public_inputs,
             private_inputs,
+            false,
         )?;
 
         assert_eq!(
@@ -1,16 +1,18 @@
 use std::{fmt::Debug, hash::Hash, str::FromStr};
 
-use ark_ff::{Field, Zero};
+use ark_ff::{Field, One, Zero};
 use num_bigint::BigUint;
 
 use crate::{
+    circuit_writer::CircuitWriter,
     compiler::Sources,
     constants::Span,
+    constraints::bitwise::Shift,
     error::{Error, ErrorKind, Result},
     helpers::PrettyField,
     imports::FnHandle,
     parser::FunctionDef,
-    var::{Value, Var},
+    var::{ConstOrCell, Value, Var},
     witness::WitnessEnv,
 };
 
@@ -30,7 +32,7 @@ pub trait BackendField:
 
 /// This trait allows different backends to have different cell var types.
 /// It is intended to make it opaque to the frondend.
-pub trait BackendVar: Clone + Debug + PartialEq + Eq {}
+pub trait BackendVar: Clone + Debug + PartialEq + Eq + 'static {}
 
 pub enum BackendKind {
     KimchiVesta(KimchiVesta),
@@ -53,7 +55,8 @@ impl BackendKind {
 }
 
 // TODO: should it be cloneable? It is now so because FnInfo needs to be cloneable.
-pub trait Backend: Clone {
+// (it must be `'static` as hints can capture type information about the circuit)
+pub trait Backend: Clone + 'static {
     /// The circuit field / scalar field that the circuit is written on.
     type Field: BackendField;
 
@@ -68,6 +71,37 @@ pub trait Backend: Clone {
     /// poseidon crypto builtin function for different backends
     fn poseidon() -> FnHandle<Self>;
 
+    /// Returns the bitwise xor of two vars of at most `bitlen` bits.
+    /// (this is used by the bitwise gadgets, as some backends have dedicated gates for it)
+    fn xor(
+        compiler: &mut CircuitWriter<Self>,
+        lhs: &Self::Var,
+        rhs: &Self::Var,
+        bitlen: usize,
+        span: Span,
+    ) -> Self::Var;
+
+    /// Moves the bits of a var of at most `bitlen` bits by a non-zero amount.
+    /// (this is used by the shift gadgets, as some backends have dedicated gates for it)
+    fn shift(
+        compiler: &mut CircuitWriter<Self>,
+        var: &Self::Var,
+        shift: Shift,
+        bitlen: usize,
+        span: Span,
+    ) -> Self::Var;
+
+    /// Returns the element at `idx` in an array of elements made of `elem_len` cells each,
+    /// and constrains `idx` to be in bounds.
+    /// (this is used when the index is only known at proving time, as some backends can use lookups for it)
+    fn array_get(
+        compiler: &mut CircuitWriter<Self>,
+        array: &Var<Self::Field, Self::Var>,
+        idx: &ConstOrCell<Self::Field, Self::Var>,
+        elem_len: usize,
+        span: Span,
+    ) -> Var<Self::Field, Self::Var>;
+
     /// Init circuit
     fn init_circuit(&mut self) {
         // do nothing by default
@@ -104,6 +138,11 @@ pub trait Backend: Clone {
     /// add a constraint to assert a var equals another var
     fn assert_eq_var(&mut self, lhs: &Self::Var, rhs: &Self::Var, span: Span);
 
+    /// Sets the message of the assertion being compiled (e.g. `assert(cond, "msg")`),
+    /// which is stored in the debug info of the constraints added until it is reset,
+    /// and reported if one of them is not satisfied during witness generation.
+    fn set_assert_message(&mut self, message: Option<String>);
+
     /// Process a public input
     fn add_public_input(&mut self, val: Value<Self>, span: Span) -> Self::Var;
 
@@ -141,8 +180,7 @@ pub trait Backend: Clone {
 
         match val {
             Value::Hint(func) => {
-                let res = func(self, env)
-                    .expect("that function doesn't return a var (type checker error)");
+                let res = func(self, env)?;
                 env.cached_values.insert(cache_key, res);
                 Ok(res)
             }
@@ -168,6 +206,16 @@ pub trait Backend: Clone {
                 env.cached_values.insert(cache_key, res); // cache
                 Ok(res)
             }
+            Value::NthBit(v, n) => {
+                let v: BigUint = self.compute_var(env, v)?.into();
+                let res = if v.bit(*n as u64) {
+                    Self::Field::one()
+                } else {
+                    Self::Field::zero()
+                };
+                env.cached_values.insert(cache_key, res); // cache
+                Ok(res)
+            }
             Value::External(name, idx) => Ok(env.get_external(name)[*idx]),
             Value::PublicOutput(var) => {
                 // var can be none. what could be the better way to pass in the span in that case?
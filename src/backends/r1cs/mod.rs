@@ -9,11 +9,19 @@ use itertools::{izip, Itertools as _};
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
+use crate::circuit_writer::CircuitWriter;
 use crate::constants::Span;
+use crate::constraints::{
+    array,
+    bitwise::{self, Shift},
+};
 use crate::error::{Error, ErrorKind, Result};
 use crate::helpers::PrettyField;
 use crate::parser::FunctionDef;
-use crate::{circuit_writer::DebugInfo, var::Value};
+use crate::{
+    circuit_writer::DebugInfo,
+    var::{ConstOrCell, Value, Var},
+};
 
 use super::{Backend, BackendField, BackendVar};
 
@@ -205,6 +213,8 @@ where
     private_input_cell_vars: Vec<CellVar>,
     /// Record the public outputs for reordering the witness vector
     pub public_outputs: Vec<CellVar>,
+    /// The message of the assertion being compiled, if any
+    assert_message: Option<String>,
     finalized: bool,
 }
 
@@ -220,6 +230,7 @@ where
             public_inputs: Vec::new(),
             private_input_cell_vars: Vec::new(),
             public_outputs: Vec::new(),
+            assert_message: None,
             finalized: false,
         }
     }
@@ -240,6 +251,8 @@ where
         let debug_info = DebugInfo {
             note: note.to_string(),
             span,
+            message: self.assert_message.clone(),
+            second_message: None,
         };
         self.debug_info.push(debug_info);
 
@@ -293,6 +306,39 @@ where
         builtin::poseidon::<F>
     }
 
+    fn xor(
+        compiler: &mut CircuitWriter<Self>,
+        lhs: &Self::Var,
+        rhs: &Self::Var,
+        bitlen: usize,
+        span: Span,
+    ) -> Self::Var {
+        // there's no dedicated gate in R1CS, so we work on the bits
+        bitwise::xor_from_bits(compiler, lhs, rhs, bitlen, span)
+    }
+
+    fn shift(
+        compiler: &mut CircuitWriter<Self>,
+        var: &LinearCombination<F>,
+        shift: Shift,
+        bitlen: usize,
+        span: Span,
+    ) -> LinearCombination<F> {
+        // there's no dedicated gate in R1CS, so we work on the bits
+        bitwise::shift_from_bits(compiler, var, shift, bitlen, span)
+    }
+
+    fn array_get(
+        compiler: &mut CircuitWriter<Self>,
+        array: &Var<F, LinearCombination<F>>,
+        idx: &ConstOrCell<F, LinearCombination<F>>,
+        elem_len: usize,
+        span: Span,
+    ) -> Var<F, LinearCombination<F>> {
+        // there are no lookups in R1CS, so we use a one-hot selector
+        array::get_with_selector(compiler, array, idx, elem_len, span)
+    }
+
     fn init_circuit(&mut self) {
         // create the first var that is always 1
         self.new_internal_var(Value::Constant(F::one()), Span::default());
@@ -446,11 +492,11 @@ where
             let c = constraint.c.evaluate(&witness);
 
             if ab != c {
-                return Err(Error::new(
-                    "runtime",
-                    ErrorKind::InvalidWitness(index),
-                    debug_info.span,
-                ));
+                let kind = match &debug_info.message {
+                    Some(message) => ErrorKind::InvalidWitnessWithMessage(index, message.clone()),
+                    None => ErrorKind::InvalidWitness(index),
+                };
+                return Err(Error::new("runtime", kind, debug_info.span));
             }
         }
 
@@ -574,6 +620,10 @@ where
         lhs.assert_eq(self, rhs, span)
     }
 
+    fn set_assert_message(&mut self, message: Option<String>) {
+        self.assert_message = message;
+    }
+
     /// Adds the public input cell vars.
     fn add_public_input(&mut self, val: Value<Self>, span: Span) -> LinearCombination<F> {
         let var = self.new_internal_var(val, span);
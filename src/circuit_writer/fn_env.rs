@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::{
     backends::{BackendField, BackendVar},
-    parser::types::TyKind,
+    parser::types::{ArraySize, TyKind},
     var::Var,
 };
 
@@ -75,6 +75,14 @@ where
     /// This needs to be garbage collected when we exit a scope.
     /// Note: The `usize` is the scope in which the variable was created.
     vars: HashMap<String, (usize, VarInfo<F, C>)>,
+
+    /// The sizes of the function's generic arrays (e.g. `N` in `[Field; N]`),
+    /// as inferred from the arguments the function was called with.
+    generic_sizes: HashMap<String, ArraySize>,
+
+    /// The types of the function's generic types (e.g. `T` in `fn digest<T: Hashable>(value: T)`),
+    /// as inferred from the arguments the function was called with.
+    generic_types: HashMap<String, TyKind>,
 }
 
 impl<F: BackendField, C: BackendVar> FnEnv<F, C> {
@@ -83,6 +91,38 @@ impl<F: BackendField, C: BackendVar> FnEnv<F, C> {
         Self {
             current_scope: 0,
             vars: HashMap::new(),
+            generic_sizes: HashMap::new(),
+            generic_types: HashMap::new(),
+        }
+    }
+
+    /// Creates a new FnEnv for a function called with the given generic array sizes and types.
+    pub fn with_generics(
+        generic_sizes: HashMap<String, ArraySize>,
+        generic_types: HashMap<String, TyKind>,
+    ) -> Self {
+        Self {
+            generic_sizes,
+            generic_types,
+            ..Self::new()
+        }
+    }
+
+    /// Returns the sizes of the function's generic arrays.
+    pub fn generic_sizes(&self) -> &HashMap<String, ArraySize> {
+        &self.generic_sizes
+    }
+
+    /// Returns the types of the function's generic types.
+    pub fn generic_types(&self) -> &HashMap<String, TyKind> {
+        &self.generic_types
+    }
+
+    /// Returns the size bound to a generic array size (e.g. `N`), if it is one.
+    pub fn generic_size(&self, name: &str) -> Option<u32> {
+        match self.generic_sizes.get(name)? {
+            ArraySize::Fixed(size) => Some(*size),
+            ArraySize::Generic(_) => unreachable!("generic sizes are known in the circuit writer"),
         }
     }
 
@@ -143,6 +183,18 @@ impl<F: BackendField, C: BackendVar> FnEnv<F, C> {
         var_info.clone()
     }
 
+    /// Returns the names of the mutable variables in scope (sorted, to keep circuits deterministic).
+    pub fn mutable_vars(&self) -> Vec<String> {
+        let mut names: Vec<_> = self
+            .vars
+            .iter()
+            .filter(|(_, (scope, var_info))| self.is_in_scope(*scope) && var_info.mutable)
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
     pub fn reassign_local_var(&mut self, var_name: &str, var: Var<F, C>) {
         // get the scope first, we don't want to modify that
         let (scope, var_info) = self
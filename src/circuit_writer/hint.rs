@@ -0,0 +1,820 @@
+//! Hint functions (declared with `hint fn`) are only run at witness generation time.
+//!
+//! Calling a hint function doesn't compile its body:
+//! each cell of the returned value becomes a new variable of the circuit,
+//! whose value is computed by running the body of the function (out of circuit)
+//! on the values of the arguments.
+//! The type checker makes sure that hint functions only call other hint functions,
+//! and it is up to the caller to constrain the values returned by a hint.
+
+use std::{collections::HashMap, sync::Arc};
+
+use ark_ff::{Field as _, One, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    backends::Backend,
+    circuit_writer::{CircuitWriter, VarInfo},
+    constants::Span,
+    constraints::comparison,
+    error::{Error, ErrorKind, Result},
+    imports::FnKind,
+    parser::{
+        types::{ArraySize, FunctionDef, Pattern, Stmt, StmtKind, TyKind},
+        Expr, ExprKind, MatchPattern, Op2,
+    },
+    stdlib::is_concat,
+    type_checker::{FullyQualified, TypeChecker},
+    var::{ConstOrCell, Value, Var},
+    witness::WitnessEnv,
+};
+
+impl<B: Backend> CircuitWriter<B> {
+    /// Compiles a call to a hint function,
+    /// given the generic array sizes and types inferred from its arguments.
+    pub(crate) fn compile_hint_call(
+        &mut self,
+        function: &FunctionDef,
+        args: &[VarInfo<B::Field, B::Var>],
+        generic_sizes: HashMap<String, ArraySize>,
+        generic_types: HashMap<String, TyKind>,
+        span: Span,
+    ) -> Result<Var<B::Field, B::Var>> {
+        let return_typ = function
+            .sig
+            .return_type
+            .as_ref()
+            .expect("type checker bug: hint function without a return type")
+            .kind
+            .resolve_generic_sizes(&generic_sizes)
+            .resolve_generic_types(&generic_types);
+        let len = self.size_of(&return_typ);
+
+        let id = self.hint_calls;
+        self.hint_calls += 1;
+
+        let call = Arc::new(HintCall {
+            id,
+            typed: self.typed.clone(),
+            function: function.clone(),
+            args: args.iter().map(|arg| arg.var.cvars.clone()).collect(),
+            generic_sizes,
+            generic_types,
+            condition: self.condition.clone(),
+            len,
+        });
+
+        // each cell of the returned value is computed out of circuit
+        // (the function is run once, and each cell reads its value from the result)
+        let mut cvars = Vec::with_capacity(len);
+        for idx in 0..len {
+            let call = call.clone();
+            let cvar = self.backend.new_internal_var(
+                Value::Hint(Arc::new(move |backend, env| {
+                    Ok(call.run_once(backend, env)?[idx])
+                })),
+                span,
+            );
+            cvars.push(ConstOrCell::Cell(cvar));
+        }
+
+        // like the inputs of main, nothing constrains the values returned by a hint yet,
+        // so we at least make sure that they are well-typed
+        self.constrain_inputs_to_main(&cvars, &return_typ, span)?;
+
+        Ok(Var::new(cvars, span))
+    }
+}
+
+/// A call to a hint function made by the circuit.
+struct HintCall<B: Backend> {
+    /// Identifies the call, to share its result between the cells it returns.
+    id: usize,
+
+    typed: Arc<TypeChecker<B>>,
+    function: FunctionDef,
+    args: Vec<Vec<ConstOrCell<B::Field, B::Var>>>,
+    generic_sizes: HashMap<String, ArraySize>,
+    generic_types: HashMap<String, TyKind>,
+
+    /// The condition under which the call is active, if it is made within an `if`/`else` branch.
+    condition: Option<ConstOrCell<B::Field, B::Var>>,
+
+    /// The number of cells of the returned value.
+    len: usize,
+}
+
+impl<B: Backend> HintCall<B> {
+    /// Returns the values computed by the hint function, running it if it wasn't already.
+    fn run_once(&self, backend: &B, env: &mut WitnessEnv<B::Field>) -> Result<Vec<B::Field>> {
+        if let Some(values) = env.hint_values.get(&self.id) {
+            return Ok(values.clone());
+        }
+
+        let values = self.run(backend, env)?;
+        env.hint_values.insert(self.id, values.clone());
+        Ok(values)
+    }
+
+    /// Runs the hint function on the values of its arguments.
+    fn run(&self, backend: &B, env: &mut WitnessEnv<B::Field>) -> Result<Vec<B::Field>> {
+        let mut eval = |cell: &ConstOrCell<B::Field, B::Var>| match cell {
+            ConstOrCell::Const(cst) => Ok(*cst),
+            ConstOrCell::Cell(cvar) => backend.compute_var(env, cvar),
+        };
+
+        // within an inactive branch, the arguments might not make sense to the hint
+        // (and what it returns is not used anyway)
+        if let Some(condition) = &self.condition {
+            if eval(condition)?.is_zero() {
+                return Ok(vec![B::Field::zero(); self.len]);
+            }
+        }
+
+        let mut args = Vec::with_capacity(self.args.len());
+        for arg in &self.args {
+            args.push(arg.iter().map(&mut eval).collect::<Result<Vec<_>>>()?);
+        }
+
+        let hint_env = HintEnv::new(self.generic_sizes.clone(), self.generic_types.clone());
+        Interpreter { typed: &self.typed }.call(&self.function, args, hint_env)
+    }
+}
+
+/// The variables of a hint function being run,
+/// and the generic array sizes and types it was called with.
+/// (there's no need to track scopes, as the type checker forbids shadowing)
+struct HintEnv<F> {
+    vars: HashMap<String, Vec<F>>,
+    generic_sizes: HashMap<String, ArraySize>,
+    generic_types: HashMap<String, TyKind>,
+}
+
+impl<F> HintEnv<F> {
+    fn new(
+        generic_sizes: HashMap<String, ArraySize>,
+        generic_types: HashMap<String, TyKind>,
+    ) -> Self {
+        Self {
+            vars: HashMap::new(),
+            generic_sizes,
+            generic_types,
+        }
+    }
+}
+
+/// Runs the body of hint functions on actual values.
+/// Values are laid out like the variables of the circuit, as a list of field elements.
+struct Interpreter<'a, B: Backend> {
+    typed: &'a TypeChecker<B>,
+}
+
+impl<B: Backend> Interpreter<'_, B> {
+    fn error(&self, kind: ErrorKind, span: Span) -> Error {
+        Error::new("runtime", kind, span)
+    }
+
+    /// Returns the type of an expression,
+    /// where generic array sizes and generic types are replaced by the ones of the current call.
+    fn expr_type(&self, env: &HintEnv<B::Field>, expr: &Expr) -> TyKind {
+        self.typed
+            .expr_type(expr)
+            .expect("type checker bug: expression without a type")
+            .resolve_generic_sizes(&env.generic_sizes)
+            .resolve_generic_types(&env.generic_types)
+    }
+
+    fn call(
+        &self,
+        function: &FunctionDef,
+        args: Vec<Vec<B::Field>>,
+        mut env: HintEnv<B::Field>,
+    ) -> Result<Vec<B::Field>> {
+        for (arg, value) in function.sig.arguments.iter().zip(args) {
+            env.vars.insert(arg.name.value.clone(), value);
+        }
+
+        self.run_block(&mut env, &function.body)?
+            .ok_or_else(|| self.error(ErrorKind::MissingReturn, function.span))
+    }
+
+    /// Calls a hint function from another hint function,
+    /// given the values of the arguments and their types.
+    fn call_nested(
+        &self,
+        function: &FunctionDef,
+        args: Vec<(Vec<B::Field>, TyKind)>,
+    ) -> Result<Vec<B::Field>> {
+        let mut generic_sizes = HashMap::new();
+        let mut generic_types = HashMap::new();
+        for (arg, (_, typ)) in function.sig.arguments.iter().zip(&args) {
+            arg.typ
+                .kind
+                .bind_generic_sizes(typ, &mut generic_sizes)
+                .expect("type checker bug: conflicting generic sizes");
+            arg.typ
+                .kind
+                .bind_generic_types(typ, &mut generic_types)
+                .expect("type checker bug: conflicting generic types");
+        }
+
+        let args = args.into_iter().map(|(value, _)| value).collect();
+        self.call(function, args, HintEnv::new(generic_sizes, generic_types))
+    }
+
+    /// Runs a block of statements, and returns the value returned by a `return` if one was reached.
+    fn run_block(
+        &self,
+        env: &mut HintEnv<B::Field>,
+        stmts: &[Stmt],
+    ) -> Result<Option<Vec<B::Field>>> {
+        for stmt in stmts {
+            if let Some(res) = self.run_stmt(env, stmt)? {
+                return Ok(Some(res));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn run_stmt(&self, env: &mut HintEnv<B::Field>, stmt: &Stmt) -> Result<Option<Vec<B::Field>>> {
+        match &stmt.kind {
+            StmtKind::Assign { lhs, rhs, .. } => {
+                let value = self.eval(env, rhs)?;
+
+                match lhs {
+                    Pattern::Ident(name) => {
+                        env.vars.insert(name.value.clone(), value);
+                    }
+                    Pattern::Tuple(names) => {
+                        let typs = match self.expr_type(env, rhs) {
+                            TyKind::Tuple(typs) => typs,
+                            _ => panic!("type checker bug: destructuring a non-tuple"),
+                        };
+
+                        let mut start = 0;
+                        for (name, typ) in names.iter().zip(typs) {
+                            let len = self.typed.size_of(&typ);
+                            let elem = value[start..start + len].to_vec();
+                            env.vars.insert(name.value.clone(), elem);
+                            start += len;
+                        }
+                    }
+                }
+            }
+
+            StmtKind::ForLoop { var, range, body } => {
+                // the bounds are constants
+                let start = self.eval_usize(env, &range.start)?;
+                let end = self.eval_usize(env, &range.end)?;
+
+                for ii in start..end {
+                    let index = vec![B::Field::from(ii as u64)];
+                    env.vars.insert(var.value.clone(), index);
+
+                    if let Some(res) = self.run_block(env, body)? {
+                        return Ok(Some(res));
+                    }
+                }
+            }
+
+            StmtKind::ForIn {
+                index,
+                var,
+                array,
+                body,
+            } => {
+                let values = self.eval(env, array)?;
+                let elem_len = match self.expr_type(env, array) {
+                    TyKind::Array(typ, _) => self.typed.size_of(&typ),
+                    _ => panic!("type checker bug: iteration over a non-array"),
+                };
+
+                for (ii, elem) in values.chunks(elem_len).enumerate() {
+                    if let Some(index) = index {
+                        env.vars
+                            .insert(index.value.clone(), vec![B::Field::from(ii as u64)]);
+                    }
+                    env.vars.insert(var.value.clone(), elem.to_vec());
+
+                    if let Some(res) = self.run_block(env, body)? {
+                        return Ok(Some(res));
+                    }
+                }
+            }
+
+            StmtKind::IfElse { cond, then_, else_ } => {
+                // only the branch that is taken is run
+                let block = if self.eval(env, cond)?[0].is_zero() {
+                    else_
+                } else {
+                    then_
+                };
+
+                return self.run_block(env, block);
+            }
+
+            StmtKind::Expr(expr) => {
+                self.eval(env, expr)?;
+            }
+
+            StmtKind::Return(expr) => {
+                return self.eval(env, expr).map(Some);
+            }
+
+            StmtKind::Comment(_) => (),
+        }
+
+        Ok(None)
+    }
+
+    /// Evaluates an index, or any other value that is expected to be small.
+    fn eval_usize(&self, env: &mut HintEnv<B::Field>, expr: &Expr) -> Result<usize> {
+        let value: BigUint = self.eval(env, expr)?[0].into();
+        Ok(usize::try_from(value).unwrap_or(usize::MAX))
+    }
+
+    fn eval(&self, env: &mut HintEnv<B::Field>, expr: &Expr) -> Result<Vec<B::Field>> {
+        match &expr.kind {
+            ExprKind::FnCall {
+                module,
+                fn_name,
+                args,
+            } => {
+                let qualified = FullyQualified::new(module, &fn_name.value);
+                let fn_info = self
+                    .typed
+                    .fn_info(&qualified)
+                    .expect("type checker bug: undefined function");
+
+                // `concat` doesn't add any constraint, so it can be used in hints
+                if is_concat(fn_info) {
+                    let mut values = self.eval(env, &args[0])?;
+                    values.extend(self.eval(env, &args[1])?);
+                    return Ok(values);
+                }
+
+                let function = match &fn_info.kind {
+                    FnKind::Native(function) => function,
+                    _ => panic!("type checker bug: a hint function can only call hint functions"),
+                };
+
+                let mut values = Vec::with_capacity(args.len());
+                for arg in args {
+                    values.push((self.eval(env, arg)?, self.expr_type(env, arg)));
+                }
+
+                self.call_nested(function, values)
+            }
+
+            ExprKind::MethodCall {
+                lhs,
+                method_name,
+                args,
+            } => {
+                let lhs_typ = self.expr_type(env, lhs);
+                let (module, name) = match &lhs_typ {
+                    TyKind::Custom { module, name, .. } => (module, name),
+                    TyKind::Array(_, len) => return Ok(vec![B::Field::from(*len as u64)]),
+                    _ => panic!("type checker bug: method call on a non-struct"),
+                };
+
+                let qualified = FullyQualified::new(module, name);
+                let function = self
+                    .typed
+                    .struct_info(&qualified)
+                    .and_then(|struct_info| struct_info.methods.get(&method_name.value))
+                    .expect("type checker bug: method not found");
+
+                // `self` is passed as the first argument
+                // (unless this is a static method)
+                let mut values = Vec::with_capacity(args.len() + 1);
+                if matches!(function.sig.arguments.first(), Some(arg) if arg.name.value == "self") {
+                    values.push((self.eval(env, lhs)?, lhs_typ.clone()));
+                }
+
+                for arg in args {
+                    values.push((self.eval(env, arg)?, self.expr_type(env, arg)));
+                }
+
+                self.call_nested(function, values)
+            }
+
+            ExprKind::Assignment { lhs, rhs } => {
+                let value = self.eval(env, rhs)?;
+                let (var_name, start) = self.place(env, lhs)?;
+
+                let var = env
+                    .vars
+                    .get_mut(&var_name)
+                    .expect("type checker bug: assignment to an undefined variable");
+                var[start..start + value.len()].copy_from_slice(&value);
+
+                Ok(vec![])
+            }
+
+            ExprKind::FieldAccess { lhs, rhs } => {
+                let value = self.eval(env, lhs)?;
+                let (start, len) = self.field_range(&self.expr_type(env, lhs), &rhs.value);
+                Ok(value[start..start + len].to_vec())
+            }
+
+            ExprKind::TupleAccess { lhs, idx } => {
+                let value = self.eval(env, lhs)?;
+                let (start, len) = self.tuple_range(&self.expr_type(env, lhs), *idx);
+                Ok(value[start..start + len].to_vec())
+            }
+
+            ExprKind::ArrayAccess { array, idx } => {
+                let value = self.eval(env, array)?;
+                let (start, len) = self.element_range(env, array, idx)?;
+                Ok(value[start..start + len].to_vec())
+            }
+
+            ExprKind::ArraySlice { array, start, .. } => {
+                let value = self.eval(env, array)?;
+                let (start, len) = self.slice_range(env, array, start, expr)?;
+                Ok(value[start..start + len].to_vec())
+            }
+
+            ExprKind::BinaryOp { op, lhs, rhs, .. } => self.eval_binary_op(env, op, lhs, rhs),
+
+            ExprKind::Negated(inner) => Ok(vec![-self.eval(env, inner)?[0]]),
+
+            ExprKind::Not(inner) => Ok(vec![B::Field::one() - self.eval(env, inner)?[0]]),
+
+            ExprKind::BigUInt(b) => {
+                let ff = B::Field::try_from(b.clone()).map_err(|_| {
+                    self.error(ErrorKind::CannotConvertToField(b.to_string()), expr.span)
+                })?;
+                Ok(vec![ff])
+            }
+
+            ExprKind::StringLiteral(_) => {
+                Err(self.error(ErrorKind::UnexpectedStringLiteral, expr.span))
+            }
+
+            ExprKind::ByteString(bytes) => Ok(bytes
+                .iter()
+                .map(|byte| B::Field::from(*byte as u64))
+                .collect()),
+
+            ExprKind::Bool(b) => Ok(vec![from_bool(*b)]),
+
+            ExprKind::Variable { module, name } => {
+                // generic array sizes are constants within the function
+                match env.generic_sizes.get(&name.value) {
+                    Some(ArraySize::Fixed(size)) => return Ok(vec![B::Field::from(*size)]),
+                    Some(ArraySize::Generic(_)) => {
+                        unreachable!("generic sizes are known when running a hint")
+                    }
+                    None => (),
+                }
+
+                let qualified = FullyQualified::new(module, &name.value);
+                if let Some(cst_info) = self.typed.const_info(&qualified) {
+                    return Ok(cst_info.value.clone());
+                }
+
+                let value = env.vars.get(&name.value).unwrap_or_else(|| {
+                    panic!("type checker bug: undefined variable `{}`", name.value)
+                });
+                Ok(value.clone())
+            }
+
+            ExprKind::ArrayDeclaration(items) | ExprKind::TupleDeclaration(items) => {
+                let mut values = vec![];
+                for item in items {
+                    values.extend(self.eval(env, item)?);
+                }
+                Ok(values)
+            }
+
+            ExprKind::RepeatedArrayDeclaration { item, .. } => {
+                let value = self.eval(env, item)?;
+                let size = match self.expr_type(env, expr) {
+                    TyKind::Array(_, size) => size as usize,
+                    _ => panic!("type checker bug: expected an array"),
+                };
+                Ok(value.repeat(size))
+            }
+
+            ExprKind::CustomTypeDeclaration { custom: _, fields } => {
+                let mut values = vec![];
+                for (_field, rhs) in fields {
+                    values.extend(self.eval(env, rhs)?);
+                }
+                Ok(values)
+            }
+
+            ExprKind::IfElse { cond, then_, else_ } => {
+                // only the branch that is taken is evaluated
+                if self.eval(env, cond)?[0].is_zero() {
+                    self.eval(env, else_)
+                } else {
+                    self.eval(env, then_)
+                }
+            }
+
+            ExprKind::Cast { lhs, typ } => {
+                let value = self.eval(env, lhs)?;
+
+                // converting to an integer fails if the value doesn't fit
+                if let Some(bitlen) = typ.kind.int_bitlen() {
+                    self.check_int(value[0], bitlen, expr.span)?;
+                }
+
+                Ok(value)
+            }
+
+            ExprKind::EnumVariant {
+                custom,
+                variant,
+                args,
+            } => {
+                let qualified = FullyQualified::new(&custom.module, &custom.name);
+                let (tag, _) = self
+                    .typed
+                    .enum_info(&qualified)
+                    .and_then(|enum_info| enum_info.variant(&variant.value))
+                    .expect("type checker bug: unknown variant");
+
+                // the tag, followed by the values held by the variant,
+                // padded with zeros to the size of the largest variant
+                let mut values = vec![B::Field::from(tag as u64)];
+                for arg in args {
+                    values.extend(self.eval(env, arg)?);
+                }
+
+                let size = self.typed.size_of(&TyKind::Custom {
+                    module: custom.module.clone(),
+                    name: custom.name.clone(),
+                    sizes: vec![],
+                });
+                values.resize(size, B::Field::zero());
+
+                Ok(values)
+            }
+
+            ExprKind::Match {
+                expr: scrutinee,
+                arms,
+            } => {
+                let enum_info = match self.expr_type(env, scrutinee) {
+                    TyKind::Custom { module, name, .. } => self
+                        .typed
+                        .enum_info(&FullyQualified::new(&module, &name))
+                        .expect("type checker bug: match on a non-enum"),
+                    _ => panic!("type checker bug: match on a non-enum"),
+                };
+
+                let value = self.eval(env, scrutinee)?;
+                let tag: BigUint = value[0].into();
+
+                // the first arm matching the tag is selected
+                for arm in arms {
+                    if let MatchPattern::Variant {
+                        variant, bindings, ..
+                    } = &arm.pattern
+                    {
+                        let (variant_tag, payload) = enum_info
+                            .variant(&variant.value)
+                            .expect("type checker bug: unknown variant");
+                        if tag != BigUint::from(variant_tag) {
+                            continue;
+                        }
+
+                        // the values held by the variant are laid out after the tag
+                        let mut start = 1;
+                        for (binding, typ) in bindings.iter().zip(payload) {
+                            let len = self.typed.size_of(typ);
+                            let elem = value[start..start + len].to_vec();
+                            env.vars.insert(binding.value.clone(), elem);
+                            start += len;
+                        }
+                    }
+
+                    return self.eval(env, &arm.expr);
+                }
+
+                unreachable!("type checker bug: non-exhaustive match")
+            }
+        }
+    }
+
+    fn eval_binary_op(
+        &self,
+        env: &mut HintEnv<B::Field>,
+        op: &Op2,
+        lhs: &Expr,
+        rhs: &Expr,
+    ) -> Result<Vec<B::Field>> {
+        let span = lhs.span.merge_with(rhs.span);
+
+        // integer operands (one of them might be a literal)
+        let int_bitlen = [lhs, rhs]
+            .iter()
+            .find_map(|operand| self.expr_type(env, operand).int_bitlen());
+
+        let lhs = self.eval(env, lhs)?;
+        let rhs = self.eval(env, rhs)?;
+
+        // field elements are compared as integers
+        let (lhs_int, rhs_int): (BigUint, BigUint) = (lhs[0].into(), rhs[0].into());
+
+        // like in the circuit, field elements are shifted as integers of up to this many bits
+        let bitlen = int_bitlen.unwrap_or_else(comparison::max_bitlen::<B::Field>);
+
+        let res = match op {
+            Op2::Addition => lhs[0] + rhs[0],
+            Op2::Subtraction => lhs[0] - rhs[0],
+            Op2::Multiplication => lhs[0] * rhs[0],
+            Op2::Division | Op2::Remainder if rhs[0].is_zero() => {
+                return Err(self.error(ErrorKind::DivisionByZero, span));
+            }
+            // integers use the euclidean division
+            Op2::Division => match int_bitlen {
+                Some(_) => from_int(lhs_int / rhs_int),
+                None => lhs[0] * rhs[0].inverse().expect("the divisor is not zero"),
+            },
+            Op2::Remainder => from_int(lhs_int % rhs_int),
+            Op2::Equality => from_bool(lhs == rhs),
+            Op2::Inequality => from_bool(lhs != rhs),
+            Op2::LessThan => from_bool(lhs_int < rhs_int),
+            Op2::LessThanOrEqual => from_bool(lhs_int <= rhs_int),
+            Op2::GreaterThan => from_bool(lhs_int > rhs_int),
+            Op2::GreaterThanOrEqual => from_bool(lhs_int >= rhs_int),
+            Op2::BoolAnd => lhs[0] * rhs[0],
+            Op2::BoolOr => lhs[0] + rhs[0] - lhs[0] * rhs[0],
+            Op2::BitAnd => from_int(lhs_int & rhs_int),
+            Op2::BitOr => from_int(lhs_int | rhs_int),
+            Op2::BitXor => from_int(lhs_int ^ rhs_int),
+            Op2::ShiftLeft | Op2::ShiftRight => {
+                let shift = usize::try_from(rhs_int)
+                    .ok()
+                    .filter(|shift| *shift < bitlen)
+                    .ok_or_else(|| self.error(ErrorKind::InvalidShiftAmount, span))?;
+
+                if matches!(op, Op2::ShiftLeft) {
+                    let mask = (BigUint::from(1u32) << bitlen) - 1u32;
+                    from_int((lhs_int << shift) & mask)
+                } else {
+                    from_int(lhs_int >> shift)
+                }
+            }
+        };
+
+        // integer arithmetic must not overflow
+        if let (Some(bitlen), Op2::Addition | Op2::Subtraction | Op2::Multiplication) =
+            (int_bitlen, op)
+        {
+            self.check_int(res, bitlen, span)?;
+        }
+
+        Ok(vec![res])
+    }
+
+    /// Fails if the value doesn't fit in an integer of `bitlen` bits.
+    fn check_int(&self, value: B::Field, bitlen: usize, span: Span) -> Result<()> {
+        let value: BigUint = value.into();
+        if value.bits() > bitlen as u64 {
+            return Err(self.error(ErrorKind::IntegerOverflow(bitlen), span));
+        }
+
+        Ok(())
+    }
+
+    /// Computes the place on the left-hand side of an assignment,
+    /// as the name of the variable it belongs to and its offset in that variable.
+    fn place(&self, env: &mut HintEnv<B::Field>, expr: &Expr) -> Result<(String, usize)> {
+        match &expr.kind {
+            ExprKind::Variable { name, .. } => Ok((name.value.clone(), 0)),
+
+            ExprKind::FieldAccess { lhs, rhs } => {
+                let (var_name, start) = self.place(env, lhs)?;
+                let (offset, _) = self.field_range(&self.expr_type(env, lhs), &rhs.value);
+                Ok((var_name, start + offset))
+            }
+
+            ExprKind::TupleAccess { lhs, idx } => {
+                let (var_name, start) = self.place(env, lhs)?;
+                let (offset, _) = self.tuple_range(&self.expr_type(env, lhs), *idx);
+                Ok((var_name, start + offset))
+            }
+
+            ExprKind::ArrayAccess { array, idx } => {
+                let (var_name, start) = self.place(env, array)?;
+                let (offset, _) = self.element_range(env, array, idx)?;
+                Ok((var_name, start + offset))
+            }
+
+            _ => panic!("type checker bug: invalid left-hand side of an assignment"),
+        }
+    }
+
+    /// Returns the range of a field within a struct.
+    fn field_range(&self, struct_typ: &TyKind, field: &str) -> (usize, usize) {
+        let (module, name, sizes) = match struct_typ {
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => (module, name, sizes),
+            _ => panic!("type checker bug: field access on a non-struct"),
+        };
+
+        let struct_info = self
+            .typed
+            .struct_info(&FullyQualified::new(module, name))
+            .expect("type checker bug: struct not found");
+
+        let mut start = 0;
+        for (field_name, field_typ) in &struct_info.fields_of(sizes) {
+            let len = self.typed.size_of(field_typ);
+            if field_name == field {
+                return (start, len);
+            }
+            start += len;
+        }
+
+        panic!("type checker bug: undefined field `{field}`")
+    }
+
+    /// Returns the range of an element within a tuple.
+    fn tuple_range(&self, tuple_typ: &TyKind, idx: usize) -> (usize, usize) {
+        let typs = match tuple_typ {
+            TyKind::Tuple(typs) => typs,
+            _ => panic!("type checker bug: tuple access on a non-tuple"),
+        };
+
+        let start = typs[..idx].iter().map(|typ| self.typed.size_of(typ)).sum();
+        (start, self.typed.size_of(&typs[idx]))
+    }
+
+    /// Returns the range of an element within an array,
+    /// failing if the index is out of bounds.
+    fn element_range(
+        &self,
+        env: &mut HintEnv<B::Field>,
+        array: &Expr,
+        idx: &Expr,
+    ) -> Result<(usize, usize)> {
+        let (elem_typ, array_len) = match self.expr_type(env, array) {
+            TyKind::Array(typ, len) => (typ, len as usize),
+            _ => panic!("type checker bug: array access on a non-array"),
+        };
+
+        let ii = self.eval_usize(env, idx)?;
+        if ii >= array_len {
+            return Err(self.error(
+                ErrorKind::ArrayIndexOutOfBounds(ii, array_len - 1),
+                idx.span,
+            ));
+        }
+
+        let len = self.typed.size_of(&elem_typ);
+        Ok((ii * len, len))
+    }
+
+    /// Returns the range of cells covered by the slice `array[start..end]`,
+    /// whose length was computed by the type checker.
+    fn slice_range(
+        &self,
+        env: &mut HintEnv<B::Field>,
+        array: &Expr,
+        start: &Expr,
+        slice: &Expr,
+    ) -> Result<(usize, usize)> {
+        let (elem_typ, array_len) = match self.expr_type(env, array) {
+            TyKind::Array(typ, len) => (typ, len as usize),
+            _ => panic!("type checker bug: slice of a non-array"),
+        };
+        let slice_len = match self.expr_type(env, slice) {
+            TyKind::Array(_, len) => len as usize,
+            _ => panic!("type checker bug: a slice must be an array"),
+        };
+
+        let start = self.eval_usize(env, start)?;
+        if start.saturating_add(slice_len) > array_len {
+            return Err(self.error(
+                ErrorKind::InvalidSliceBounds(start, start.saturating_add(slice_len), array_len),
+                slice.span,
+            ));
+        }
+
+        let len = self.typed.size_of(&elem_typ);
+        Ok((start * len, slice_len * len))
+    }
+}
+
+fn from_bool<F: Zero + One>(b: bool) -> F {
+    if b {
+        F::one()
+    } else {
+        F::zero()
+    }
+}
+
+fn from_int<F: TryFrom<BigUint>>(value: BigUint) -> F {
+    F::try_from(value).unwrap_or_else(|_| unreachable!("the value fits in the field"))
+}
@@ -0,0 +1,262 @@
+//! The `log` builtin, which prints values during witness generation.
+//!
+//! Logging a value doesn't add any constraint to the circuit:
+//! its cells are recorded along with its type and location,
+//! and are only computed (and printed) when a witness is generated with logs enabled.
+
+use std::io::Write;
+
+use ark_ff::{One, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    backends::{Backend, BackendField, BackendVar},
+    compiler::Sources,
+    constants::Span,
+    error::Result,
+    helpers::PrettyField,
+    parser::types::TyKind,
+    type_checker::FullyQualified,
+    utils::find_exact_line,
+    var::ConstOrCell,
+    witness::WitnessEnv,
+};
+
+use super::{CircuitWriter, VarInfo};
+
+/// A value passed to `log`.
+#[derive(Debug, Clone)]
+pub struct LoggedValue<F, C>
+where
+    F: BackendField,
+    C: BackendVar,
+{
+    /// The value, and its type.
+    pub var: VarInfo<F, C>,
+
+    /// The condition of the `if`/`else` branch the value was logged in, if any.
+    /// The value is only printed if the branch is taken.
+    pub condition: Option<ConstOrCell<F, C>>,
+
+    /// The call to `log`.
+    pub span: Span,
+}
+
+impl<B: Backend> CircuitWriter<B> {
+    /// Records a value to be printed during witness generation.
+    pub(crate) fn log(&mut self, var: VarInfo<B::Field, B::Var>, span: Span) {
+        self.logs.push(LoggedValue {
+            var,
+            condition: self.condition.clone(),
+            span,
+        });
+    }
+
+    /// Prints the logged values, along with the call to `log` that produced them.
+    pub(crate) fn print_logs(
+        &self,
+        env: &mut WitnessEnv<B::Field>,
+        sources: &Sources,
+        out: &mut impl Write,
+    ) -> Result<()> {
+        for logged in &self.logs {
+            // skip the values logged in branches that are not taken
+            if let Some(condition) = &logged.condition {
+                if self.compute_cell(env, condition)?.is_zero() {
+                    continue;
+                }
+            }
+
+            let values = logged
+                .var
+                .var
+                .iter()
+                .map(|cvar| self.compute_cell(env, cvar))
+                .collect::<Result<Vec<_>>>()?;
+
+            let value = match &logged.var.typ {
+                Some(typ) => self.format_value(typ, &mut values.iter()),
+                None => format!(
+                    "{:?}",
+                    values.iter().map(|v| v.pretty()).collect::<Vec<_>>()
+                ),
+            };
+
+            let (file, source) = sources
+                .map
+                .get(&logged.span.filename_id)
+                .expect("source not found");
+            let (line_number, _, _) = find_exact_line(source, logged.span);
+            let call = &source[logged.span.start..logged.span.end()];
+
+            writeln!(out, "[{file}:{line_number}] {call} = {value}")
+                .expect("could not print a logged value");
+        }
+
+        Ok(())
+    }
+
+    fn compute_cell(
+        &self,
+        env: &mut WitnessEnv<B::Field>,
+        cvar: &ConstOrCell<B::Field, B::Var>,
+    ) -> Result<B::Field> {
+        match cvar {
+            ConstOrCell::Const(cst) => Ok(*cst),
+            ConstOrCell::Cell(cvar) => self.backend.compute_var(env, cvar),
+        }
+    }
+
+    /// Formats the value of the given type made of the next cells.
+    fn format_value<'a>(
+        &self,
+        typ: &TyKind,
+        values: &mut impl Iterator<Item = &'a B::Field>,
+    ) -> String {
+        match typ {
+            TyKind::Field | TyKind::BigInt => values.next().unwrap().pretty(),
+
+            TyKind::Bool => values.next().unwrap().is_one().to_string(),
+
+            TyKind::U8 | TyKind::U16 | TyKind::U32 | TyKind::U64 => {
+                let value: BigUint = (*values.next().unwrap()).into();
+                value.to_string()
+            }
+
+            TyKind::Array(elem, len) => {
+                let elems: Vec<_> = (0..*len).map(|_| self.format_value(elem, values)).collect();
+                format!("[{}]", elems.join(", "))
+            }
+
+            TyKind::Tuple(typs) => {
+                let elems: Vec<_> = typs
+                    .iter()
+                    .map(|typ| self.format_value(typ, values))
+                    .collect();
+                format!("({})", elems.join(", "))
+            }
+
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => {
+                let qualified = FullyQualified::new(module, name);
+
+                // an enum is laid out as its tag, followed by the values held by its largest variant
+                if let Some(enum_info) = self.enum_info(&qualified) {
+                    let size = self.size_of(typ);
+                    let tag: BigUint = (*values.next().unwrap()).into();
+                    let tag = usize::try_from(tag).expect("invalid enum tag");
+                    let (variant, payload) = &enum_info.variants[tag];
+
+                    let mut used = 1;
+                    let mut fields = Vec::with_capacity(payload.len());
+                    for typ in payload {
+                        used += self.size_of(typ);
+                        fields.push(self.format_value(typ, values));
+                    }
+
+                    // skip the padding of the smaller variants
+                    for _ in used..size {
+                        values.next();
+                    }
+
+                    return if fields.is_empty() {
+                        format!("{name}::{variant}")
+                    } else {
+                        format!("{name}::{variant}({})", fields.join(", "))
+                    };
+                }
+
+                let struct_info = self
+                    .struct_info(&qualified)
+                    .expect("bug in the type checker: cannot find struct info");
+                let fields: Vec<_> = struct_info
+                    .fields_of(sizes)
+                    .iter()
+                    .map(|(field, typ)| format!("{field}: {}", self.format_value(typ, values)))
+                    .collect();
+                format!("{name} {{ {} }}", fields.join(", "))
+            }
+
+            TyKind::GenericSizedArray(..) | TyKind::Generic { .. } => {
+                unreachable!("generic types must be resolved before logging values")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::Zero;
+
+    use crate::{
+        backends::r1cs::{R1csBls12381Field, R1CS},
+        compiler::{compile, typecheck_next_file_inner, Sources},
+        type_checker::TypeChecker,
+        witness::WitnessEnv,
+    };
+
+    #[test]
+    fn test_print_logs() {
+        let code = r#"struct Point {
+    xx: Field,
+    yy: Field,
+}
+
+enum Tx {
+    Transfer(Field, Field),
+    Mint(Field),
+    Burn,
+}
+
+fn main(pub xx: Field, flag: Bool) {
+    let point = Point { xx: xx, yy: 2 };
+    log(point);
+    log([point, Point { xx: 3, yy: xx }]);
+    log(Tx::Mint(xx));
+    log(Tx::Burn);
+    log((flag, [[1, 2], [3, 4]]));
+    if flag {
+        log(xx);
+    }
+    assert_eq(xx, 5);
+}
+"#;
+
+        let mut sources = Sources::new();
+        let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+        typecheck_next_file_inner(
+            &mut tast,
+            None,
+            &mut sources,
+            "example.no".to_string(),
+            code.to_string(),
+            0,
+        )
+        .unwrap();
+        let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+        let mut env = WitnessEnv::default();
+        env.add_value("xx".to_string(), vec![R1csBls12381Field::from(5u64)]);
+        env.add_value("flag".to_string(), vec![R1csBls12381Field::zero()]);
+
+        let mut out = Vec::new();
+        compiled_circuit
+            .circuit
+            .print_logs(&mut env, &sources, &mut out)
+            .unwrap();
+
+        // the value logged in the branch that is not taken is skipped
+        let expected = [
+            "[example.no:14] log(point) = Point { xx: 5, yy: 2 }",
+            "[example.no:15] log([point, Point { xx: 3, yy: xx }]) = [Point { xx: 5, yy: 2 }, Point { xx: 3, yy: 5 }]",
+            "[example.no:16] log(Tx::Mint(xx)) = Tx::Mint(5)",
+            "[example.no:17] log(Tx::Burn) = Tx::Burn",
+            "[example.no:18] log((flag, [[1, 2], [3, 4]])) = (false, [[1, 2], [3, 4]])",
+        ];
+        let printed = String::from_utf8(out).unwrap();
+        assert_eq!(printed.lines().collect::<Vec<_>>(), expected);
+    }
+}
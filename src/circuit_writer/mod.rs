@@ -1,22 +1,28 @@
+use std::sync::Arc;
+
 use crate::{
     backends::Backend,
+    compiler::Sources,
     constants::Span,
     error::{Error, ErrorKind, Result},
     parser::{
         types::{AttributeKind, FnArg, TyKind},
         Expr,
     },
-    type_checker::{ConstInfo, FnInfo, FullyQualified, StructInfo, TypeChecker},
-    var::Var,
+    type_checker::{ConstInfo, EnumInfo, FnInfo, FullyQualified, StructInfo, TypeChecker},
+    var::{ConstOrCell, Var},
     witness::{CompiledCircuit, WitnessEnv},
 };
 
 pub use fn_env::{FnEnv, VarInfo};
+pub use log::LoggedValue;
 use serde::{Deserialize, Serialize};
 //use serde::{Deserialize, Serialize};
 pub use writer::{Gate, GateKind, Wiring};
 
 pub mod fn_env;
+pub mod hint;
+pub mod log;
 pub mod writer;
 
 //#[derive(Debug, Serialize, Deserialize)]
@@ -29,7 +35,8 @@ where
     // Important: this field must not be used directly.
     // This is because, depending on the value of [current_module],
     // the type checker state might be this one, or one of the ones in [dependencies].
-    typed: TypeChecker<B>,
+    // (it is shared with the hints, which need it to run hint functions at witness generation time)
+    typed: Arc<TypeChecker<B>>,
 
     /// The constraint backend for the circuit.
     /// For now, this needs to be exposed for the kimchi prover for kimchi specific low level data.
@@ -45,6 +52,25 @@ where
     /// 3. During witness generation, the public output computation
     ///    is delayed until the very end.
     pub(crate) public_output: Option<Var<B::Field, B::Var>>,
+
+    /// Set when compiling the branch of an `if`/`else` statement,
+    /// to the condition under which that branch is active.
+    /// Assertions are only enforced when this condition holds.
+    pub(crate) condition: Option<ConstOrCell<B::Field, B::Var>>,
+
+    /// Set when the function being compiled returned within an `if`/`else` branch,
+    /// to a flag that is set if the function already returned, and to the value it returned.
+    pub(crate) early_return: Option<(ConstOrCell<B::Field, B::Var>, Var<B::Field, B::Var>)>,
+
+    /// Set when compiling the branches of an `if`/`else` statement of the function being compiled,
+    /// in which case a `return` can't leave the function right away.
+    pub(crate) in_branch: bool,
+
+    /// The values passed to `log`, printed during witness generation if logs are enabled.
+    pub(crate) logs: Vec<LoggedValue<B::Field, B::Var>>,
+
+    /// The number of hint calls made by the circuit so far, used to identify each of them.
+    pub(crate) hint_calls: usize,
 }
 
 /// Debug information related to a single row in a circuit.
@@ -55,6 +81,15 @@ pub struct DebugInfo {
 
     /// A note on why this was added
     pub note: String,
+
+    /// The message of the assertion that created that gate, if any.
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// The message of the assertion that created the second half of that gate, if any
+    /// (for backends that can fit two gates in a single row).
+    #[serde(default)]
+    pub second_message: Option<String>,
 }
 
 impl<B: Backend> CircuitWriter<B> {
@@ -62,6 +97,19 @@ impl<B: Backend> CircuitWriter<B> {
         self.typed.expr_type(expr)
     }
 
+    /// Returns the type of an expression,
+    /// where generic array sizes and generic types are replaced by the ones of the current function call.
+    pub fn resolved_expr_type(
+        &self,
+        fn_env: &FnEnv<B::Field, B::Var>,
+        expr: &Expr,
+    ) -> Option<TyKind> {
+        self.expr_type(expr).map(|typ| {
+            typ.resolve_generic_sizes(fn_env.generic_sizes())
+                .resolve_generic_types(fn_env.generic_types())
+        })
+    }
+
     // TODO: can we get rid of this?
     pub fn node_type(&self, node_id: usize) -> Option<&TyKind> {
         self.typed.node_type(node_id)
@@ -71,6 +119,10 @@ impl<B: Backend> CircuitWriter<B> {
         self.typed.struct_info(qualified)
     }
 
+    pub fn enum_info(&self, qualified: &FullyQualified) -> Option<&EnumInfo> {
+        self.typed.enum_info(qualified)
+    }
+
     pub fn fn_info(&self, qualified: &FullyQualified) -> Option<&FnInfo<B>> {
         self.typed.fn_info(qualified)
     }
@@ -110,7 +162,7 @@ impl<B: Backend> CircuitWriter<B> {
         let qualified = FullyQualified::local(var_name.to_string());
         if let Some(cst_info) = self.typed.const_info(&qualified) {
             let var = Var::new_constant_typ(cst_info, cst_info.typ.span);
-            return VarInfo::new(var, false, Some(TyKind::Field));
+            return VarInfo::new(var, false, Some(cst_info.typ.kind.clone()));
         }
 
         // then check for local variables
@@ -136,9 +188,14 @@ impl<B: Backend> CircuitWriter<B> {
     /// Creates a global environment from the one created by the type checker.
     fn new(typed: TypeChecker<B>, backend: B) -> Self {
         Self {
-            typed,
+            typed: Arc::new(typed),
             backend,
             public_output: None,
+            condition: None,
+            early_return: None,
+            in_branch: false,
+            logs: vec![],
+            hint_calls: 0,
         }
     }
 
@@ -212,11 +269,18 @@ impl<B: Backend> CircuitWriter<B> {
         Ok(CompiledCircuit::new(circuit_writer))
     }
 
-    /// A wrapper for the backend generate_witness
+    /// A wrapper for the backend generate_witness.
+    /// If sources are passed, the logged values are printed along with their location in them.
     pub fn generate_witness(
         &self,
         witness_env: &mut WitnessEnv<B::Field>,
+        logs: Option<&Sources>,
     ) -> Result<B::GeneratedWitness> {
+        // logs are printed first, as they are most useful when the witness is invalid
+        if let Some(sources) = logs {
+            self.print_logs(witness_env, sources, &mut std::io::stdout())?;
+        }
+
         self.backend.generate_witness(witness_env)
     }
 
@@ -229,17 +293,7 @@ impl<B: Backend> CircuitWriter<B> {
         let FnArg { name, typ, .. } = arg;
 
         // get length
-        let len = match &typ.kind {
-            TyKind::Field => 1,
-            TyKind::Array(typ, len) => {
-                if !matches!(**typ, TyKind::Field) {
-                    unimplemented!();
-                }
-                *len as usize
-            }
-            TyKind::Bool => 1,
-            typ => self.size_of(typ),
-        };
+        let len = self.size_of(&typ.kind);
 
         // create the variable
         let var = handle_input(self, name.value.clone(), len, name.span);
@@ -254,7 +308,7 @@ impl<B: Backend> CircuitWriter<B> {
         self.constrain_inputs_to_main(&var.cvars, &typ.kind, typ.span)?;
 
         // add argument variable to the ast env
-        let mutable = false; // TODO: should we add a mut keyword in arguments as well?
+        let mutable = arg.is_mutable();
         let var_info = VarInfo::new(var, mutable, Some(typ.kind.clone()));
         self.add_local_var(fn_env, name.value.clone(), var_info);
 
@@ -1,4 +1,7 @@
-use std::fmt::{self, Display, Formatter};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
 
 use ark_ff::{One, Zero};
 use kimchi::circuits::wires::Wire;
@@ -10,13 +13,18 @@ use crate::{
     backends::{kimchi::VestaField, Backend},
     circuit_writer::{CircuitWriter, DebugInfo, FnEnv, VarInfo},
     constants::Span,
-    constraints::{boolean, field},
-    error::{ErrorKind, Result},
+    constraints::{
+        array,
+        bitwise::{self, Shift},
+        boolean, comparison, field, uint,
+    },
+    error::{Error, ErrorKind, Result},
     imports::FnKind,
     parser::{
-        types::{FunctionDef, Stmt, StmtKind, TyKind},
-        Expr, ExprKind, Op2,
+        types::{FunctionDef, Ident, Pattern, Stmt, StmtKind, TyKind},
+        Expr, ExprKind, MatchArm, MatchPattern, Op2,
     },
+    stdlib::split_assert_message,
     syntax::is_type,
     type_checker::FullyQualified,
     var::{ConstOrCell, Value, Var, VarOrRef},
@@ -31,6 +39,10 @@ pub enum GateKind {
     Zero,
     DoubleGeneric,
     Poseidon,
+    Xor16,
+    Rot64,
+    RangeCheck0,
+    Lookup,
 }
 
 impl From<GateKind> for kimchi::circuits::gate::GateType {
@@ -40,6 +52,10 @@ impl From<GateKind> for kimchi::circuits::gate::GateType {
             GateKind::Zero => Zero,
             GateKind::DoubleGeneric => Generic,
             GateKind::Poseidon => Poseidon,
+            GateKind::Xor16 => Xor16,
+            GateKind::Rot64 => Rot64,
+            GateKind::RangeCheck0 => RangeCheck0,
+            GateKind::Lookup => Lookup,
         }
     }
 }
@@ -109,6 +125,36 @@ impl Ord for AnnotatedCell {
     }
 }
 
+/// A step from a variable to one of its parts, on the left-hand side of an assignment.
+enum PlaceStep<B: Backend> {
+    /// A range of cells known at compile time
+    /// (a field, a tuple element, or an array element at a constant index).
+    Range { start: usize, len: usize },
+
+    /// An array element at an index only known at proving time,
+    /// where each element is made of `elem_len` cells.
+    Index {
+        idx: ConstOrCell<B::Field, B::Var>,
+        elem_len: usize,
+    },
+}
+
+impl<B: Backend> PlaceStep<B> {
+    /// Adds a range to the steps, merging it with the previous step if it is a range as well.
+    fn push_range(steps: &mut Vec<Self>, start: usize, len: usize) {
+        match steps.last_mut() {
+            Some(PlaceStep::Range {
+                start: prev_start,
+                len: prev_len,
+            }) => {
+                *prev_start += start;
+                *prev_len = len;
+            }
+            _ => steps.push(PlaceStep::Range { start, len }),
+        }
+    }
+}
+
 //
 // Circuit Writer (also used by witness generation)
 //
@@ -120,7 +166,9 @@ impl<B: Backend> CircuitWriter<B> {
         stmt: &Stmt,
     ) -> Result<Option<VarOrRef<B>>> {
         match &stmt.kind {
-            StmtKind::Assign { mutable, lhs, rhs } => {
+            StmtKind::Assign {
+                mutable, lhs, rhs, ..
+            } => {
                 // compute the rhs
                 let rhs_var = self
                     .compute_expr(fn_env, rhs)?
@@ -129,27 +177,102 @@ impl<B: Backend> CircuitWriter<B> {
                 // obtain the actual values
                 let rhs_var = rhs_var.value(self, fn_env);
 
-                let typ = self.expr_type(rhs).cloned();
-                let var_info = VarInfo::new(rhs_var, *mutable, typ);
+                let typ = self.resolved_expr_type(fn_env, rhs);
+
+                match lhs {
+                    Pattern::Ident(lhs) => {
+                        let var_info = VarInfo::new(rhs_var, *mutable, typ);
 
-                // store the new variable
-                // TODO: do we really need to store that in the scope? That's not an actual var in the scope that's an internal var...
-                self.add_local_var(fn_env, lhs.value.clone(), var_info);
+                        // store the new variable
+                        // TODO: do we really need to store that in the scope? That's not an actual var in the scope that's an internal var...
+                        self.add_local_var(fn_env, lhs.value.clone(), var_info);
+                    }
+                    Pattern::Tuple(names) => {
+                        let typs = match typ {
+                            Some(TyKind::Tuple(typs)) => typs,
+                            _ => panic!("type checker bug: destructuring a non-tuple"),
+                        };
+
+                        // split the tuple like we would split a struct
+                        let mut start = 0;
+                        for (name, typ) in names.iter().zip(typs) {
+                            let len = self.size_of(&typ);
+                            let var = Var::new(rhs_var.range(start, len).to_vec(), name.span);
+                            start += len;
+
+                            let var_info = VarInfo::new(var, *mutable, Some(typ));
+                            self.add_local_var(fn_env, name.value.clone(), var_info);
+                        }
+                    }
+                }
             }
 
             StmtKind::ForLoop { var, range, body } => {
-                for ii in range.range() {
+                let start = self.compute_range_bound(fn_env, &range.start)?;
+                let end = self.compute_range_bound(fn_env, &range.end)?;
+
+                for ii in start..end {
                     fn_env.nest();
 
                     let cst_var = Var::new_constant(ii.into(), var.span);
                     let var_info = VarInfo::new(cst_var, false, Some(TyKind::Field));
                     self.add_local_var(fn_env, var.value.clone(), var_info);
 
-                    self.compile_block(fn_env, body)?;
+                    if let Some(var) = self.compile_block(fn_env, body)? {
+                        return Ok(Some(VarOrRef::Var(var)));
+                    }
 
                     fn_env.pop();
                 }
             }
+            StmtKind::ForIn {
+                index,
+                var,
+                array,
+                body,
+            } => {
+                // the array is evaluated once, before the loop
+                let array_var = self
+                    .compute_expr(fn_env, array)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, array.span))?
+                    .value(self, fn_env);
+
+                let (elem_typ, len) = match self.resolved_expr_type(fn_env, array) {
+                    Some(TyKind::Array(typ, len)) => (*typ, len as usize),
+                    _ => panic!("type checker bug: iteration over a non-array"),
+                };
+                let elem_len = self.size_of(&elem_typ);
+
+                for ii in 0..len {
+                    fn_env.nest();
+
+                    if let Some(index) = index {
+                        let cst_var = Var::new_constant((ii as u32).into(), index.span);
+                        let var_info = VarInfo::new(cst_var, false, Some(TyKind::Field));
+                        self.add_local_var(fn_env, index.value.clone(), var_info);
+                    }
+
+                    let start = ii * elem_len;
+                    let elem =
+                        Var::new(array_var.cvars[start..start + elem_len].to_vec(), var.span);
+                    let var_info = VarInfo::new(elem, false, Some(elem_typ.clone()));
+                    self.add_local_var(fn_env, var.value.clone(), var_info);
+
+                    if let Some(var) = self.compile_block(fn_env, body)? {
+                        return Ok(Some(VarOrRef::Var(var)));
+                    }
+
+                    fn_env.pop();
+                }
+            }
+            StmtKind::IfElse { cond, then_, else_ } => {
+                let cond = self
+                    .compute_expr(fn_env, cond)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, cond.span))?
+                    .value(self, fn_env);
+
+                self.compile_if_else(fn_env, &cond[0], then_, else_, stmt.span)?;
+            }
             StmtKind::Expr(expr) => {
                 // compute the expression
                 let var = self.compute_expr(fn_env, expr)?;
@@ -162,8 +285,13 @@ impl<B: Backend> CircuitWriter<B> {
                     .compute_expr(fn_env, expr)?
                     .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, stmt.span))?;
 
-                // we already checked in type checking that this is not an early return
-                return Ok(Some(var));
+                // outside of any branch, we can leave the function right away
+                if !self.in_branch && self.early_return.is_none() {
+                    return Ok(Some(var));
+                }
+
+                let var = var.value(self, fn_env);
+                self.compile_early_return(var, stmt.span);
             }
             StmtKind::Comment(_) => (),
         }
@@ -171,6 +299,281 @@ impl<B: Backend> CircuitWriter<B> {
         Ok(None)
     }
 
+    /// Computes the bound of a range, which must be known at compile time.
+    fn compute_range_bound(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        bound: &Expr,
+    ) -> Result<u32> {
+        let var = self
+            .compute_expr(fn_env, bound)?
+            .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, bound.span))?;
+        // (a `const` argument might have been passed a variable)
+        let bound_value = var
+            .constant()
+            .ok_or_else(|| self.error(ErrorKind::NonConstantRangeBound, bound.span))?;
+        let bound_value: BigUint = bound_value.into();
+        bound_value
+            .try_into()
+            .map_err(|_| self.error(ErrorKind::InvalidRangeSize, bound.span))
+    }
+
+    /// Computes the amount of a shift, which must be a constant smaller than the bit length.
+    pub(crate) fn compute_shift(
+        &self,
+        shift: &Var<B::Field, B::Var>,
+        bitlen: usize,
+        span: Span,
+    ) -> Result<usize> {
+        let shift: BigUint = shift
+            .constant()
+            .ok_or_else(|| self.error(ErrorKind::InvalidShiftAmount, span))?
+            .into();
+
+        match usize::try_from(shift) {
+            Ok(shift) if shift < bitlen => Ok(shift),
+            _ => Err(self.error(ErrorKind::InvalidShiftAmount, span)),
+        }
+    }
+
+    /// Compiles a `return` that might not be reached.
+    /// The returned value is accumulated with the ones of previous returns,
+    /// and the rest of the function is made inactive.
+    fn compile_early_return(&mut self, var: Var<B::Field, B::Var>, span: Span) {
+        let active = self
+            .condition
+            .clone()
+            .unwrap_or(ConstOrCell::Const(B::Field::one()));
+
+        let early_return = match self.early_return.take() {
+            None => (active.clone(), var),
+            Some((returned, prev)) => {
+                // the previous returns are mutually exclusive with this one,
+                // as they made the rest of the function inactive
+                let mut cvars = Vec::with_capacity(var.len());
+                for (new_cvar, prev_cvar) in var.iter().zip(prev.iter()) {
+                    let res = field::if_else_inner(self, &active, new_cvar, prev_cvar, span);
+                    cvars.push(res[0].clone());
+                }
+                let returned = field::add(self, &returned, &active, span)[0].clone();
+
+                (returned, Var::new(cvars, var.span))
+            }
+        };
+
+        self.early_return = Some(early_return);
+        self.condition = Some(ConstOrCell::Const(B::Field::zero()));
+    }
+
+    /// Compiles an `if`/`else` statement.
+    /// Both branches are compiled, but their side effects are conditioned on the branch being active:
+    /// assertions are only enforced in the active branch,
+    /// and the mutable variables are set to the values of the active branch.
+    fn compile_if_else(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        cond: &ConstOrCell<B::Field, B::Var>,
+        then_: &[Stmt],
+        else_: &[Stmt],
+        span: Span,
+    ) -> Result<()> {
+        // a branch is active if its condition holds, and if we're in an active branch already
+        let outer_condition = self.condition.clone();
+        let not_cond = boolean::not(self, cond, span)[0].clone();
+        let (then_condition, else_condition) = match &outer_condition {
+            None => (cond.clone(), not_cond),
+            Some(outer) => (
+                boolean::and(self, outer, cond, span)[0].clone(),
+                boolean::and(self, outer, &not_cond, span)[0].clone(),
+            ),
+        };
+
+        // compile each branch on its own copy of the environment
+        let before = fn_env.clone();
+        let in_branch = std::mem::replace(&mut self.in_branch, true);
+        let returned_before = self
+            .early_return
+            .as_ref()
+            .map(|(returned, _)| returned.clone());
+
+        self.condition = Some(then_condition);
+        self.compile_block(fn_env, then_)?;
+        let then_env = std::mem::replace(fn_env, before);
+
+        self.condition = Some(else_condition);
+        self.compile_block(fn_env, else_)?;
+
+        self.in_branch = in_branch;
+
+        // if one of the branches returned, the rest of the function is only active if we haven't returned
+        let returned = self
+            .early_return
+            .as_ref()
+            .map(|(returned, _)| returned.clone());
+        self.condition = match returned {
+            Some(returned) if Some(&returned) != returned_before.as_ref() => {
+                let not_returned = boolean::not(self, &returned, span)[0].clone();
+                match &outer_condition {
+                    None => Some(not_returned),
+                    Some(outer) => Some(boolean::and(self, outer, &not_returned, span)[0].clone()),
+                }
+            }
+            _ => outer_condition,
+        };
+
+        // merge the mutations of the two branches
+        for var_name in fn_env.mutable_vars() {
+            let then_var = then_env.get_local_var(&var_name).var;
+            let else_var = fn_env.get_local_var(&var_name).var;
+
+            let mut cvars = Vec::with_capacity(else_var.len());
+            for (then_cvar, else_cvar) in then_var.iter().zip(else_var.iter()) {
+                if then_cvar == else_cvar {
+                    cvars.push(else_cvar.clone());
+                } else {
+                    let res = field::if_else_inner(self, cond, then_cvar, else_cvar, span);
+                    cvars.push(res[0].clone());
+                }
+            }
+
+            fn_env.reassign_local_var(&var_name, Var::new(cvars, else_var.span));
+        }
+
+        Ok(())
+    }
+
+    /// Compiles a `match` expression.
+    /// Each arm is compiled under the condition that the matched value is of the arm's variant,
+    /// and the values (and mutations) of the arms are then merged.
+    fn compile_match(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        scrutinee: &Expr,
+        arms: &[MatchArm],
+        span: Span,
+    ) -> Result<Option<VarOrRef<B>>> {
+        let enum_info = match self.expr_type(scrutinee) {
+            Some(TyKind::Custom { module, name, .. }) => self
+                .enum_info(&FullyQualified::new(module, name))
+                .expect("type checker bug: match on a non-enum")
+                .clone(),
+            _ => panic!("type checker bug: match on a non-enum"),
+        };
+
+        let var = self
+            .compute_expr(fn_env, scrutinee)?
+            .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, scrutinee.span))?
+            .value(self, fn_env);
+        let tag = Var::new_cvar(var[0].clone(), span);
+
+        // figure out which arm is selected
+        // (a wildcard is selected if none of the arms before it are)
+        let mut selected = Vec::with_capacity(arms.len());
+        let mut any_selected = ConstOrCell::Const(B::Field::zero());
+        for arm in arms {
+            let is_selected = match &arm.pattern {
+                MatchPattern::Variant { variant, .. } => {
+                    let (variant_tag, _) = enum_info
+                        .variant(&variant.value)
+                        .expect("type checker bug: unknown variant");
+                    let variant_tag = Var::new_constant(B::Field::from(variant_tag as u64), span);
+                    field::equal(self, &tag, &variant_tag, span)[0].clone()
+                }
+                MatchPattern::Wildcard(_) => boolean::not(self, &any_selected, span)[0].clone(),
+            };
+
+            any_selected = field::add(self, &any_selected, &is_selected, span)[0].clone();
+            selected.push(is_selected);
+        }
+
+        // compile each arm on its own copy of the environment
+        let outer_condition = self.condition.clone();
+        let before = fn_env.clone();
+        let mut results = Vec::with_capacity(arms.len());
+        let mut envs = Vec::with_capacity(arms.len());
+
+        for (arm, is_selected) in arms.iter().zip(&selected) {
+            // an arm is active if it is selected, and if we're in an active branch already
+            self.condition = Some(match &outer_condition {
+                None => is_selected.clone(),
+                Some(outer) => boolean::and(self, outer, is_selected, span)[0].clone(),
+            });
+
+            *fn_env = before.clone();
+            fn_env.nest();
+
+            // the values held by the variant are laid out after the tag
+            if let MatchPattern::Variant {
+                variant, bindings, ..
+            } = &arm.pattern
+            {
+                let (_, payload) = enum_info
+                    .variant(&variant.value)
+                    .expect("type checker bug: unknown variant");
+
+                let mut start = 1;
+                for (binding, typ) in bindings.iter().zip(payload) {
+                    let len = self.size_of(typ);
+                    let value = Var::new(var.range(start, len).to_vec(), binding.span);
+                    start += len;
+
+                    let var_info = VarInfo::new(value, false, Some(typ.clone()));
+                    self.add_local_var(fn_env, binding.value.clone(), var_info);
+                }
+            }
+
+            let res = self
+                .compute_expr(fn_env, &arm.expr)?
+                .map(|res| res.value(self, fn_env));
+            results.push(res);
+
+            fn_env.pop();
+            envs.push(fn_env.clone());
+        }
+
+        self.condition = outer_condition;
+        *fn_env = before;
+
+        // merge the values and the mutations of the arms,
+        // starting from the last arm (exactly one of the arms is selected)
+        let mut merged = results.pop().expect("type checker bug: empty match");
+        for (res, is_selected) in results.into_iter().zip(&selected).rev() {
+            merged = match (res, merged) {
+                (Some(then_), Some(else_)) => {
+                    let cond = Var::new_cvar(is_selected.clone(), span);
+                    Some(field::if_else(self, &cond, &then_, &else_, span))
+                }
+                (None, None) => None,
+                _ => unreachable!("type checker bug: match arms of different types"),
+            };
+        }
+
+        let last_env = envs.pop().expect("type checker bug: empty match");
+        for var_name in fn_env.mutable_vars() {
+            let mut merged_var = last_env.get_local_var(&var_name).var;
+            for (env, is_selected) in envs.iter().zip(&selected).rev() {
+                let arm_var = env.get_local_var(&var_name).var;
+
+                let mut cvars = Vec::with_capacity(merged_var.len());
+                for (arm_cvar, merged_cvar) in arm_var.iter().zip(merged_var.iter()) {
+                    if arm_cvar == merged_cvar {
+                        cvars.push(merged_cvar.clone());
+                    } else {
+                        let res =
+                            field::if_else_inner(self, is_selected, arm_cvar, merged_cvar, span);
+                        cvars.push(res[0].clone());
+                    }
+                }
+
+                merged_var = Var::new(cvars, merged_var.span);
+            }
+
+            fn_env.reassign_local_var(&var_name, merged_var);
+        }
+
+        Ok(merged.map(VarOrRef::Var))
+    }
+
     /// might return something?
     fn compile_block(
         &mut self,
@@ -192,25 +595,141 @@ impl<B: Backend> CircuitWriter<B> {
         Ok(None)
     }
 
+    /// Compiles a call to a function defined in noname.
+    /// The `mut` arguments are updated with the values they have at the end of the function.
     fn compile_native_function_call(
         &mut self,
         function: &FunctionDef,
-        args: Vec<VarInfo<B::Field, B::Var>>,
+        args: &mut [VarInfo<B::Field, B::Var>],
+        span: Span,
     ) -> Result<Option<Var<B::Field, B::Var>>> {
         assert!(!function.is_main());
 
-        // create new fn_env
-        let fn_env = &mut FnEnv::new();
-
         // set arguments
         assert_eq!(function.sig.arguments.len(), args.len());
 
-        for (name, var_info) in function.sig.arguments.iter().zip(args) {
-            self.add_local_var(fn_env, name.name.value.clone(), var_info);
+        // infer the sizes of generic arrays and the generic types from the arguments
+        // (the function is essentially monomorphized for these)
+        let mut generic_sizes = HashMap::new();
+        let mut generic_types = HashMap::new();
+        for (arg, var_info) in function.sig.arguments.iter().zip(args.iter()) {
+            if let Some(typ) = &var_info.typ {
+                arg.typ
+                    .kind
+                    .bind_generic_sizes(typ, &mut generic_sizes)
+                    .expect("type checker bug: conflicting generic sizes");
+                arg.typ
+                    .kind
+                    .bind_generic_types(typ, &mut generic_types)
+                    .expect("type checker bug: conflicting generic types");
+            }
+        }
+
+        // hint functions are run at witness generation time instead
+        if function.sig.hint {
+            let res = self.compile_hint_call(function, args, generic_sizes, generic_types, span)?;
+            return Ok(Some(res));
+        }
+
+        // create new fn_env
+        let fn_env = &mut FnEnv::with_generics(generic_sizes, generic_types);
+
+        for (name, var_info) in function.sig.arguments.iter().zip(args.iter()) {
+            self.add_local_var(fn_env, name.name.value.clone(), var_info.clone());
         }
 
         // compile it and potentially return a return value
-        self.compile_block(fn_env, &function.body)
+        let returned = self.compile_fn_body(fn_env, function)?;
+
+        for (arg, var_info) in function.sig.arguments.iter().zip(args.iter_mut()) {
+            if var_info.mutable {
+                var_info.var = fn_env.get_local_var(&arg.name.value).var;
+            }
+        }
+
+        Ok(returned)
+    }
+
+    /// Writes the values of the `mut` arguments at the end of a call back to the caller's variables.
+    fn write_back_args(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        places: Vec<Option<(String, usize, usize)>>,
+        args: Vec<VarInfo<B::Field, B::Var>>,
+        span: Span,
+    ) {
+        for (place, var_info) in places.into_iter().zip(args) {
+            if let Some((var_name, start, len)) = place {
+                self.reassign_var_range(fn_env, &var_name, var_info.var, start, len, span);
+            }
+        }
+    }
+
+    /// Reassigns a range of a mutable variable.
+    /// After an early return the function might have already returned,
+    /// in which case the variable must keep its value (as it might be a `mut` argument of the function).
+    fn reassign_var_range(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        var_name: &str,
+        var: Var<B::Field, B::Var>,
+        start: usize,
+        len: usize,
+        span: Span,
+    ) {
+        let var = match self.condition.clone() {
+            Some(condition) if self.early_return.is_some() => {
+                let old_var = self.get_local_var(fn_env, var_name).var;
+
+                let mut cvars = Vec::with_capacity(len);
+                for (new_cvar, old_cvar) in var.iter().zip(old_var.range(start, len)) {
+                    let res = field::if_else_inner(self, &condition, new_cvar, old_cvar, span);
+                    cvars.push(res[0].clone());
+                }
+
+                Var::new(cvars, var.span)
+            }
+            _ => var,
+        };
+
+        fn_env.reassign_var_range(var_name, var, start, len);
+    }
+
+    /// Returns the variable (and the range within it) behind an argument passed as `mut`,
+    /// so that its value can be written back after the call.
+    fn mutable_place(var: &VarOrRef<B>) -> (String, usize, usize) {
+        match var {
+            VarOrRef::Ref {
+                var_name,
+                start,
+                len,
+            } => (var_name.clone(), *start, *len),
+            VarOrRef::Var(_) => panic!("type checker bug: `mut` argument is not a mutable place"),
+        }
+    }
+
+    /// Compiles the body of a function, and returns its return value (if any).
+    /// The body is compiled with its own early return state,
+    /// but under the condition of the caller (as we might be in a branch).
+    fn compile_fn_body(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        function: &FunctionDef,
+    ) -> Result<Option<Var<B::Field, B::Var>>> {
+        let condition = self.condition.clone();
+        let early_return = self.early_return.take();
+        let in_branch = std::mem::replace(&mut self.in_branch, false);
+
+        let returned = self.compile_block(fn_env, &function.body)?;
+
+        // if the function didn't return right away, it returned within branches
+        let returned = returned.or_else(|| self.early_return.take().map(|(_, var)| var));
+
+        self.condition = condition;
+        self.early_return = early_return;
+        self.in_branch = in_branch;
+
+        Ok(returned)
     }
 
     pub(crate) fn constrain_inputs_to_main(
@@ -223,7 +742,29 @@ impl<B: Backend> CircuitWriter<B> {
             TyKind::Field => (),
             TyKind::Bool => {
                 assert_eq!(input.len(), 1);
-                boolean::check(self, &input[0], span);
+
+                // (the values held by an enum are only constrained if its variant is the one in use)
+                match self.condition.clone() {
+                    None => boolean::check(self, &input[0], span),
+                    Some(condition) => {
+                        let gated = field::mul(self, &condition, &input[0], span);
+                        boolean::check(self, &gated[0], span);
+                    }
+                }
+            }
+            TyKind::U8 | TyKind::U16 | TyKind::U32 | TyKind::U64 => {
+                assert_eq!(input.len(), 1);
+                let bitlen = input_typ.int_bitlen().unwrap();
+                uint::range_check(self, &input[0], bitlen, span)?;
+            }
+            TyKind::Tuple(typs) => {
+                let mut offset = 0;
+                for typ in typs {
+                    let len = self.size_of(typ);
+                    let range = offset..(offset + len);
+                    self.constrain_inputs_to_main(&input[range], typ, span)?;
+                    offset += len;
+                }
             }
             TyKind::Array(tykind, _) => {
                 let el_size = self.size_of(tykind);
@@ -231,9 +772,60 @@ impl<B: Backend> CircuitWriter<B> {
                     self.constrain_inputs_to_main(el, tykind, span)?;
                 }
             }
+            // the tag of an enum must be one of its variants,
+            // and the cells that its variant doesn't use must be zero
+            TyKind::Custom { module, name, .. }
+                if self.enum_info(&FullyQualified::new(module, name)).is_some() =>
+            {
+                let qualified = FullyQualified::new(module, name);
+                let enum_info = self
+                    .enum_info(&qualified)
+                    .expect("enum info not found for custom enum")
+                    .clone();
+
+                let outer_condition = self.condition.clone();
+                let tag = Var::new_cvar(input[0].clone(), span);
+                let mut is_valid = ConstOrCell::Const(B::Field::zero());
+
+                for (variant_tag, (_, payload)) in enum_info.variants.iter().enumerate() {
+                    let variant_tag = Var::new_constant(B::Field::from(variant_tag as u64), span);
+                    let is_variant = field::equal(self, &tag, &variant_tag, span)[0].clone();
+                    is_valid = field::add(self, &is_valid, &is_variant, span)[0].clone();
+
+                    // the cells of a variant are only constrained if it is the one in use
+                    let condition = match &outer_condition {
+                        None => is_variant,
+                        Some(outer) => boolean::and(self, outer, &is_variant, span)[0].clone(),
+                    };
+                    self.condition = Some(condition.clone());
+
+                    let mut offset = 1;
+                    for typ in payload {
+                        let len = self.size_of(typ);
+                        let range = offset..(offset + len);
+                        self.constrain_inputs_to_main(&input[range], typ, span)?;
+                        offset += len;
+                    }
+
+                    for unused in &input[offset..] {
+                        let gated = field::mul(self, &condition, unused, span);
+                        self.assert_input_zero(&gated[0], span);
+                    }
+                }
+
+                self.condition = outer_condition.clone();
+
+                let not_valid = boolean::not(self, &is_valid, span)[0].clone();
+                let not_valid = match &outer_condition {
+                    None => not_valid,
+                    Some(outer) => field::mul(self, outer, &not_valid, span)[0].clone(),
+                };
+                self.assert_input_zero(&not_valid, span);
+            }
             TyKind::Custom {
                 module,
                 name: struct_name,
+                sizes,
             } => {
                 let qualified = FullyQualified::new(module, &struct_name);
                 let struct_info = self
@@ -242,7 +834,7 @@ impl<B: Backend> CircuitWriter<B> {
                     .clone();
 
                 let mut offset = 0;
-                for (_field_name, field_typ) in &struct_info.fields {
+                for (_field_name, field_typ) in &struct_info.fields_of(sizes) {
                     let len = self.size_of(field_typ);
                     let range = offset..(offset + len);
                     self.constrain_inputs_to_main(&input[range], field_typ, span)?;
@@ -250,10 +842,23 @@ impl<B: Backend> CircuitWriter<B> {
                 }
             }
             TyKind::BigInt => unreachable!(),
+            TyKind::GenericSizedArray(..) | TyKind::Generic { .. } => {
+                unreachable!("main can't have generic arguments")
+            }
         };
         Ok(())
     }
 
+    /// Constrains a cell computed from the inputs of the main function to be zero.
+    fn assert_input_zero(&mut self, cell: &ConstOrCell<B::Field, B::Var>, span: Span) {
+        match cell {
+            ConstOrCell::Cell(cvar) => self.backend.assert_eq_const(cvar, B::Field::zero(), span),
+            ConstOrCell::Const(_) => {
+                unreachable!("the inputs of the main function are not constants")
+            }
+        }
+    }
+
     /// Compile a function. Used to compile `main()` only for now
     pub(crate) fn compile_main_function(
         &mut self,
@@ -263,7 +868,7 @@ impl<B: Backend> CircuitWriter<B> {
         assert!(function.is_main());
 
         // compile the block
-        let returned = self.compile_block(fn_env, &function.body)?;
+        let returned = self.compile_fn_body(fn_env, function)?;
 
         // we're expecting something returned?
         match (function.sig.return_type.as_ref(), returned) {
@@ -291,6 +896,157 @@ impl<B: Backend> CircuitWriter<B> {
         }
     }
 
+    /// Returns the range of cells of a field within the struct that `struct_expr` evaluates to.
+    fn field_range(
+        &self,
+        fn_env: &FnEnv<B::Field, B::Var>,
+        struct_expr: &Expr,
+        field: &Ident,
+    ) -> Result<(usize, usize)> {
+        // get struct info behind the expression
+        // (the generic array sizes of the struct might be the ones of the current function call)
+        let struct_typ = self
+            .resolved_expr_type(fn_env, struct_expr)
+            .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, struct_expr.span))?;
+
+        let (module, struct_name, sizes) = match &struct_typ {
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => (module, name, sizes),
+            _ => {
+                panic!("could not figure out struct implementing that method call")
+            }
+        };
+
+        let qualified = FullyQualified::new(module, struct_name);
+        let struct_info = self
+            .struct_info(&qualified)
+            .expect("struct info not found for custom struct");
+
+        let mut start = 0;
+        let mut len = 0;
+        for (field_name, field_typ) in &struct_info.fields_of(sizes) {
+            if field_name == &field.value {
+                len = self.size_of(field_typ);
+                break;
+            }
+
+            start += self.size_of(field_typ);
+        }
+
+        Ok((start, len))
+    }
+
+    /// Computes the place on the left-hand side of an assignment,
+    /// as the name of the local variable it belongs to and the steps to get to it from that variable
+    /// (for example, `state.accounts[ii].balance` is a field of an element of a field of `state`).
+    fn compute_place(
+        &mut self,
+        fn_env: &mut FnEnv<B::Field, B::Var>,
+        expr: &Expr,
+    ) -> Result<(String, Vec<PlaceStep<B>>)> {
+        match &expr.kind {
+            ExprKind::Variable { name, .. } => Ok((name.value.clone(), vec![])),
+
+            ExprKind::FieldAccess { lhs, rhs } => {
+                let (var_name, mut steps) = self.compute_place(fn_env, lhs)?;
+                let (start, len) = self.field_range(fn_env, lhs, rhs)?;
+                PlaceStep::push_range(&mut steps, start, len);
+                Ok((var_name, steps))
+            }
+
+            ExprKind::TupleAccess { lhs, idx } => {
+                let (var_name, mut steps) = self.compute_place(fn_env, lhs)?;
+
+                let typs = match self.resolved_expr_type(fn_env, lhs) {
+                    Some(TyKind::Tuple(typs)) => typs,
+                    _ => panic!("type checker bug: tuple access on a non-tuple"),
+                };
+                let start = typs[..*idx].iter().map(|typ| self.size_of(typ)).sum();
+                let len = self.size_of(&typs[*idx]);
+
+                PlaceStep::push_range(&mut steps, start, len);
+                Ok((var_name, steps))
+            }
+
+            ExprKind::ArrayAccess { array, idx } => {
+                let (var_name, mut steps) = self.compute_place(fn_env, array)?;
+
+                let (elem_type, array_len) = match self.resolved_expr_type(fn_env, array) {
+                    Some(TyKind::Array(ty, array_len)) => (*ty, array_len as usize),
+                    _ => panic!("expected array"),
+                };
+                let len = self.size_of(&elem_type);
+
+                let idx_var = self
+                    .compute_expr(fn_env, idx)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, idx.span))?
+                    .value(self, fn_env);
+
+                match idx_var.constant() {
+                    Some(idx) => {
+                        let idx: BigUint = idx.into();
+                        let idx: usize = idx.try_into().unwrap();
+
+                        if idx >= array_len {
+                            return Err(self.error(
+                                ErrorKind::ArrayIndexOutOfBounds(idx, array_len - 1),
+                                expr.span,
+                            ));
+                        }
+
+                        PlaceStep::push_range(&mut steps, idx * len, len);
+                    }
+                    None => steps.push(PlaceStep::Index {
+                        idx: idx_var[0].clone(),
+                        elem_len: len,
+                    }),
+                }
+
+                Ok((var_name, steps))
+            }
+
+            _ => panic!("type checker bug: cannot assign to this expression"),
+        }
+    }
+
+    /// Returns a copy of `var` where the part at the end of the steps is replaced by `value`.
+    fn replace_place(
+        &mut self,
+        var: Var<B::Field, B::Var>,
+        steps: &[PlaceStep<B>],
+        value: Var<B::Field, B::Var>,
+        span: Span,
+    ) -> Var<B::Field, B::Var> {
+        match steps.split_first() {
+            None => value,
+
+            Some((PlaceStep::Range { start, len }, rest)) => {
+                let inner = Var::new(var.range(*start, *len).to_vec(), var.span);
+                let inner = self.replace_place(inner, rest, value, span);
+
+                let mut cvars = var.cvars.clone();
+                cvars[*start..*start + *len].clone_from_slice(&inner.cvars);
+                Var::new(cvars, var.span)
+            }
+
+            Some((PlaceStep::Index { idx, elem_len }, rest)) => {
+                // the same selector is used to read the element and to write it back
+                let selector = array::selector(self, idx, var.len() / elem_len, span);
+                let inner = if rest.is_empty() {
+                    value
+                } else {
+                    let inner = array::select(self, &var, &selector, *elem_len, span);
+                    self.replace_place(inner, rest, value, span)
+                };
+
+                array::replace(self, &var, &selector, &inner, span)
+            }
+        }
+    }
+
     fn compute_expr(
         &mut self,
         fn_env: &mut FnEnv<B::Field, B::Var>,
@@ -320,21 +1076,27 @@ impl<B: Backend> CircuitWriter<B> {
                     })?
                     .clone();
 
+                // assertions can be passed a message, which is not part of their signature
+                let (args, message) = split_assert_message(&fn_info, args);
+
                 // compute the arguments
                 // module::fn_name(args)
                 //                 ^^^^
                 let mut vars = Vec::with_capacity(args.len());
-                for arg in args {
+                let mut places = Vec::with_capacity(args.len());
+                for (sig_arg, arg) in fn_info.sig().arguments.iter().zip(args) {
                     // get the variable behind the expression
                     let var = self
                         .compute_expr(fn_env, arg)?
                         .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, arg.span))?;
 
-                    // we pass variables by values always
+                    // `mut` arguments are written back to the caller's variable after the call,
+                    // the others are passed by value
+                    let mutable = sig_arg.is_mutable();
+                    places.push(mutable.then(|| Self::mutable_place(&var)));
                     let var = var.value(self, fn_env);
 
-                    let typ = self.expr_type(arg).cloned();
-                    let mutable = false; // TODO: mut keyword in arguments?
+                    let typ = self.resolved_expr_type(fn_env, arg);
                     let var_info = VarInfo::new(var, mutable, typ);
 
                     vars.push(var_info);
@@ -343,8 +1105,25 @@ impl<B: Backend> CircuitWriter<B> {
                 let res = match &fn_info.kind {
                     // assert() <-- for example
                     FnKind::BuiltIn(_sig, handle) => {
+                        // the constraints added by an assertion carry its message
+                        self.backend
+                            .set_assert_message(message.map(ToString::to_string));
                         let res = handle(self, &vars, expr.span);
-                        res.map(|r| r.map(VarOrRef::Var))
+                        self.backend.set_assert_message(None);
+
+                        match (res, message) {
+                            // assertions on constants fail at compile time
+                            (Err(err), Some(message))
+                                if matches!(err.kind, ErrorKind::AssertionFailed) =>
+                            {
+                                Err(Error::new(
+                                    err.label,
+                                    ErrorKind::AssertionFailedWithMessage(message.to_string()),
+                                    err.span,
+                                ))
+                            }
+                            (res, _) => res.map(|r| r.map(VarOrRef::Var)),
+                        }
                     }
 
                     // fn_name(args)
@@ -352,11 +1131,13 @@ impl<B: Backend> CircuitWriter<B> {
                     FnKind::Native(func) => {
                         // module::fn_name(args)
                         // ^^^^^^
-                        self.compile_native_function_call(&func, vars)
+                        self.compile_native_function_call(&func, &mut vars, expr.span)
                             .map(|r| r.map(VarOrRef::Var))
                     }
                 };
 
+                self.write_back_args(fn_env, places, vars, expr.span);
+
                 //
                 res
             }
@@ -367,34 +1148,69 @@ impl<B: Backend> CircuitWriter<B> {
                     .compute_expr(fn_env, lhs)?
                     .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, lhs.span))?;
 
-                // get struct info behind lhs
-                let lhs_struct = self
-                    .expr_type(lhs)
-                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, lhs.span))?;
+                // find range of field
+                let (start, len) = self.field_range(fn_env, lhs, rhs)?;
 
-                let (module, self_struct) = match lhs_struct {
-                    TyKind::Custom { module, name } => (module, name),
-                    _ => {
-                        panic!("could not figure out struct implementing that method call")
-                    }
-                };
+                // narrow the variable to the given range
+                let var = lhs_var.narrow(start, len);
+                Ok(Some(var))
+            }
 
-                let qualified = FullyQualified::new(module, self_struct);
-                let struct_info = self
-                    .struct_info(&qualified)
-                    .expect("struct info not found for custom struct");
+            // `Enum::Variant(args)`
+            ExprKind::EnumVariant {
+                custom,
+                variant,
+                args,
+            } => {
+                let qualified = FullyQualified::new(&custom.module, &custom.name);
+                let (tag, _) = self
+                    .enum_info(&qualified)
+                    .expect("type checker bug: enum not found")
+                    .variant(&variant.value)
+                    .expect("type checker bug: unknown variant");
+
+                // the tag, followed by the values held by the variant,
+                // padded with zeros to the size of the largest variant
+                let mut cvars = vec![ConstOrCell::Const(B::Field::from(tag as u64))];
+                for arg in args {
+                    let arg_var = self
+                        .compute_expr(fn_env, arg)?
+                        .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, arg.span))?
+                        .value(self, fn_env);
+                    cvars.extend(arg_var.cvars);
+                }
 
-                // find range of field
-                let mut start = 0;
-                let mut len = 0;
-                for (field, field_typ) in &struct_info.fields {
-                    if field == &rhs.value {
-                        len = self.size_of(field_typ);
-                        break;
-                    }
+                let size = self.size_of(&TyKind::Custom {
+                    module: custom.module.clone(),
+                    name: custom.name.clone(),
+                    sizes: vec![],
+                });
+                cvars.resize(size, ConstOrCell::Const(B::Field::zero()));
 
-                    start += self.size_of(field_typ);
-                }
+                Ok(Some(VarOrRef::Var(Var::new(cvars, expr.span))))
+            }
+
+            // `match expr { ... }`
+            ExprKind::Match {
+                expr: scrutinee,
+                arms,
+            } => self.compile_match(fn_env, scrutinee, arms, expr.span),
+
+            ExprKind::TupleAccess { lhs, idx } => {
+                // get var behind lhs
+                let lhs_var = self
+                    .compute_expr(fn_env, lhs)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, lhs.span))?;
+
+                // get the types of the tuple's elements
+                let typs = match self.resolved_expr_type(fn_env, lhs) {
+                    Some(TyKind::Tuple(typs)) => typs,
+                    _ => panic!("type checker bug: tuple access on a non-tuple"),
+                };
+
+                // find range of element
+                let start: usize = typs[..*idx].iter().map(|typ| self.size_of(typ)).sum();
+                let len = self.size_of(&typs[*idx]);
 
                 // narrow the variable to the given range
                 let var = lhs_var.narrow(start, len);
@@ -408,10 +1224,20 @@ impl<B: Backend> CircuitWriter<B> {
                 args,
             } => {
                 // figure out the name of the custom struct
-                let lhs_typ = self.expr_type(lhs).expect("method call on what?").clone();
+                // (a generic type is resolved to the struct the function was called with)
+                let lhs_typ = self
+                    .resolved_expr_type(fn_env, lhs)
+                    .expect("method call on what?");
 
                 let (module, struct_name) = match &lhs_typ {
-                    TyKind::Custom { module, name } => (module, name),
+                    TyKind::Custom { module, name, .. } => (module, name),
+
+                    // `array.len()` is a constant
+                    TyKind::Array(_, len) => {
+                        let len = Var::new_constant(B::Field::from(*len as u64), expr.span);
+                        return Ok(Some(VarOrRef::Var(len)));
+                    }
+
                     _ => {
                         return Err(self.error(
                             ErrorKind::UnexpectedError("method call only work on custom types"),
@@ -440,42 +1266,45 @@ impl<B: Backend> CircuitWriter<B> {
 
                 // if method has a `self` argument, manually add it to the list of argument
                 let mut vars = vec![];
-                if let Some(first_arg) = func.sig.arguments.first() {
-                    if first_arg.name.value == "self" {
-                        let self_var = self_var.ok_or_else(|| {
-                            self.error(ErrorKind::NotAStaticMethod, method_name.span)
-                        })?;
-
-                        // TODO: for now we pass `self` by value as well
-                        let mutable = false;
-                        let self_var = self_var.value(self, fn_env);
-
-                        let self_var_info = VarInfo::new(self_var, mutable, Some(lhs_typ.clone()));
-                        vars.insert(0, self_var_info);
-                    }
+                let mut places = vec![];
+                let mut sig_args = func.sig.arguments.iter().peekable();
+                if let Some(first_arg) = sig_args.next_if(|arg| arg.name.value == "self") {
+                    let self_var = self_var
+                        .ok_or_else(|| self.error(ErrorKind::NotAStaticMethod, method_name.span))?;
+
+                    // a `mut self` is written back to the caller's variable after the call
+                    let mutable = first_arg.is_mutable();
+                    places.push(mutable.then(|| Self::mutable_place(&self_var)));
+                    let self_var = self_var.value(self, fn_env);
+
+                    let self_var_info = VarInfo::new(self_var, mutable, Some(lhs_typ.clone()));
+                    vars.push(self_var_info);
                 } else {
                     assert!(self_var.is_none());
                 }
 
                 // compute the arguments
-                for arg in args {
+                for (sig_arg, arg) in sig_args.zip(args) {
                     let var = self
                         .compute_expr(fn_env, arg)?
                         .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, arg.span))?;
 
-                    // TODO: for now we pass `self` by value as well
-                    let mutable = false;
+                    let mutable = sig_arg.is_mutable();
+                    places.push(mutable.then(|| Self::mutable_place(&var)));
                     let var = var.value(self, fn_env);
 
-                    let typ = self.expr_type(arg).cloned();
+                    let typ = self.resolved_expr_type(fn_env, arg);
                     let var_info = VarInfo::new(var, mutable, typ);
 
                     vars.push(var_info);
                 }
 
                 // execute method
-                self.compile_native_function_call(func, vars)
-                    .map(|r| r.map(VarOrRef::Var))
+                let res = self.compile_native_function_call(func, &mut vars, expr.span);
+
+                self.write_back_args(fn_env, places, vars, expr.span);
+
+                res.map(|r| r.map(VarOrRef::Var))
             }
 
             ExprKind::IfElse { cond, then_, else_ } => {
@@ -497,58 +1326,127 @@ impl<B: Backend> CircuitWriter<B> {
                 Ok(Some(VarOrRef::Var(res)))
             }
 
+            ExprKind::Cast { lhs, typ } => {
+                let lhs_bitlen = self.expr_type(lhs).and_then(TyKind::int_bitlen);
+
+                let var = self.compute_expr(fn_env, lhs)?.unwrap().value(self, fn_env);
+
+                // converting to an integer requires a range check,
+                // unless the value is already an integer that fits
+                if let Some(bitlen) = typ.kind.int_bitlen() {
+                    if !matches!(lhs_bitlen, Some(lhs_bitlen) if lhs_bitlen <= bitlen) {
+                        uint::range_check(self, &var[0], bitlen, expr.span)?;
+                    }
+                }
+
+                Ok(Some(VarOrRef::Var(var)))
+            }
+
             ExprKind::Assignment { lhs, rhs } => {
-                // figure out the local var  of lhs
-                let lhs = self.compute_expr(fn_env, lhs)?.unwrap();
+                // figure out the part of a local variable that is on the left
+                let (var_name, steps) = self.compute_place(fn_env, lhs)?;
 
                 // figure out the var of what's on the right
-                let rhs = self.compute_expr(fn_env, rhs)?.unwrap();
-                let rhs_var = match rhs {
-                    VarOrRef::Var(var) => var,
-                    VarOrRef::Ref {
-                        var_name,
-                        start,
-                        len,
-                    } => {
-                        let var_info = self.get_local_var(fn_env, &var_name);
-                        let cvars = var_info.var.range(start, len).to_vec();
-                        Var::new(cvars, var_info.var.span)
-                    }
+                let rhs_var = self.compute_expr(fn_env, rhs)?.unwrap().value(self, fn_env);
+
+                // the leading fields and constant indices give us a range of the variable
+                let var = self.get_local_var(fn_env, &var_name).var;
+                let (start, len, steps) = match steps.split_first() {
+                    Some((PlaceStep::Range { start, len }, rest)) => (*start, *len, rest),
+                    _ => (0, var.len(), steps.as_slice()),
                 };
 
+                // the indices only known at proving time are handled with selectors
+                let old_var = Var::new(var.range(start, len).to_vec(), var.span);
+                let new_var = self.replace_place(old_var, steps, rhs_var, lhs.span);
+
                 // replace the left with the right
-                match lhs {
-                    VarOrRef::Var(_) => panic!("can't reassign this non-mutable variable"),
-                    VarOrRef::Ref {
-                        var_name,
-                        start,
-                        len,
-                    } => {
-                        fn_env.reassign_var_range(&var_name, rhs_var, start, len);
-                    }
-                }
+                self.reassign_var_range(fn_env, &var_name, new_var, start, len, expr.span);
 
                 Ok(None)
             }
 
             ExprKind::BinaryOp { op, lhs, rhs, .. } => {
+                // integer operands (one of them might be a literal)
+                let int_bitlen = [lhs, rhs]
+                    .iter()
+                    .find_map(|operand| self.expr_type(operand).and_then(TyKind::int_bitlen));
+
                 let lhs = self.compute_expr(fn_env, lhs)?.unwrap();
                 let rhs = self.compute_expr(fn_env, rhs)?.unwrap();
 
                 let lhs = lhs.value(self, fn_env);
                 let rhs = rhs.value(self, fn_env);
 
+                // field elements are compared as integers of up to this many bits
+                let bitlen = int_bitlen.unwrap_or_else(comparison::max_bitlen::<B::Field>);
+
+                // unlike integers, field elements are not known to fit in that many bits,
+                // so they are range checked before being compared
+                if int_bitlen.is_none()
+                    && matches!(
+                        op,
+                        Op2::LessThan
+                            | Op2::LessThanOrEqual
+                            | Op2::GreaterThan
+                            | Op2::GreaterThanOrEqual
+                    )
+                {
+                    uint::range_check(self, &lhs[0], bitlen, expr.span)?;
+                    uint::range_check(self, &rhs[0], bitlen, expr.span)?;
+                }
+
                 let res = match op {
                     Op2::Addition => field::add(self, &lhs[0], &rhs[0], expr.span),
                     Op2::Subtraction => field::sub(self, &lhs[0], &rhs[0], expr.span),
                     Op2::Multiplication => field::mul(self, &lhs[0], &rhs[0], expr.span),
                     Op2::Equality => field::equal(self, &lhs, &rhs, expr.span),
                     Op2::Inequality => field::not_equal(self, &lhs, &rhs, expr.span),
+                    Op2::LessThan => {
+                        comparison::less_than(self, &lhs[0], &rhs[0], bitlen, expr.span)
+                    }
+                    Op2::LessThanOrEqual => {
+                        comparison::less_than_or_equal(self, &lhs[0], &rhs[0], bitlen, expr.span)
+                    }
+                    Op2::GreaterThan => {
+                        comparison::greater_than(self, &lhs[0], &rhs[0], bitlen, expr.span)
+                    }
+                    Op2::GreaterThanOrEqual => {
+                        comparison::greater_than_or_equal(self, &lhs[0], &rhs[0], bitlen, expr.span)
+                    }
                     Op2::BoolAnd => boolean::and(self, &lhs[0], &rhs[0], expr.span),
                     Op2::BoolOr => boolean::or(self, &lhs[0], &rhs[0], expr.span),
-                    Op2::Division => todo!(),
+                    // integers use the euclidean division
+                    Op2::Division => match int_bitlen {
+                        Some(bitlen) => uint::div_rem(self, &lhs[0], &rhs[0], bitlen, expr.span)?.0,
+                        None => field::div(self, &lhs[0], &rhs[0], expr.span)?,
+                    },
+                    Op2::Remainder => {
+                        // the type checker makes sure that these are integers
+                        let bitlen = int_bitlen.expect("type checker bug: remainder of a field");
+                        uint::div_rem(self, &lhs[0], &rhs[0], bitlen, expr.span)?.1
+                    }
+                    Op2::BitAnd => bitwise::and(self, &lhs[0], &rhs[0], bitlen, expr.span),
+                    Op2::BitOr => bitwise::or(self, &lhs[0], &rhs[0], bitlen, expr.span),
+                    Op2::BitXor => bitwise::xor(self, &lhs[0], &rhs[0], bitlen, expr.span),
+                    Op2::ShiftLeft | Op2::ShiftRight => {
+                        let amount = self.compute_shift(&rhs, bitlen, expr.span)?;
+                        let shift = if matches!(op, Op2::ShiftLeft) {
+                            Shift::Left(amount)
+                        } else {
+                            Shift::Right(amount)
+                        };
+                        bitwise::shift(self, &lhs[0], shift, bitlen, expr.span)
+                    }
                 };
 
+                // integer arithmetic must not overflow
+                if let (Some(bitlen), Op2::Addition | Op2::Subtraction | Op2::Multiplication) =
+                    (int_bitlen, op)
+                {
+                    uint::range_check(self, &res[0], bitlen, expr.span)?;
+                }
+
                 Ok(Some(VarOrRef::Var(res)))
             }
 
@@ -557,7 +1455,8 @@ impl<B: Backend> CircuitWriter<B> {
 
                 let var = var.value(self, fn_env);
 
-                todo!()
+                let res = field::neg(self, &var[0], expr.span);
+                Ok(Some(VarOrRef::Var(res)))
             }
 
             ExprKind::Not(b) => {
@@ -578,6 +1477,18 @@ impl<B: Backend> CircuitWriter<B> {
                 Ok(Some(res))
             }
 
+            ExprKind::StringLiteral(_) => {
+                Err(self.error(ErrorKind::UnexpectedStringLiteral, expr.span))
+            }
+
+            ExprKind::ByteString(bytes) => {
+                let cvars = bytes
+                    .iter()
+                    .map(|byte| ConstOrCell::Const(B::Field::from(*byte as u64)))
+                    .collect();
+                Ok(Some(VarOrRef::Var(Var::new(cvars, expr.span))))
+            }
+
             ExprKind::Bool(b) => {
                 let value = if *b {
                     B::Field::one()
@@ -589,6 +1500,12 @@ impl<B: Backend> CircuitWriter<B> {
             }
 
             ExprKind::Variable { module, name } => {
+                // generic array sizes are constants within the function
+                if let Some(size) = fn_env.generic_size(&name.value) {
+                    let var = Var::new_constant(size.into(), name.span);
+                    return Ok(Some(VarOrRef::Var(var)));
+                }
+
                 // if it's a type we return nothing
                 // (most likely what follows is a static method call)
                 if is_type(&name.value) {
@@ -620,31 +1537,41 @@ impl<B: Backend> CircuitWriter<B> {
                 let idx_var = self
                     .compute_expr(fn_env, idx)?
                     .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, expr.span))?;
-                let idx = idx_var
-                    .constant()
-                    .ok_or_else(|| self.error(ErrorKind::ExpectedConstant, expr.span))?;
-                let idx: BigUint = idx.into();
-                let idx: usize = idx.try_into().unwrap();
 
                 // retrieve the type of the elements in the array
-                let array_typ = self.expr_type(array).expect("cannot find type of array");
+                let array_typ = self
+                    .resolved_expr_type(fn_env, array)
+                    .expect("cannot find type of array");
 
-                let elem_type = match array_typ {
-                    TyKind::Array(ty, array_len) => {
-                        if idx >= (*array_len as usize) {
-                            return Err(self.error(
-                                ErrorKind::ArrayIndexOutOfBounds(idx, *array_len as usize - 1),
-                                expr.span,
-                            ));
-                        }
-                        ty
-                    }
+                let (elem_type, array_len) = match &array_typ {
+                    TyKind::Array(ty, array_len) => (ty, *array_len as usize),
                     _ => panic!("expected array"),
                 };
 
                 // compute the size of each element in the array
                 let len = self.size_of(elem_type);
 
+                // if the index is only known at proving time, we have to use a selector
+                let idx = match idx_var.constant() {
+                    Some(idx) => idx,
+                    None => {
+                        let array_var = var.value(self, fn_env);
+                        let idx_var = idx_var.value(self, fn_env);
+                        let res = array::get(self, &array_var, &idx_var[0], len, expr.span);
+                        return Ok(Some(VarOrRef::Var(res)));
+                    }
+                };
+
+                let idx: BigUint = idx.into();
+                let idx: usize = idx.try_into().unwrap();
+
+                if idx >= array_len {
+                    return Err(self.error(
+                        ErrorKind::ArrayIndexOutOfBounds(idx, array_len - 1),
+                        expr.span,
+                    ));
+                }
+
                 // compute the real index
                 let start = idx * len;
 
@@ -663,6 +1590,57 @@ impl<B: Backend> CircuitWriter<B> {
                 Ok(Some(var))
             }
 
+            ExprKind::ArraySlice { array, start, .. } => {
+                // retrieve var of array
+                let var = self
+                    .compute_expr(fn_env, array)?
+                    .expect("array slice on non-array");
+
+                // retrieve the type of the elements in the array
+                let (elem_type, array_len) = match self.resolved_expr_type(fn_env, array) {
+                    Some(TyKind::Array(ty, array_len)) => (*ty, array_len as usize),
+                    _ => panic!("expected array"),
+                };
+
+                // the size of the slice was computed by the type checker
+                let slice_len = match self.resolved_expr_type(fn_env, expr) {
+                    Some(TyKind::Array(_, len)) => len as usize,
+                    _ => panic!("type checker bug: a slice must be an array"),
+                };
+                let start = self.compute_range_bound(fn_env, start)? as usize;
+
+                // out-of-bound checks
+                // (the size of a generic array is only known at this point)
+                if start + slice_len > array_len {
+                    return Err(self.error(
+                        ErrorKind::InvalidSliceBounds(start, start + slice_len, array_len),
+                        expr.span,
+                    ));
+                }
+
+                // narrow the var down to the slice
+                let len = self.size_of(&elem_type);
+                let var = var.narrow(start * len, slice_len * len);
+
+                Ok(Some(var))
+            }
+
+            ExprKind::RepeatedArrayDeclaration { item, .. } => {
+                let var = self
+                    .compute_expr(fn_env, item)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, item.span))?
+                    .value(self, fn_env);
+
+                let size = match self.resolved_expr_type(fn_env, expr) {
+                    Some(TyKind::Array(_, size)) => size as usize,
+                    _ => panic!("type checker bug: expected an array"),
+                };
+
+                // the item is computed once, and its cells are repeated
+                let cvars = (0..size).flat_map(|_| var.cvars.clone()).collect();
+                Ok(Some(VarOrRef::Var(Var::new(cvars, expr.span))))
+            }
+
             ExprKind::ArrayDeclaration(items) => {
                 let mut cvars = vec![];
 
@@ -677,6 +1655,20 @@ impl<B: Backend> CircuitWriter<B> {
                 Ok(Some(var))
             }
 
+            ExprKind::TupleDeclaration(items) => {
+                // create the tuple by just concatenating all of its cvars
+                let mut cvars = vec![];
+                for item in items {
+                    let var = self.compute_expr(fn_env, item)?.unwrap();
+                    let to_extend = var.value(self, fn_env).cvars.clone();
+                    cvars.extend(to_extend);
+                }
+                let var = VarOrRef::Var(Var::new(cvars, expr.span));
+
+                //
+                Ok(Some(var))
+            }
+
             ExprKind::CustomTypeDeclaration { custom: _, fields } => {
                 // create the struct by just concatenating all of its cvars
                 let mut cvars = vec![];
@@ -754,4 +1746,5 @@ pub(crate) struct PendingGate {
     pub coeffs: Vec<VestaField>,
     pub vars: Vec<Option<crate::backends::kimchi::KimchiCellVar>>,
     pub span: Span,
+    pub message: Option<String>,
 }
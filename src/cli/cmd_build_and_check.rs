@@ -329,6 +329,10 @@ pub struct CmdRun {
     /// JSON encoding of the private inputs. Similar to `--public-inputs` but for private inputs.
     #[clap(long, value_parser, default_value = "{}")]
     private_inputs: Option<String>,
+
+    /// prints the values passed to `log` (defaults to false)
+    #[clap(short, long)]
+    debug: bool,
 }
 
 pub fn cmd_run(args: CmdRun) -> miette::Result<()> {
@@ -354,10 +358,10 @@ pub fn cmd_run(args: CmdRun) -> miette::Result<()> {
             unimplemented!("kimchi-vesta backend is not yet supported for this command")
         }
         BackendKind::R1csBls12_381(r1cs) => {
-            run_r1cs_backend(r1cs, &curr_dir, public_inputs, private_inputs)?
+            run_r1cs_backend(r1cs, &curr_dir, public_inputs, private_inputs, args.debug)?
         }
         BackendKind::R1csBn254(r1cs) => {
-            run_r1cs_backend(r1cs, &curr_dir, public_inputs, private_inputs)?
+            run_r1cs_backend(r1cs, &curr_dir, public_inputs, private_inputs, args.debug)?
         }
     }
 
@@ -369,6 +373,7 @@ fn run_r1cs_backend<F>(
     curr_dir: &PathBuf,
     public_inputs: JsonInputs,
     private_inputs: JsonInputs,
+    debug: bool,
 ) -> miette::Result<()>
 where
     F: BackendField,
@@ -378,8 +383,13 @@ where
 
     let compiled_circuit = compile(&sources, tast, r1cs)?;
 
-    let generated_witness =
-        generate_witness(&compiled_circuit, &sources, public_inputs, private_inputs)?;
+    let generated_witness = generate_witness(
+        &compiled_circuit,
+        &sources,
+        public_inputs,
+        private_inputs,
+        debug,
+    )?;
 
     let snarkjs_exporter = SnarkjsExporter::new(compiled_circuit.circuit.backend);
 
@@ -411,7 +421,13 @@ where
 
     let compiled_circuit = compile(&sources, tast, r1cs)?;
 
-    generate_witness(&compiled_circuit, &sources, public_inputs, private_inputs)?;
+    generate_witness(
+        &compiled_circuit,
+        &sources,
+        public_inputs,
+        private_inputs,
+        debug,
+    )?;
 
     let asm = compiled_circuit.asm(&sources, debug);
 
@@ -9,9 +9,16 @@ use std::collections::HashMap;
 use miette::NamedSource;
 
 use crate::{
-    backends::Backend, circuit_writer::CircuitWriter, cli::packages::UserRepo, error::Result,
-    inputs::JsonInputs, lexer::Token, name_resolution::NAST, parser::AST,
-    type_checker::TypeChecker, witness::CompiledCircuit,
+    backends::Backend,
+    circuit_writer::CircuitWriter,
+    cli::packages::UserRepo,
+    error::Result,
+    inputs::JsonInputs,
+    lexer::Token,
+    name_resolution::NAST,
+    parser::{types::TyKind, AST},
+    type_checker::{FullyQualified, TypeChecker},
+    witness::CompiledCircuit,
 };
 
 /// Contains the association between a counter and the corresponding filename and source code.
@@ -96,7 +103,14 @@ pub fn typecheck_next_file_inner<B: Backend>(
     let is_lib = this_module.is_some();
 
     // parsing to name resolution
-    let (nast, new_node_id) = get_nast(this_module, sources, filename, code, node_id)?;
+    let (nast, new_node_id) = get_nast(
+        this_module,
+        typechecker.type_aliases(),
+        sources,
+        filename,
+        code,
+        node_id,
+    )?;
 
     // type checker
     typechecker.analyze(nast, is_lib)?;
@@ -104,13 +118,14 @@ pub fn typecheck_next_file_inner<B: Backend>(
     Ok(new_node_id)
 }
 
-pub fn get_nast<B: Backend>(
+pub fn get_nast(
     this_module: Option<UserRepo>,
+    type_aliases: &HashMap<FullyQualified, TyKind>,
     sources: &mut Sources,
     filename: String,
     code: String,
     node_id: usize,
-) -> Result<(NAST<B>, usize)> {
+) -> Result<(NAST, usize)> {
     // save filename and source code
     let filename_id = sources.add(filename, code);
     let code = &sources.map[&filename_id].1;
@@ -128,7 +143,7 @@ pub fn get_nast<B: Backend>(
     }
 
     // name resolution
-    let nast = NAST::resolve_modules(this_module, ast)?;
+    let nast = NAST::resolve_modules(this_module, type_aliases, ast)?;
     if std::env::var("NONAME_VERBOSE").is_ok() {
         println!("name resolution succeeded");
     }
@@ -144,13 +159,20 @@ pub fn compile<B: Backend>(
     CircuitWriter::generate_circuit(tast, backend).into_miette(sources)
 }
 
+/// Generates the witness of a compiled circuit.
+/// If `log` is set, the values passed to `log` are printed.
 pub fn generate_witness<B: Backend>(
     compiled_circuit: &CompiledCircuit<B>,
     sources: &Sources,
     public_inputs: JsonInputs,
     private_inputs: JsonInputs,
+    log: bool,
 ) -> miette::Result<B::GeneratedWitness> {
-    compiled_circuit
-        .generate_witness(public_inputs, private_inputs)
-        .into_miette(sources)
+    let res = if log {
+        compiled_circuit.generate_witness_with_logs(public_inputs, private_inputs, sources)
+    } else {
+        compiled_circuit.generate_witness(public_inputs, private_inputs)
+    };
+
+    res.into_miette(sources)
 }
@@ -0,0 +1,135 @@
+//! Accessing arrays at an index that is only known at proving time.
+//!
+//! We compute a one-hot selector for the index:
+//! a boolean per element of the array, only set for the element being accessed.
+//! Constraining exactly one of them to be set also proves that the index is in bounds.
+//!
+//! Reads can also be done by the backend itself,
+//! as some backends can use lookups for it instead (e.g. kimchi's runtime tables).
+
+use ark_ff::{One, Zero};
+
+use crate::{
+    backends::Backend,
+    circuit_writer::CircuitWriter,
+    constants::Span,
+    var::{ConstOrCell, Var},
+};
+
+use super::field;
+
+/// Returns the one-hot selector of `idx` for an array of `len` elements,
+/// and constrains `idx` to be in bounds.
+pub fn selector<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    idx: &ConstOrCell<B::Field, B::Var>,
+    len: usize,
+    span: Span,
+) -> Vec<ConstOrCell<B::Field, B::Var>> {
+    let idx = Var::new_cvar(idx.clone(), span);
+
+    let mut selector = Vec::with_capacity(len);
+    let mut sum = ConstOrCell::Const(B::Field::zero());
+
+    for ii in 0..len {
+        let position = Var::new_constant(B::Field::from(ii as u64), span);
+        let bit = field::equal(compiler, &idx, &position, span)[0].clone();
+
+        sum = field::add(compiler, &sum, &bit, span)[0].clone();
+        selector.push(bit);
+    }
+
+    // the index is in bounds iff exactly one of the bits is set
+    match sum {
+        ConstOrCell::Cell(sum) => {
+            field::assert_eq_const_if_active(compiler, &sum, B::Field::one(), span)
+        }
+        ConstOrCell::Const(_) => unreachable!("the index is not a constant"),
+    }
+
+    selector
+}
+
+/// Returns the element at `idx` in an array of elements made of `elem_len` cells each,
+/// and constrains `idx` to be in bounds.
+pub fn get<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    array: &Var<B::Field, B::Var>,
+    idx: &ConstOrCell<B::Field, B::Var>,
+    elem_len: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    assert_eq!(array.len() % elem_len, 0);
+    B::array_get(compiler, array, idx, elem_len, span)
+}
+
+/// Returns the element at `idx` using a one-hot selector, and constrains `idx` to be in bounds.
+/// This is used by backends that don't have lookups.
+pub fn get_with_selector<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    array: &Var<B::Field, B::Var>,
+    idx: &ConstOrCell<B::Field, B::Var>,
+    elem_len: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    let selector = selector(compiler, idx, array.len() / elem_len, span);
+    select(compiler, array, &selector, elem_len, span)
+}
+
+/// Returns the element picked by a one-hot selector
+/// in an array of elements made of `elem_len` cells each.
+pub fn select<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    array: &Var<B::Field, B::Var>,
+    selector: &[ConstOrCell<B::Field, B::Var>],
+    elem_len: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    // each cell of the result is the sum of the cells at the same position in every element,
+    // where only the selected element is not zeroed out
+    let mut cvars = Vec::with_capacity(elem_len);
+    for jj in 0..elem_len {
+        let mut acc = ConstOrCell::Const(B::Field::zero());
+        for (ii, bit) in selector.iter().enumerate() {
+            let term = field::mul(compiler, bit, &array[ii * elem_len + jj], span);
+            acc = field::add(compiler, &acc, &term[0], span)[0].clone();
+        }
+        cvars.push(acc);
+    }
+
+    Var::new(cvars, span)
+}
+
+/// Returns a copy of the array where the element at `idx` is replaced by `value`.
+pub fn set<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    array: &Var<B::Field, B::Var>,
+    idx: &ConstOrCell<B::Field, B::Var>,
+    value: &Var<B::Field, B::Var>,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    let selector = selector(compiler, idx, array.len() / value.len(), span);
+    replace(compiler, array, &selector, value, span)
+}
+
+/// Returns a copy of the array where the element picked by a one-hot selector is replaced by `value`.
+pub fn replace<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    array: &Var<B::Field, B::Var>,
+    selector: &[ConstOrCell<B::Field, B::Var>],
+    value: &Var<B::Field, B::Var>,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    let elem_len = value.len();
+
+    let mut cvars = Vec::with_capacity(array.len());
+    for (ii, bit) in selector.iter().enumerate() {
+        for jj in 0..elem_len {
+            let old = &array[ii * elem_len + jj];
+            let res = field::if_else_inner(compiler, bit, &value[jj], old, span);
+            cvars.push(res[0].clone());
+        }
+    }
+
+    Var::new(cvars, span)
+}
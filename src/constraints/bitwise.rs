@@ -0,0 +1,256 @@
+//! Bitwise operations on integers.
+//!
+//! The xor of two integers is delegated to the backend,
+//! as some backends have dedicated gates for it (e.g. kimchi's `Xor16`).
+//! The `and` and `or` operations are derived from it,
+//! as `a + b = (a ^ b) + 2 * (a & b)` and `a | b = (a ^ b) + (a & b)`.
+//! Shifts and rotations by constants are also delegated to the backend
+//! (e.g. kimchi's `Rot64`), and otherwise computed from a bit decomposition.
+
+use ark_ff::{Field, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    backends::Backend,
+    circuit_writer::CircuitWriter,
+    constants::Span,
+    var::{ConstOrCell, Var},
+};
+
+use super::{comparison, field};
+
+/// Applies a bitwise operation on two constants.
+fn constant_op<B: Backend>(
+    lhs: &B::Field,
+    rhs: &B::Field,
+    op: impl Fn(BigUint, BigUint) -> BigUint,
+) -> B::Field {
+    let res = op((*lhs).into(), (*rhs).into());
+    B::Field::try_from(res).unwrap_or_else(|_| unreachable!("bitwise ops can't overflow"))
+}
+
+/// Returns a cell for the given variable, constraining it if it's a constant.
+fn to_cell<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &ConstOrCell<B::Field, B::Var>,
+    span: Span,
+) -> B::Var {
+    match var {
+        ConstOrCell::Const(cst) => {
+            compiler
+                .backend
+                .add_constant(Some("encoding constant operand of a xor"), *cst, span)
+        }
+        ConstOrCell::Cell(cvar) => cvar.clone(),
+    }
+}
+
+/// Returns `lhs ^ rhs`, where both operands fit in `bitlen` bits.
+pub fn xor<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    if let (ConstOrCell::Const(lhs), ConstOrCell::Const(rhs)) = (lhs, rhs) {
+        return Var::new_constant(constant_op::<B>(lhs, rhs, |a, b| a ^ b), span);
+    }
+
+    let lhs = to_cell(compiler, lhs, span);
+    let rhs = to_cell(compiler, rhs, span);
+
+    let res = B::xor(compiler, &lhs, &rhs, bitlen, span);
+    Var::new_var(res, span)
+}
+
+/// Returns `lhs & rhs`, where both operands fit in `bitlen` bits.
+pub fn and<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    if let (ConstOrCell::Const(lhs), ConstOrCell::Const(rhs)) = (lhs, rhs) {
+        return Var::new_constant(constant_op::<B>(lhs, rhs, |a, b| a & b), span);
+    }
+
+    // a & b = (a + b - (a ^ b)) / 2
+    let xor = xor(compiler, lhs, rhs, bitlen, span);
+    let sum = field::add(compiler, lhs, rhs, span);
+    let diff = field::sub(compiler, &sum[0], &xor[0], span);
+    halve(compiler, &diff[0], span)
+}
+
+/// Returns `lhs | rhs`, where both operands fit in `bitlen` bits.
+pub fn or<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    if let (ConstOrCell::Const(lhs), ConstOrCell::Const(rhs)) = (lhs, rhs) {
+        return Var::new_constant(constant_op::<B>(lhs, rhs, |a, b| a | b), span);
+    }
+
+    // a | b = (a + b + (a ^ b)) / 2
+    let xor = xor(compiler, lhs, rhs, bitlen, span);
+    let sum = field::add(compiler, lhs, rhs, span);
+    let sum = field::add(compiler, &sum[0], &xor[0], span);
+    halve(compiler, &sum[0], span)
+}
+
+/// Divides a variable known to be even by two.
+fn halve<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &ConstOrCell<B::Field, B::Var>,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    let half = B::Field::from(2u64)
+        .inverse()
+        .expect("two is invertible in the field");
+    field::mul(compiler, var, &ConstOrCell::Const(half), span)
+}
+
+/// A move of the bits of an integer by a constant amount,
+/// which must be smaller than the bit length of the integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shift {
+    /// The highest bits are shifted out.
+    Left(usize),
+    /// The lowest bits are shifted out.
+    Right(usize),
+    /// The highest bits are moved to the lowest ones.
+    RotateLeft(usize),
+}
+
+impl Shift {
+    /// Applies the shift to an integer of `bitlen` bits.
+    fn apply(self, value: BigUint, bitlen: usize) -> BigUint {
+        let mask = (BigUint::from(1u32) << bitlen) - 1u32;
+        match self {
+            Shift::Left(amount) => (value << amount) & mask,
+            Shift::Right(amount) => value >> amount,
+            Shift::RotateLeft(amount) => ((&value << amount) & mask) | (value >> (bitlen - amount)),
+        }
+    }
+}
+
+/// Moves the bits of `var`, an integer of `bitlen` bits (see [Shift]).
+/// The amount of the shift must be smaller than `bitlen`.
+pub fn shift<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &ConstOrCell<B::Field, B::Var>,
+    shift: Shift,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    let amount = match shift {
+        Shift::Left(amount) | Shift::Right(amount) | Shift::RotateLeft(amount) => amount,
+    };
+    assert!(amount < bitlen);
+
+    match var {
+        ConstOrCell::Const(cst) => {
+            let res = constant_op::<B>(cst, cst, |a, _| shift.apply(a, bitlen));
+            Var::new_constant(res, span)
+        }
+        // nothing moves
+        ConstOrCell::Cell(_) if amount == 0 => Var::new_cvar(var.clone(), span),
+        ConstOrCell::Cell(cvar) => {
+            let res = B::shift(compiler, cvar, shift, bitlen, span);
+            Var::new_var(res, span)
+        }
+    }
+}
+
+/// Returns `sum_i bits[i] * 2^(offset + i)`.
+fn recompose<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    bits: &[B::Var],
+    offset: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    let mut acc = ConstOrCell::Const(B::Field::zero());
+    let mut power = B::Field::from(2u64).pow([offset as u64]);
+
+    for bit in bits {
+        let term = field::mul(
+            compiler,
+            &ConstOrCell::Cell(bit.clone()),
+            &ConstOrCell::Const(power),
+            span,
+        );
+        acc = field::add(compiler, &acc, &term[0], span)[0].clone();
+        power.double_in_place();
+    }
+
+    Var::new_cvar(acc, span)
+}
+
+/// Moves the bits of a cell of at most `bitlen` bits, using its bit decomposition.
+/// This is used by backends that don't have a dedicated gate for shifts.
+pub fn shift_from_bits<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &B::Var,
+    shift: Shift,
+    bitlen: usize,
+    span: Span,
+) -> B::Var {
+    let bits = comparison::to_bits(compiler, var, bitlen, span);
+
+    let res = match shift {
+        Shift::Left(amount) => recompose(compiler, &bits[..bitlen - amount], amount, span),
+        Shift::Right(amount) => recompose(compiler, &bits[amount..], 0, span),
+        Shift::RotateLeft(amount) => {
+            let rotated: Vec<_> = bits[bitlen - amount..]
+                .iter()
+                .chain(&bits[..bitlen - amount])
+                .cloned()
+                .collect();
+            recompose(compiler, &rotated, 0, span)
+        }
+    };
+
+    match &res[0] {
+        ConstOrCell::Cell(cvar) => cvar.clone(),
+        ConstOrCell::Const(_) => unreachable!("bits are variables"),
+    }
+}
+
+/// Returns `lhs ^ rhs` for two cells of at most `bitlen` bits, using their bit decompositions.
+/// This is used by backends that don't have a dedicated gate for xor.
+pub fn xor_from_bits<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &B::Var,
+    rhs: &B::Var,
+    bitlen: usize,
+    span: Span,
+) -> B::Var {
+    let lhs_bits = comparison::to_bits(compiler, lhs, bitlen, span);
+    let rhs_bits = comparison::to_bits(compiler, rhs, bitlen, span);
+
+    let mut xor_bits = Vec::with_capacity(bitlen);
+    for (lhs_bit, rhs_bit) in lhs_bits.into_iter().zip(rhs_bits) {
+        let lhs_bit = ConstOrCell::Cell(lhs_bit);
+        let rhs_bit = ConstOrCell::Cell(rhs_bit);
+
+        // a ^ b = a + b - 2ab (for bits)
+        let prod = field::mul(compiler, &lhs_bit, &rhs_bit, span);
+        let double_prod = field::add(compiler, &prod[0], &prod[0], span);
+        let sum = field::add(compiler, &lhs_bit, &rhs_bit, span);
+        let xor_bit = field::sub(compiler, &sum[0], &double_prod[0], span);
+
+        match &xor_bit[0] {
+            ConstOrCell::Cell(cvar) => xor_bits.push(cvar.clone()),
+            ConstOrCell::Const(_) => unreachable!("bits are variables"),
+        }
+    }
+
+    match &recompose(compiler, &xor_bits, 0, span)[0] {
+        ConstOrCell::Cell(cvar) => cvar.clone(),
+        ConstOrCell::Const(_) => unreachable!("bits are variables"),
+    }
+}
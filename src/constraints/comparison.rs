@@ -0,0 +1,158 @@
+//! Ordered comparisons between field elements.
+//!
+//! Field elements are not naturally ordered,
+//! so these gadgets interpret them as integers of at most `bitlen` bits
+//! and compare them via a bit decomposition.
+
+use ark_ff::{Field, One, PrimeField, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    backends::Backend,
+    circuit_writer::CircuitWriter,
+    constants::Span,
+    var::{ConstOrCell, Value, Var},
+};
+
+use super::{boolean, field};
+
+/// Returns the largest bit length that can be used to compare two field elements,
+/// such that the comparison can't overflow the field.
+pub fn max_bitlen<F: Field>() -> usize {
+    // `lhs + 2^bitlen - rhs` must fit in `bitlen + 1` bits without wrapping around the modulus
+    F::BasePrimeField::size_in_bits() - 2
+}
+
+/// Decomposes a variable into `bitlen` bits (in little-endian order),
+/// and constrains the decomposition to be correct.
+/// (within an `if`/`else` branch, `condition * var` is decomposed instead)
+pub fn to_bits<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &B::Var,
+    bitlen: usize,
+    span: Span,
+) -> Vec<B::Var> {
+    // within an `if`/`else` branch, the decomposition only has to succeed if the branch is active
+    // (so we decompose `condition * var` instead, which is `var` if the branch is active)
+    let var = match compiler.condition.clone() {
+        None => var.clone(),
+        Some(condition) => {
+            match &field::mul(compiler, &condition, &ConstOrCell::Cell(var.clone()), span)[0] {
+                ConstOrCell::Cell(cvar) => cvar.clone(),
+                ConstOrCell::Const(cst) => compiler.backend.add_constant(None, *cst, span),
+            }
+        }
+    };
+    let var = &var;
+
+    let mut bits = Vec::with_capacity(bitlen);
+    let mut acc: Option<B::Var> = None;
+    let mut power = B::Field::one();
+
+    for i in 0..bitlen {
+        // compute the bit out of circuit, and constrain it to be a boolean
+        let bit = compiler
+            .backend
+            .new_internal_var(Value::NthBit(var.clone(), i), span);
+        boolean::check(compiler, &ConstOrCell::Cell(bit.clone()), span);
+
+        // acc += bit * 2^i
+        let term = if i == 0 {
+            bit.clone()
+        } else {
+            compiler.backend.mul_const(&bit, &power, span)
+        };
+        acc = Some(match acc {
+            None => term,
+            Some(acc) => compiler.backend.add(&acc, &term, span),
+        });
+
+        power.double_in_place();
+        bits.push(bit);
+    }
+
+    // the bits must recompose to the original variable
+    if let Some(acc) = acc {
+        compiler.backend.assert_eq_var(&acc, var, span);
+    }
+
+    bits
+}
+
+/// Returns 1 if `lhs < rhs`, 0 otherwise.
+/// Both operands are expected to fit in `bitlen` bits.
+pub fn less_than<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    assert!(bitlen <= max_bitlen::<B::Field>());
+
+    // two constants are compared as integers
+    if let (ConstOrCell::Const(lhs), ConstOrCell::Const(rhs)) = (lhs, rhs) {
+        let lhs: BigUint = (*lhs).into();
+        let rhs: BigUint = (*rhs).into();
+        let res = if lhs < rhs {
+            B::Field::one()
+        } else {
+            B::Field::zero()
+        };
+        return Var::new_constant(res, span);
+    }
+
+    // we compute `diff = lhs + 2^bitlen - rhs`,
+    // which sets its most significant bit (the one at index `bitlen`) iff `lhs >= rhs`
+    let two_pow = B::Field::from(2u64).pow([bitlen as u64]);
+    let diff = field::sub(compiler, lhs, rhs, span);
+    let diff = field::add(compiler, &diff[0], &ConstOrCell::Const(two_pow), span);
+
+    let diff = match &diff[0] {
+        ConstOrCell::Cell(cvar) => cvar.clone(),
+        ConstOrCell::Const(_) => unreachable!("at least one of the operands is a variable"),
+    };
+
+    let bits = to_bits(compiler, &diff, bitlen + 1, span);
+
+    // lhs < rhs iff the most significant bit is not set
+    let msb = ConstOrCell::Cell(bits[bitlen].clone());
+    boolean::not(compiler, &msb, span)
+}
+
+/// Returns 1 if `lhs <= rhs`, 0 otherwise.
+pub fn less_than_or_equal<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    // lhs <= rhs <=> !(rhs < lhs)
+    let gt = less_than(compiler, rhs, lhs, bitlen, span);
+    boolean::not(compiler, &gt[0], span)
+}
+
+/// Returns 1 if `lhs > rhs`, 0 otherwise.
+pub fn greater_than<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    less_than(compiler, rhs, lhs, bitlen, span)
+}
+
+/// Returns 1 if `lhs >= rhs`, 0 otherwise.
+pub fn greater_than_or_equal<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Var<B::Field, B::Var> {
+    // lhs >= rhs <=> !(lhs < rhs)
+    let lt = less_than(compiler, lhs, rhs, bitlen, span);
+    boolean::not(compiler, &lt[0], span)
+}
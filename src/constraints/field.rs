@@ -2,12 +2,13 @@ use crate::{
     backends::Backend,
     circuit_writer::CircuitWriter,
     constants::Span,
+    error::{ErrorKind, Result},
     var::{ConstOrCell, Value, Var},
 };
 
 use super::boolean;
 
-use ark_ff::{One, Zero};
+use ark_ff::{Field, One, Zero};
 
 use std::ops::Neg;
 
@@ -99,6 +100,54 @@ pub fn mul<B: Backend>(
     }
 }
 
+/// Divides two field elements.
+/// The inverse of the divisor is computed out of circuit,
+/// and constrained to be correct (which also enforces that the divisor is not zero).
+/// Within an inactive `if`/`else` branch, the divisor can be zero (and the result is then zero).
+pub fn div<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    span: Span,
+) -> Result<Var<B::Field, B::Var>> {
+    match (lhs, rhs) {
+        // dividing by a constant zero is never valid
+        (_, ConstOrCell::Const(cst)) if cst.is_zero() => {
+            Err(compiler.error(ErrorKind::DivisionByZero, span))
+        }
+
+        // 2 constants
+        (ConstOrCell::Const(lhs), ConstOrCell::Const(rhs)) => {
+            // the match guard above ensures that rhs is not zero
+            let inv = rhs.inverse().unwrap();
+            Ok(Var::new_constant(*lhs * inv, span))
+        }
+
+        // a constant divisor can be inverted at compile time
+        (ConstOrCell::Cell(_), ConstOrCell::Const(cst)) => {
+            let inv = ConstOrCell::Const(cst.inverse().unwrap());
+            Ok(mul(compiler, lhs, &inv, span))
+        }
+
+        // a var divisor
+        (_, ConstOrCell::Cell(cvar)) => {
+            // compute the inverse of the divisor out of circuit
+            let inv = compiler
+                .backend
+                .new_internal_var(Value::Inverse(cvar.clone()), span);
+
+            // constrain rhs * inv = 1
+            // (note that this fails if rhs is zero, as its inverse is then set to zero)
+            let rhs_mul_inv = compiler.backend.mul(cvar, &inv, span);
+            assert_eq_const_if_active(compiler, &rhs_mul_inv, B::Field::one(), span);
+
+            // lhs / rhs = lhs * inv
+            let inv = ConstOrCell::Cell(inv);
+            Ok(mul(compiler, lhs, &inv, span))
+        }
+    }
+}
+
 /// This takes variables that can be anything, and returns a boolean
 // TODO: so perhaps it's not really relevant in this file?
 pub fn equal<B: Backend>(
@@ -345,3 +394,32 @@ pub fn if_else_inner<B: Backend>(
     let temp = mul(compiler, &one_minus_cond[0], else_, span);
     add(compiler, &cond_then[0], &temp[0], span)
 }
+
+/// Constrains a var to be equal to a constant,
+/// only if the current `if`/`else` branch is active.
+/// (within a branch, we enforce `condition * (var - cst) = 0` instead)
+pub fn assert_eq_const_if_active<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &B::Var,
+    cst: B::Field,
+    span: Span,
+) {
+    let Some(condition) = compiler.condition.clone() else {
+        compiler.backend.assert_eq_const(var, cst, span);
+        return;
+    };
+
+    let diff = sub(
+        compiler,
+        &ConstOrCell::Cell(var.clone()),
+        &ConstOrCell::Const(cst),
+        span,
+    );
+    match &mul(compiler, &condition, &diff[0], span)[0] {
+        ConstOrCell::Cell(res) => compiler
+            .backend
+            .assert_eq_const(res, B::Field::zero(), span),
+        // the branch is never active
+        ConstOrCell::Const(_) => (),
+    }
+}
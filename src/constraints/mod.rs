@@ -1,2 +1,6 @@
+pub mod array;
+pub mod bitwise;
 pub mod boolean;
+pub mod comparison;
 pub mod field;
+pub mod uint;
@@ -0,0 +1,170 @@
+//! Unsigned integers of a fixed bit width.
+
+use std::sync::Arc;
+
+use ark_ff::{One, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    backends::Backend,
+    circuit_writer::CircuitWriter,
+    constants::Span,
+    error::{ErrorKind, Result},
+    var::{ConstOrCell, Value, Var},
+    witness::WitnessEnv,
+};
+
+use super::{comparison, field};
+
+/// Constrains a value to fit in `bitlen` bits.
+/// Constants are checked at compile time.
+pub fn range_check<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Result<()> {
+    match var {
+        ConstOrCell::Const(cst) => {
+            let value: BigUint = (*cst).into();
+            if value.bits() > bitlen as u64 {
+                return Err(compiler.error(ErrorKind::IntegerOverflow(bitlen), span));
+            }
+        }
+        ConstOrCell::Cell(cvar) => {
+            // the decomposition can only succeed if the value fits in `bitlen` bits
+            // (within an `if`/`else` branch, only if the branch is active)
+            comparison::to_bits(compiler, cvar, bitlen, span);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the quotient and the remainder of the euclidean division of `lhs` by `rhs`,
+/// two integers of `bitlen` bits.
+/// Both are computed out of circuit, and constrained with `lhs = quotient * rhs + remainder`
+/// and `remainder < rhs` (which also enforces that `rhs` is not zero).
+pub fn div_rem<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    lhs: &ConstOrCell<B::Field, B::Var>,
+    rhs: &ConstOrCell<B::Field, B::Var>,
+    bitlen: usize,
+    span: Span,
+) -> Result<(Var<B::Field, B::Var>, Var<B::Field, B::Var>)> {
+    match (lhs, rhs) {
+        // dividing by a constant zero is never valid
+        (_, ConstOrCell::Const(cst)) if cst.is_zero() => {
+            Err(compiler.error(ErrorKind::DivisionByZero, span))
+        }
+
+        // 2 constants
+        (ConstOrCell::Const(lhs), ConstOrCell::Const(rhs)) => {
+            let lhs: BigUint = (*lhs).into();
+            let rhs: BigUint = (*rhs).into();
+            let quotient = to_field::<B>(&lhs / &rhs);
+            let remainder = to_field::<B>(&lhs % &rhs);
+            Ok((
+                Var::new_constant(quotient, span),
+                Var::new_constant(remainder, span),
+            ))
+        }
+
+        _ => {
+            // within an `if`/`else` branch, an inactive branch divides by one instead,
+            // so that the constraints below hold whatever the divisor is
+            let divisor = match compiler.condition.clone() {
+                None => rhs.clone(),
+                Some(condition) => {
+                    let one = ConstOrCell::Const(B::Field::one());
+                    let rhs_minus_one = field::sub(compiler, rhs, &one, span);
+                    let scaled = field::mul(compiler, &condition, &rhs_minus_one[0], span);
+                    field::add(compiler, &scaled[0], &one, span)[0].clone()
+                }
+            };
+
+            // compute the quotient and the remainder out of circuit
+            let quotient = compiler.backend.new_internal_var(
+                Value::Hint(Arc::new({
+                    let (lhs, divisor) = (lhs.clone(), divisor.clone());
+                    move |backend, env| {
+                        let lhs = eval(backend, env, &lhs)?;
+                        let divisor = eval(backend, env, &divisor)?;
+                        if divisor.is_zero() {
+                            // the constraints below will fail
+                            return Ok(B::Field::zero());
+                        }
+                        Ok(to_field::<B>(lhs / divisor))
+                    }
+                })),
+                span,
+            );
+            let remainder = compiler.backend.new_internal_var(
+                Value::Hint(Arc::new({
+                    let (lhs, divisor) = (lhs.clone(), divisor.clone());
+                    move |backend, env| {
+                        let lhs = eval(backend, env, &lhs)?;
+                        let divisor = eval(backend, env, &divisor)?;
+                        if divisor.is_zero() {
+                            return Ok(B::Field::zero());
+                        }
+                        Ok(to_field::<B>(lhs % divisor))
+                    }
+                })),
+                span,
+            );
+            let quotient = ConstOrCell::Cell(quotient);
+            let remainder = ConstOrCell::Cell(remainder);
+
+            // both must fit in `bitlen` bits, so that `quotient * rhs + remainder` can't wrap around
+            range_check(compiler, &quotient, bitlen, span)?;
+            range_check(compiler, &remainder, bitlen, span)?;
+
+            // lhs = quotient * rhs + remainder
+            let product = field::mul(compiler, &quotient, &divisor, span);
+            let sum = field::add(compiler, &product[0], &remainder, span);
+            match (&sum[0], lhs) {
+                (ConstOrCell::Cell(sum), ConstOrCell::Const(cst)) => {
+                    compiler.backend.assert_eq_const(sum, *cst, span)
+                }
+                (ConstOrCell::Cell(sum), ConstOrCell::Cell(lhs)) => {
+                    compiler.backend.assert_eq_var(sum, lhs, span)
+                }
+                (ConstOrCell::Const(_), _) => unreachable!("the remainder is a variable"),
+            }
+
+            // remainder < rhs
+            let is_smaller = comparison::less_than(compiler, &remainder, &divisor, bitlen, span);
+            match &is_smaller[0] {
+                ConstOrCell::Cell(cvar) => {
+                    compiler
+                        .backend
+                        .assert_eq_const(cvar, B::Field::one(), span)
+                }
+                ConstOrCell::Const(_) => unreachable!("the remainder is a variable"),
+            }
+
+            Ok((
+                Var::new_cvar(quotient, span),
+                Var::new_cvar(remainder, span),
+            ))
+        }
+    }
+}
+
+/// Computes the value of a variable during witness generation.
+fn eval<B: Backend>(
+    backend: &B,
+    env: &mut WitnessEnv<B::Field>,
+    var: &ConstOrCell<B::Field, B::Var>,
+) -> Result<BigUint> {
+    let value = match var {
+        ConstOrCell::Const(cst) => *cst,
+        ConstOrCell::Cell(cvar) => backend.compute_var(env, cvar)?,
+    };
+    Ok(value.into())
+}
+
+fn to_field<B: Backend>(value: BigUint) -> B::Field {
+    B::Field::try_from(value).unwrap_or_else(|_| unreachable!("the value fits in the field"))
+}
@@ -45,6 +45,12 @@ pub enum ErrorKind {
     #[error("variable is not mutable. You must set the `mut` keyword to make it mutable")]
     AssignmentToImmutableVariable,
 
+    #[error("can only assign to a variable, or to a field or an element of one")]
+    InvalidAssignmentTarget,
+
+    #[error("a `mut` argument must be a mutable variable, or a field or an element (at a constant index) of one")]
+    ImmutableArgument,
+
     #[error(
         "the dependency `{0}` does not appear to be listed in your manifest file `Noname.toml`"
     )]
@@ -77,7 +83,7 @@ pub enum ErrorKind {
     #[error("{0} arguments are passed when {1} were expected")]
     MismatchFunctionArguments(usize, usize),
 
-    #[error("constants must be declared before any structs or functions")]
+    #[error("constants must be declared before any functions")]
     OrderOfConstDeclaration,
 
     #[error(
@@ -192,7 +198,7 @@ pub enum ErrorKind {
     #[error("no return value was expected as part of this function signature")]
     NoReturnExpected,
 
-    #[error("the `self` argument cannot have attributes")]
+    #[error("the `self` argument cannot have attributes other than `mut`")]
     SelfHasAttribute,
 
     #[error("the return type observed (`{0}`) doesn't match what the function expected as return type (`{1}`)")]
@@ -248,6 +254,9 @@ pub enum ErrorKind {
     #[error("the program did not run to completion with the given private and/or public inputs (row {0} of the witness failed to verify)")]
     InvalidWitness(usize),
 
+    #[error("the program did not run to completion with the given private and/or public inputs: {1} (row {0} of the witness failed to verify)")]
+    InvalidWitnessWithMessage(usize, String),
+
     #[error("user provided input `{0}` is not defined in the main function's arguments")]
     UnusedInput(String),
 
@@ -284,16 +293,170 @@ pub enum ErrorKind {
     #[error("array access can only be performed on arrays")]
     ArrayAccessOnNonArray,
 
+    #[error("tuple access can only be performed on tuples")]
+    TupleAccessOnNonTuple,
+
+    #[error("tuple index {0} is out of bounds for a tuple of {1} elements")]
+    TupleIndexOutOfBounds(usize, usize),
+
+    #[error("cannot destructure {0} into {1} variables")]
+    InvalidDestructuring(TyKind, usize),
+
+    #[error("the main function cannot have generic arguments")]
+    GenericInMain,
+
+    #[error("generic `{0}` cannot be inferred from the function arguments")]
+    UndefinedGeneric(String),
+
+    #[error("generic `{0}` is used with arrays of different sizes")]
+    ConflictingGenericSize(String),
+
+    #[error("generic `{0}` cannot be used in an enum variant")]
+    GenericInEnum(String),
+
+    #[error("the struct `{0}` has {1} generic sizes, but {2} were given")]
+    MismatchStructSizes(String, usize, usize),
+
+    #[error("the condition of an `if` statement must be a boolean, not {0}")]
+    IfElseInvalidConditionType(TyKind),
+
+    #[error("this statement is unreachable, as all paths returned before it")]
+    UnreachableStatement,
+
+    #[error("the bounds of a loop must be known at compile time (literals, constants, `const` arguments, or arithmetic on those)")]
+    NonConstantRangeBound,
+
+    #[error("a loop binds a single variable, or `(index, element)` when iterating over `array.enumerate()`")]
+    InvalidForLoopPattern,
+
+    #[error("only arrays can be iterated over, not {0}")]
+    IterationOnNonArray(TyKind),
+
+    #[error("array sizes and slice bounds must be known at compile time (literals, constants, or arithmetic on those)")]
+    NonConstantArraySize,
+
+    #[error("invalid slice {0}..{1} of an array of {2} elements")]
+    InvalidSliceBounds(usize, usize, usize),
+
+    #[error("only arrays of known sizes can be concatenated, not {0}")]
+    InvalidConcatArgument(TyKind),
+
+    #[error("arrays have no method `{0}` (did you mean `len`?)")]
+    UndefinedArrayMethod(String),
+
+    #[error("the main function cannot be a hint function")]
+    HintInMain,
+
+    #[error("the main function cannot return a value of type {0}")]
+    InvalidMainReturnType(TyKind),
+
+    #[error("a hint function must return a value")]
+    HintWithoutReturn,
+
+    #[error("a hint function cannot modify its arguments, but `{0}` is mutable")]
+    MutableArgInHint(String),
+
+    #[error("a hint function cannot create constraints, so it can only call other hint functions (`{0}` is not one)")]
+    ConstraintInHint(String),
+
+    #[error("unterminated string literal")]
+    UnterminatedStringLiteral,
+
+    #[error("byte string literals can only contain ASCII characters")]
+    NonAsciiByteString,
+
+    #[error("string literals can only be used as the message of `assert` or `assert_eq`")]
+    UnexpectedStringLiteral,
+
+    #[error("bitwise operations can only be applied to integers, not {0}")]
+    InvalidBitwiseOperand(TyKind),
+
+    #[error(
+        "the shift amount must be a constant smaller than the bit length of the shifted integer"
+    )]
+    InvalidShiftAmount,
+
+    #[error("the remainder of a division can only be computed on integers, not {0}")]
+    InvalidRemainderOperand(TyKind),
+
+    #[error("enum variants must start with an uppercase letter, unlike `{0}`")]
+    InvalidVariantName(String),
+
+    #[error("the variant `{0}` holds {1} values, but {2} were given")]
+    MismatchVariantPayload(String, usize, usize),
+
+    #[error("invalid type for the variant's value, expected `{0}` and got `{1}`")]
+    InvalidVariantPayloadType(TyKind, TyKind),
+
+    #[error("only enums can be matched on, not {0}")]
+    MatchOnNonEnum(TyKind),
+
+    #[error("the variant `{0}` is not covered by any arm of this `match`")]
+    NonExhaustiveMatch(String),
+
+    #[error("this arm is unreachable, as its variant is already covered by a previous arm")]
+    UnreachableMatchArm,
+
+    #[error("the arms of a `match` must all return a value of the same type")]
+    MatchArmTypeMismatch,
+
+    #[error("type parameters must start with an uppercase letter, unlike `{0}`")]
+    InvalidTypeParam(String),
+
+    #[error("generic `{0}` is used with values of different types")]
+    ConflictingGenericType(String),
+
+    #[error("{0} does not implement the trait `{1}`")]
+    TraitNotImplemented(TyKind, String),
+
+    #[error("the trait `{0}` is already implemented for `{1}`")]
+    DuplicateTraitImpl(String, String),
+
+    #[error("the method `{0}` of the trait `{1}` is not implemented")]
+    MissingTraitMethod(String, String),
+
+    #[error("the method `{0}` is not part of the trait `{1}`")]
+    MethodNotInTrait(String, String),
+
+    #[error("the signature of the method `{0}` does not match the one declared in the trait")]
+    MismatchTraitMethod(String),
+
     #[error("struct `{0}` does not exist (are you sure it is defined?)")]
     UndefinedStruct(String),
 
     #[error("struct `{0}` does not have a field called `{1}`")]
     UndefinedField(String, String),
 
+    #[error("enum `{0}` does not exist (are you sure it is defined?)")]
+    UndefinedEnum(String),
+
+    #[error("enum `{0}` does not have a variant called `{1}`")]
+    UndefinedVariant(String, String),
+
+    #[error("trait `{0}` does not exist (are you sure it is defined?)")]
+    UndefinedTrait(String),
+
+    #[error("division by zero")]
+    DivisionByZero,
+
+    #[error("integer overflow: the value does not fit in {0} bits")]
+    IntegerOverflow(usize),
+
+    #[error("the bit length of a comparison must be a constant between 1 and {0}")]
+    InvalidComparisonBitlen(usize),
+
+    #[error("cannot convert {0} to {1}")]
+    InvalidCast(TyKind, TyKind),
+
     #[error("this assertion failed")]
     AssertionFailed,
 
-    #[error("constants can only have a literal decimal value")]
+    #[error("this assertion failed: {0}")]
+    AssertionFailedWithMessage(String),
+
+    #[error(
+        "constants can only be made of literals, other constants, and arrays or structs of those"
+    )]
     InvalidConstType,
 
     #[error("cannot compile a module without a main function")]
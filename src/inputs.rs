@@ -32,8 +32,14 @@ pub enum ParsingError {
     #[error("couldn't convert given field element `{0}`")]
     InvalidField(String),
 
+    #[error("given value `{0}` does not fit in a {1}")]
+    IntegerOutOfRange(String, TyKind),
+
     #[error("mismatch between expected argument format ({0}), and given argument in JSON (`{1}`)")]
     MismatchJsonArgument(TyKind, serde_json::Value),
+
+    #[error("enum `{0}` does not have a variant called `{1}`")]
+    UndefinedVariant(String, String),
 }
 
 //
@@ -90,7 +96,45 @@ impl<B: Backend> CompiledCircuit<B> {
                 };
                 Ok(vec![ff])
             }
+            (
+                TyKind::U8 | TyKind::U16 | TyKind::U32 | TyKind::U64,
+                input @ (Value::String(_) | Value::Number(_)),
+            ) => {
+                let ss = match input {
+                    Value::String(ss) => ss,
+                    nn => nn.to_string(),
+                };
 
+                let value =
+                    BigUint::from_str(&ss).map_err(|_| ParsingError::InvalidField(ss.clone()))?;
+
+                // reject values that wouldn't pass the range check in the circuit
+                let bitlen = expected_input.int_bitlen().unwrap();
+                if value.bits() > bitlen as u64 {
+                    return Err(ParsingError::IntegerOutOfRange(ss, expected_input.clone()));
+                }
+
+                let cell_value =
+                    B::Field::try_from(value).map_err(|_| ParsingError::InvalidField(ss))?;
+                Ok(vec![cell_value])
+            }
+
+            (TyKind::Tuple(typs), Value::Array(values)) => {
+                if values.len() != typs.len() {
+                    return Err(ParsingError::MismatchJsonArgument(
+                        expected_input.clone(),
+                        Value::Array(values),
+                    ));
+                }
+
+                let mut res = vec![];
+                for (value, typ) in values.into_iter().zip(typs) {
+                    let el = self.parse_single_input(value, typ)?;
+                    res.extend(el);
+                }
+
+                Ok(res)
+            }
             (TyKind::Array(el_typ, size), Value::Array(values)) => {
                 if values.len() != (*size as usize) {
                     panic!("wrong size of array");
@@ -103,10 +147,65 @@ impl<B: Backend> CompiledCircuit<B> {
 
                 Ok(res)
             }
+            // an enum is given as `{"Variant": [values]}`, or as `"Variant"` if it holds no values
+            (TyKind::Custom { module, name, .. }, input)
+                if self
+                    .circuit
+                    .enum_info(&FullyQualified::new(module, name))
+                    .is_some() =>
+            {
+                let qualified = FullyQualified::new(module, name);
+                let enum_info = self
+                    .circuit
+                    .enum_info(&qualified)
+                    .expect("compiler bug: couldn't find enum given as input");
+
+                let (variant, values) = match input {
+                    Value::String(variant) => (variant, vec![]),
+                    Value::Object(map) if map.len() == 1 => match map.into_iter().next().unwrap() {
+                        (variant, Value::Array(values)) => (variant, values),
+                        (_, value) => {
+                            return Err(ParsingError::MismatchJsonArgument(
+                                expected_input.clone(),
+                                value,
+                            ))
+                        }
+                    },
+                    input => {
+                        return Err(ParsingError::MismatchJsonArgument(
+                            expected_input.clone(),
+                            input,
+                        ))
+                    }
+                };
+
+                let (tag, payload) = enum_info
+                    .variant(&variant)
+                    .ok_or_else(|| ParsingError::UndefinedVariant(name.clone(), variant.clone()))?;
+
+                if payload.len() != values.len() {
+                    return Err(ParsingError::MismatchJsonArgument(
+                        expected_input.clone(),
+                        Value::Array(values),
+                    ));
+                }
+
+                // the tag, followed by the values held by the variant,
+                // padded with zeros to the size of the largest variant
+                let mut res = vec![B::Field::from(tag as u64)];
+                for (value, typ) in values.into_iter().zip(payload) {
+                    let parsed = self.parse_single_input(value, typ)?;
+                    res.extend(parsed);
+                }
+                res.resize(self.circuit.size_of(expected_input), B::Field::zero());
+
+                Ok(res)
+            }
             (
                 TyKind::Custom {
                     module,
                     name: struct_name,
+                    sizes,
                 },
                 Value::Object(mut map),
             ) => {
@@ -116,7 +215,7 @@ impl<B: Backend> CompiledCircuit<B> {
                     .circuit
                     .struct_info(&qualified)
                     .expect("compiler bug: couldn't find struct given as input");
-                let fields = &struct_info.fields;
+                let fields = &struct_info.fields_of(sizes);
 
                 // make sure that they're the same length
                 if fields.len() != map.len() {
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use crate::{
     constants::Span,
     error::{Error, ErrorKind, Result},
-    syntax::is_identifier_or_type,
+    syntax::{is_identifier_or_type, is_type},
 };
 
 use num_bigint::BigUint;
@@ -68,6 +68,20 @@ pub enum Keyword {
     Struct,
     /// Allows constants to be defined
     Const,
+    /// Explicit conversion between types
+    As,
+    /// Allows custom enums to be defined
+    Enum,
+    /// Pattern matching on enums
+    Match,
+    /// Allows traits (sets of methods) to be declared
+    Trait,
+    /// Implements a trait for a custom struct
+    Impl,
+    /// A function only run at witness generation time
+    Hint,
+    /// Allows type aliases to be defined
+    Type,
 }
 
 impl Keyword {
@@ -87,6 +101,13 @@ impl Keyword {
             "in" => Some(Self::In),
             "struct" => Some(Self::Struct),
             "const" => Some(Self::Const),
+            "as" => Some(Self::As),
+            "enum" => Some(Self::Enum),
+            "match" => Some(Self::Match),
+            "trait" => Some(Self::Trait),
+            "impl" => Some(Self::Impl),
+            "hint" => Some(Self::Hint),
+            "type" => Some(Self::Type),
             _ => None,
         }
     }
@@ -109,6 +130,13 @@ impl Display for Keyword {
             Self::In => "in",
             Self::Struct => "struct",
             Self::Const => "const",
+            Self::As => "as",
+            Self::Enum => "enum",
+            Self::Match => "match",
+            Self::Trait => "trait",
+            Self::Impl => "impl",
+            Self::Hint => "hint",
+            Self::Type => "type",
         };
 
         write!(f, "{}", desc)
@@ -135,21 +163,30 @@ pub enum TokenKind {
     Slash,              // /
     Comment(String),    // // comment
     Greater,            // >
+    GreaterEqual,       // >=
+    DoubleGreater,      // >>
     Less,               // <
+    LessEqual,          // <=
+    DoubleLess,         // <<
     Equal,              // =
     DoubleEqual,        // ==
     NotEqual,           // !=
     Plus,               // +
     Minus,              // -
     RightArrow,         // ->
+    FatArrow,           // =>
     Star,               // *
+    Percent,            // %
     Ampersand,          // &
     DoubleAmpersand,    // &&
     Pipe,               // |
     DoublePipe,         // ||
+    Caret,              // ^
     Exclamation,        // !
     Question,           // ?
-                        //    Literal,               // "thing"
+    Underscore,         // _
+    Literal(String),    // "thing"
+    Bytes(String),      // b"thing"
 }
 
 impl Display for TokenKind {
@@ -176,21 +213,30 @@ impl Display for TokenKind {
             Slash => "`/`",
             Comment(_) => "`//`",
             Greater => "`>`",
+            GreaterEqual => "`>=`",
+            DoubleGreater => "`>>`",
             Less => "`<`",
+            LessEqual => "`<=`",
+            DoubleLess => "`<<`",
             Equal => "`=`",
             DoubleEqual => "`==`",
             NotEqual => "`!=`",
             Plus => "`+`",
             Minus => "`-`",
             RightArrow => "`->`",
+            FatArrow => "`=>`",
             Star => "`*`",
+            Percent => "`%`",
             Ampersand => "`&`",
             DoubleAmpersand => "`&&`",
             Pipe => "`|`",
             DoublePipe => "`||`",
+            Caret => "`^`",
             Exclamation => "`!`",
             Question => "`?`",
-            //            TokenType::Literal => "`\"something\"",
+            Underscore => "`_`",
+            Literal(_) => "a string literal (e.g. `\"something\"`)",
+            Bytes(_) => "a byte string literal (e.g. `b\"something\"`)",
         };
 
         write!(f, "{}", desc)
@@ -230,6 +276,8 @@ impl Token {
             let len = ident_or_number.len();
             if let Some(keyword) = Keyword::parse(&ident_or_number) {
                 tokens.push(TokenKind::Keyword(keyword).new_token(ctx, len));
+            } else if ident_or_number == "_" {
+                tokens.push(TokenKind::Underscore.new_token(ctx, len));
             } else {
                 let token_type = if let Ok(big_uint) = BigUint::from_str_radix(&ident_or_number, 10)
                 {
@@ -246,7 +294,8 @@ impl Token {
                         }
                     }
                 } else if is_identifier_or_type(&ident_or_number) {
-                    if ident_or_number.len() < 2 {
+                    // single uppercase letters are allowed, as they're used for generics (e.g. `N`)
+                    if ident_or_number.len() < 2 && !is_type(&ident_or_number) {
                         return Err(ctx.error(
                             ErrorKind::NoOneLetterVariable,
                             Span::new(ctx.filename_id, ctx.offset, 1),
@@ -281,6 +330,12 @@ impl Token {
                 break;
             };
 
+            // a `b` right before a string literal makes it a byte string literal (e.g. `b"thing"`)
+            let bytes = c == '"' && ident_or_number.as_deref() == Some("b");
+            if bytes {
+                ident_or_number = None;
+            }
+
             // where we in the middle of parsing an ident or number?
             if !c.is_alphanumeric() && c != '_' {
                 if let Some(ident_or_number) = ident_or_number.take() {
@@ -361,16 +416,37 @@ impl Token {
                     }
                 }
                 '>' => {
-                    tokens.push(TokenKind::Greater.new_token(ctx, 1));
+                    let next_c = chars.peek();
+                    if matches!(next_c, Some(&'=')) {
+                        tokens.push(TokenKind::GreaterEqual.new_token(ctx, 2));
+                        chars.next();
+                    } else if matches!(next_c, Some(&'>')) {
+                        tokens.push(TokenKind::DoubleGreater.new_token(ctx, 2));
+                        chars.next();
+                    } else {
+                        tokens.push(TokenKind::Greater.new_token(ctx, 1));
+                    }
                 }
                 '<' => {
-                    tokens.push(TokenKind::Less.new_token(ctx, 1));
+                    let next_c = chars.peek();
+                    if matches!(next_c, Some(&'=')) {
+                        tokens.push(TokenKind::LessEqual.new_token(ctx, 2));
+                        chars.next();
+                    } else if matches!(next_c, Some(&'<')) {
+                        tokens.push(TokenKind::DoubleLess.new_token(ctx, 2));
+                        chars.next();
+                    } else {
+                        tokens.push(TokenKind::Less.new_token(ctx, 1));
+                    }
                 }
                 '=' => {
                     let next_c = chars.peek();
                     if matches!(next_c, Some(&'=')) {
                         tokens.push(TokenKind::DoubleEqual.new_token(ctx, 2));
                         chars.next();
+                    } else if matches!(next_c, Some(&'>')) {
+                        tokens.push(TokenKind::FatArrow.new_token(ctx, 2));
+                        chars.next();
                     } else {
                         tokens.push(TokenKind::Equal.new_token(ctx, 1));
                     }
@@ -390,6 +466,9 @@ impl Token {
                 '*' => {
                     tokens.push(TokenKind::Star.new_token(ctx, 1));
                 }
+                '%' => {
+                    tokens.push(TokenKind::Percent.new_token(ctx, 1));
+                }
                 '&' => {
                     let next_c = chars.peek();
                     if matches!(next_c, Some(&'&')) {
@@ -408,6 +487,9 @@ impl Token {
                         tokens.push(TokenKind::Pipe.new_token(ctx, 1));
                     }
                 }
+                '^' => {
+                    tokens.push(TokenKind::Caret.new_token(ctx, 1));
+                }
                 '!' => {
                     let next_c = chars.peek();
                     if matches!(next_c, Some(&'=')) {
@@ -421,6 +503,38 @@ impl Token {
                 '?' => {
                     tokens.push(TokenKind::Question.new_token(ctx, 1));
                 }
+                '"' => {
+                    let prefix_len = if bytes { 1 } else { 0 };
+                    let mut literal = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(c) => literal.push(c),
+                            None => {
+                                return Err(ctx.error(
+                                    ErrorKind::UnterminatedStringLiteral,
+                                    Span::new(
+                                        ctx.filename_id,
+                                        ctx.offset,
+                                        prefix_len + 1 + literal.len(),
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    let len = prefix_len + literal.len() + 2;
+                    if !bytes {
+                        tokens.push(TokenKind::Literal(literal).new_token(ctx, len));
+                    } else if literal.is_ascii() {
+                        tokens.push(TokenKind::Bytes(literal).new_token(ctx, len));
+                    } else {
+                        return Err(ctx.error(
+                            ErrorKind::NonAsciiByteString,
+                            Span::new(ctx.filename_id, ctx.offset, len),
+                        ));
+                    }
+                }
                 ' ' => ctx.offset += 1,
                 _ => {
                     return Err(ctx.error(
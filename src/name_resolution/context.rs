@@ -1,15 +1,15 @@
 use std::collections::HashMap;
 
-use ark_ff::Field;
-
 use crate::{
     cli::packages::UserRepo,
     constants::Span,
     error::{Error, ErrorKind, Result},
     parser::{
         types::{FnArg, FnSig, FuncOrMethod, ModulePath, Stmt, StmtKind, TyKind},
-        ConstDef, CustomType, FunctionDef, StructDef, UsePath,
+        ConstDef, CustomType, EnumDef, FunctionDef, ImplDef, StructDef, TraitDef, TypeAliasDef,
+        UsePath,
     },
+    type_checker::FullyQualified,
 };
 
 pub struct NameResCtx {
@@ -18,13 +18,20 @@ pub struct NameResCtx {
 
     /// maps `module` to its original `use a::module`
     pub modules: HashMap<String, UsePath>,
+
+    /// maps type aliases (of this module and of the modules already processed) to the type they stand for
+    pub type_aliases: HashMap<FullyQualified, TyKind>,
 }
 
 impl NameResCtx {
-    pub(crate) fn new(this_module: Option<UserRepo>) -> Self {
+    pub(crate) fn new(
+        this_module: Option<UserRepo>,
+        type_aliases: HashMap<FullyQualified, TyKind>,
+    ) -> Self {
         Self {
             this_module,
             modules: HashMap::new(),
+            type_aliases,
         }
     }
 
@@ -72,11 +79,27 @@ impl NameResCtx {
         // signature
         //
 
+        self.resolve_fn_sig(sig)?;
+
+        //
+        // body
+        //
+
+        for stmt in body {
+            self.resolve_stmt(stmt)?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_fn_sig(&self, sig: &mut FnSig) -> Result<()> {
         let FnSig {
             kind,
             name: _,
+            type_params,
             arguments,
             return_type,
+            hint: _,
         } = sig;
 
         match kind {
@@ -112,26 +135,44 @@ impl NameResCtx {
             self.resolve_typ_kind(&mut return_type.kind)?;
         }
 
-        //
-        // body
-        //
-
-        for stmt in body {
-            self.resolve_stmt(stmt)?;
+        // as well as the traits bounding the generic types
+        for type_param in type_params {
+            self.resolve(&mut type_param.bound.module, false)?;
         }
 
         Ok(())
     }
 
-    fn resolve_typ_kind(&self, typ_kind: &mut TyKind) -> Result<()> {
+    pub(crate) fn resolve_typ_kind(&self, typ_kind: &mut TyKind) -> Result<()> {
         match typ_kind {
             TyKind::Field => (),
-            TyKind::Custom { module, name: _ } => {
+            TyKind::Custom { module, name, .. } => {
                 self.resolve(module, false)?;
+
+                // type aliases are replaced by the type they stand for
+                let qualified = FullyQualified::new(module, name);
+                if let Some(typ) = self.type_aliases.get(&qualified) {
+                    *typ_kind = typ.clone();
+                }
             }
             TyKind::BigInt => (),
-            TyKind::Array(typ_kind, _) => self.resolve_typ_kind(typ_kind)?,
+            TyKind::Array(typ_kind, _) | TyKind::GenericSizedArray(typ_kind, _) => {
+                self.resolve_typ_kind(typ_kind)?
+            }
             TyKind::Bool => (),
+            TyKind::U8 | TyKind::U16 | TyKind::U32 | TyKind::U64 => (),
+            TyKind::Tuple(typs) => {
+                for typ in typs {
+                    self.resolve_typ_kind(typ)?;
+                }
+            }
+            TyKind::Generic {
+                name: _,
+                trait_module,
+                trait_name: _,
+            } => {
+                self.resolve(trait_module, false)?;
+            }
         };
 
         Ok(())
@@ -156,16 +197,96 @@ impl NameResCtx {
         Ok(())
     }
 
-    pub(crate) fn resolve_const_def<F: Field>(&self, cst_def: &mut ConstDef<F>) -> Result<()> {
+    pub(crate) fn resolve_enum_def(&self, enum_def: &mut EnumDef) -> Result<()> {
+        let EnumDef {
+            module,
+            name: _,
+            variants,
+            span: _,
+        } = enum_def;
+
+        // we set the fully-qualified name of the enum
+        self.resolve(module, true)?;
+
+        // we resolve the fully-qualified types of the payloads
+        for (_variant_name, payload) in variants {
+            for typ in payload {
+                self.resolve_typ_kind(&mut typ.kind)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn resolve_trait_def(&self, trait_def: &mut TraitDef) -> Result<()> {
+        let TraitDef {
+            module,
+            name: _,
+            methods,
+            span: _,
+        } = trait_def;
+
+        // we set the fully-qualified name of the trait
+        self.resolve(module, true)?;
+
+        // we resolve the signatures of its methods
+        for sig in methods {
+            self.resolve_fn_sig(sig)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn resolve_impl_def(&self, impl_def: &mut ImplDef) -> Result<()> {
+        let ImplDef {
+            trait_name,
+            custom,
+            methods,
+            span: _,
+        } = impl_def;
+
+        // the trait might be imported, but the struct must be local
+        self.resolve(&mut trait_name.module, false)?;
+        self.resolve(&mut custom.module, true)?;
+
+        for method in methods {
+            self.resolve_fn_def(method)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn resolve_type_alias_def(&self, alias_def: &mut TypeAliasDef) -> Result<()> {
+        let TypeAliasDef {
+            module,
+            name: _,
+            typ,
+            span: _,
+        } = alias_def;
+
+        self.resolve(module, true)?;
+        self.resolve_typ_kind(&mut typ.kind)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn resolve_const_def(&self, cst_def: &mut ConstDef) -> Result<()> {
         let ConstDef {
             module,
             name: _,
-            value: _,
+            typ,
+            value,
             span: _,
         } = cst_def;
 
         self.resolve(module, true)?;
 
+        if let Some(typ) = typ {
+            self.resolve_typ_kind(&mut typ.kind)?;
+        }
+
+        self.resolve_expr(value)?;
+
         Ok(())
     }
 
@@ -176,8 +297,12 @@ impl NameResCtx {
             StmtKind::Assign {
                 mutable: _,
                 lhs: _,
+                typ,
                 rhs,
             } => {
+                if let Some(typ) = typ {
+                    self.resolve_typ_kind(&mut typ.kind)?;
+                }
                 self.resolve_expr(rhs)?;
             }
             StmtKind::Expr(expr) => self.resolve_expr(expr)?,
@@ -185,13 +310,35 @@ impl NameResCtx {
             StmtKind::Comment(_) => (),
             StmtKind::ForLoop {
                 var: _,
-                range: _,
+                range,
                 body,
             } => {
+                self.resolve_expr(&mut range.start)?;
+                self.resolve_expr(&mut range.end)?;
+
                 for stmt in body {
                     self.resolve_stmt(stmt)?;
                 }
             }
+            StmtKind::ForIn {
+                index: _,
+                var: _,
+                array,
+                body,
+            } => {
+                self.resolve_expr(array)?;
+
+                for stmt in body {
+                    self.resolve_stmt(stmt)?;
+                }
+            }
+            StmtKind::IfElse { cond, then_, else_ } => {
+                self.resolve_expr(cond)?;
+
+                for stmt in then_.iter_mut().chain(else_.iter_mut()) {
+                    self.resolve_stmt(stmt)?;
+                }
+            }
         };
 
         Ok(())
@@ -1,7 +1,7 @@
 use crate::{
     cli::packages::UserRepo,
     error::Result,
-    parser::{types::ModulePath, CustomType, Expr, ExprKind},
+    parser::{types::ModulePath, CustomType, Expr, ExprKind, MatchPattern},
     stdlib::{BUILTIN_FN_NAMES, QUALIFIED_BUILTINS},
 };
 
@@ -66,6 +66,8 @@ impl NameResCtx {
                 self.resolve_expr(expr)?;
             }
             ExprKind::BigUInt(_) => {}
+            ExprKind::StringLiteral(_) => {}
+            ExprKind::ByteString(_) => {}
             ExprKind::Variable { module, name: _ } => {
                 self.resolve(module, false)?;
             }
@@ -73,11 +75,20 @@ impl NameResCtx {
                 self.resolve_expr(array)?;
                 self.resolve_expr(idx)?;
             }
+            ExprKind::ArraySlice { array, start, end } => {
+                self.resolve_expr(array)?;
+                self.resolve_expr(start)?;
+                self.resolve_expr(end)?;
+            }
             ExprKind::ArrayDeclaration(items) => {
                 for expr in items {
                     self.resolve_expr(expr)?;
                 }
             }
+            ExprKind::RepeatedArrayDeclaration { item, size } => {
+                self.resolve_expr(item)?;
+                self.resolve_expr(size)?;
+            }
             ExprKind::CustomTypeDeclaration {
                 custom: struct_name,
                 fields,
@@ -98,6 +109,37 @@ impl NameResCtx {
                 self.resolve_expr(then_)?;
                 self.resolve_expr(else_)?;
             }
+            ExprKind::Cast { lhs, typ } => {
+                self.resolve_expr(lhs)?;
+                self.resolve_typ_kind(&mut typ.kind)?;
+            }
+            ExprKind::TupleDeclaration(items) => {
+                for expr in items {
+                    self.resolve_expr(expr)?;
+                }
+            }
+            ExprKind::TupleAccess { lhs, idx: _ } => {
+                self.resolve_expr(lhs)?;
+            }
+            ExprKind::EnumVariant {
+                custom,
+                variant: _,
+                args,
+            } => {
+                self.resolve(&mut custom.module, true)?;
+                for arg in args {
+                    self.resolve_expr(arg)?;
+                }
+            }
+            ExprKind::Match { expr, arms } => {
+                self.resolve_expr(expr)?;
+                for arm in arms {
+                    if let MatchPattern::Variant { custom, .. } = &mut arm.pattern {
+                        self.resolve(&mut custom.module, true)?;
+                    }
+                    self.resolve_expr(&mut arm.expr)?;
+                }
+            }
         };
 
         Ok(())
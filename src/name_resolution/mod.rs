@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
 use crate::{
-    backends::Backend,
     cli::packages::UserRepo,
     error::{Error, ErrorKind, Result},
-    parser::{ConstDef, FunctionDef, RootKind, StructDef, AST},
+    parser::{
+        types::TyKind, ConstDef, EnumDef, FunctionDef, ImplDef, RootKind, StructDef, TraitDef,
+        TypeAliasDef, AST,
+    },
+    type_checker::FullyQualified,
 };
 
 use self::context::NameResCtx;
@@ -10,20 +15,22 @@ use self::context::NameResCtx;
 mod context;
 mod expr;
 
-pub struct NAST<B>
-where
-    B: Backend,
-{
-    pub ast: AST<B>,
+pub struct NAST {
+    pub ast: AST,
 }
 
-impl<B: Backend> NAST<B> {
-    fn new(ast: AST<B>) -> Self {
+impl NAST {
+    fn new(ast: AST) -> Self {
         Self { ast }
     }
 
-    pub fn resolve_modules(this_module: Option<UserRepo>, mut ast: AST<B>) -> Result<NAST<B>> {
-        let mut ctx = NameResCtx::new(this_module);
+    /// `type_aliases` contains the type aliases defined in the modules already processed.
+    pub fn resolve_modules(
+        this_module: Option<UserRepo>,
+        type_aliases: &HashMap<FullyQualified, TyKind>,
+        mut ast: AST,
+    ) -> Result<NAST> {
+        let mut ctx = NameResCtx::new(this_module, type_aliases.clone());
 
         // create a map of the imported modules (and how they are aliases)
         let mut abort = None;
@@ -54,18 +61,36 @@ impl<B: Backend> NAST<B> {
                 }
                 RootKind::FunctionDef(FunctionDef { span, .. })
                 | RootKind::StructDef(StructDef { span, .. })
-                | RootKind::ConstDef(ConstDef { span, .. }) => abort = Some(*span),
+                | RootKind::EnumDef(EnumDef { span, .. })
+                | RootKind::TraitDef(TraitDef { span, .. })
+                | RootKind::ImplDef(ImplDef { span, .. })
+                | RootKind::ConstDef(ConstDef { span, .. })
+                | RootKind::TypeAliasDef(TypeAliasDef { span, .. }) => abort = Some(*span),
                 RootKind::Comment(_) => (),
             }
         }
 
+        // resolve the type aliases first, as they are replaced by the type they stand for everywhere else
+        // (an alias can only refer to the aliases declared before it)
+        for root in &mut ast.0 {
+            if let RootKind::TypeAliasDef(alias) = &mut root.kind {
+                ctx.resolve_type_alias_def(alias)?;
+
+                let qualified = FullyQualified::new(&alias.module, &alias.name.name);
+                ctx.type_aliases.insert(qualified, alias.typ.kind.clone());
+            }
+        }
+
         // now go through the AST and mutate any module to its fully-qualified path
         for root in &mut ast.0 {
             match &mut root.kind {
                 RootKind::FunctionDef(f) => ctx.resolve_fn_def(f)?,
                 RootKind::StructDef(s) => ctx.resolve_struct_def(s)?,
+                RootKind::EnumDef(e) => ctx.resolve_enum_def(e)?,
+                RootKind::TraitDef(t) => ctx.resolve_trait_def(t)?,
+                RootKind::ImplDef(i) => ctx.resolve_impl_def(i)?,
                 RootKind::ConstDef(c) => ctx.resolve_const_def(c)?,
-                RootKind::Use(_) | RootKind::Comment(_) => (),
+                RootKind::Use(_) | RootKind::TypeAliasDef(_) | RootKind::Comment(_) => (),
             }
         }
 
@@ -76,7 +101,6 @@ impl<B: Backend> NAST<B> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        backends::kimchi::KimchiVesta,
         lexer::Token,
         parser::{
             types::{ModulePath, StmtKind},
@@ -104,8 +128,8 @@ mod tests {
     #[test]
     fn test_name_res() {
         let tokens = Token::parse(0, CODE).unwrap();
-        let (ast, _node_id) = AST::<KimchiVesta>::parse(0, tokens, 0).unwrap();
-        let nast = NAST::resolve_modules(None, ast).unwrap();
+        let (ast, _node_id) = AST::parse(0, tokens, 0).unwrap();
+        let nast = NAST::resolve_modules(None, &HashMap::new(), ast).unwrap();
 
         // find constant declaration
         let mut roots = nast
@@ -151,8 +175,8 @@ mod tests {
         let user_repo = UserRepo::new("mimoo/example");
 
         let tokens = Token::parse(0, CODE).unwrap();
-        let (ast, _node_id) = AST::<KimchiVesta>::parse(0, tokens, 0).unwrap();
-        let nast = NAST::resolve_modules(Some(user_repo.clone()), ast).unwrap();
+        let (ast, _node_id) = AST::parse(0, tokens, 0).unwrap();
+        let nast = NAST::resolve_modules(Some(user_repo.clone()), &HashMap::new(), ast).unwrap();
 
         // find constant declaration
         let mut roots = nast
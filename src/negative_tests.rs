@@ -1,7 +1,13 @@
 use crate::{
-    backends::kimchi::KimchiVesta,
-    compiler::{typecheck_next_file_inner, Sources},
+    backends::{
+        kimchi::KimchiVesta,
+        r1cs::{R1csBls12381Field, R1CS},
+    },
+    circuit_writer::CircuitWriter,
+    compiler::{compile, typecheck_next_file_inner, Sources},
     error::ErrorKind,
+    inputs::{parse_inputs, ParsingError},
+    parser::types::TyKind,
     type_checker::TypeChecker,
 };
 
@@ -73,3 +79,1357 @@ fn test_return_mismatch() {
         ErrorKind::ReturnTypeMismatch(..)
     ));
 }
+
+#[test]
+fn test_division_by_constant_zero() {
+    let code = r#"
+        fn main(pub xx: Field) {
+            let yy = xx / 0;
+            assert_eq(yy, 1);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let res = CircuitWriter::generate_circuit(tast, KimchiVesta::new(false));
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::DivisionByZero
+    ));
+}
+
+#[test]
+fn test_comparison_of_booleans() {
+    let code = r#"
+        fn main(pub xx: Bool, yy: Bool) {
+            let zz = xx < yy;
+            assert(zz);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(res.unwrap_err().kind, ErrorKind::MismatchType(..)));
+}
+
+#[test]
+fn test_integer_mixed_with_field() {
+    let code = r#"
+        fn main(pub xx: U8, yy: Field) {
+            let zz = xx + yy;
+            assert(zz == 1);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(res.unwrap_err().kind, ErrorKind::MismatchType(..)));
+}
+
+#[test]
+fn test_integer_overflow() {
+    let code = r#"
+        fn main(pub xx: U8, yy: U8) {
+            let zz = xx + yy;
+            assert(zz > 0);
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "200"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "100"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_literal_overflow() {
+    let code = r#"
+        fn main(pub xx: U8) {
+            assert(xx < 300);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::IntegerOverflow(8)
+    ));
+}
+
+#[test]
+fn test_integer_input_out_of_range() {
+    let code = r#"
+        fn main(pub xx: U8) {
+            assert(xx > 0);
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "256"}"#).unwrap();
+    let private_inputs = parse_inputs("{}").unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::ParsingError(ParsingError::IntegerOutOfRange(..))
+    ));
+}
+
+#[test]
+fn test_field_comparison_out_of_range() {
+    // the largest field element must not be considered smaller than 1000
+    let code = r#"
+        fn main(pub xx: Field) {
+            assert(xx < 1000);
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(
+        r#"{"xx": "52435875175126190479447740508185965837690552500527637822603658699938581184512"}"#,
+    )
+    .unwrap();
+    let private_inputs = parse_inputs("{}").unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_less_than_operand_out_of_range() {
+    let code = r#"
+        fn main(pub xx: Field, yy: Field) {
+            assert(less_than(xx, yy, 8));
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "1"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "300"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_less_than_non_constant_bitlen() {
+    let code = r#"
+        fn main(pub xx: Field, yy: Field) {
+            assert(less_than(xx, 1, yy));
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let res = CircuitWriter::generate_circuit(tast, KimchiVesta::new(false));
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidComparisonBitlen(..)
+    ));
+}
+
+#[test]
+fn test_tuple_destructuring_mismatch() {
+    let code = r#"
+        fn main(pub xx: Field) {
+            let (aa, bb, cc) = (xx, xx);
+            assert_eq(aa, bb);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::InvalidDestructuring(_, 3)
+    ));
+}
+
+#[test]
+fn test_generic_size_mismatch() {
+    let code = r#"
+        fn first_sum(lhs: [Field; N], rhs: [Field; N]) -> Field {
+            return lhs[0] + rhs[0];
+        }
+
+        fn main(pub xx: Field) {
+            let res = first_sum([xx, xx], [xx, xx, xx]);
+            assert_eq(res, xx);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::ConflictingGenericSize(..)
+    ));
+}
+
+#[test]
+fn test_generic_struct_without_sizes() {
+    let code = r#"
+        struct Bag {
+            items: [Field; N],
+        }
+
+        fn main(pub bag: Bag) {
+            assert_eq(bag.items[0], 1);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::MismatchStructSizes(_, 1, 0)
+    ));
+}
+
+#[test]
+fn test_dynamic_index_out_of_bounds() {
+    let code = r#"
+        fn main(pub xx: [Field; 3], idx: Field) {
+            assert_eq(xx[idx], 1);
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": ["1", "1", "1"]}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"idx": "3"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_dynamic_index_out_of_bounds_lookup() {
+    let code = r#"
+        struct Thing {
+            first: Field,
+            second: Field,
+        }
+
+        fn main(pub xx: [Field; 3], idx: Field) {
+            let things = [
+                Thing { first: xx[0], second: xx[0] },
+                Thing { first: xx[1], second: xx[1] },
+                Thing { first: xx[2], second: xx[2] },
+            ];
+            assert_eq(things[idx].second, 0);
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, KimchiVesta::new(false)).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": ["1", "1", "1"]}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"idx": "3"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    // the lookup of the out-of-bounds index fails
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_if_statement_non_boolean_condition() {
+    let code = r#"
+        fn main(pub xx: Field) {
+            if xx {
+                assert_eq(xx, 1);
+            }
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::IfElseInvalidConditionType(..)
+    ));
+}
+
+#[test]
+fn test_missing_return_in_branch() {
+    let code = r#"
+    fn thing(xx: Field) -> Field {
+        if xx == 1 {
+            return 2;
+        }
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(res.unwrap_err().kind, ErrorKind::MissingReturn));
+}
+
+#[test]
+fn test_unreachable_statement() {
+    let code = r#"
+    fn thing(xx: Field) -> Field {
+        if xx == 1 {
+            return 2;
+        } else {
+            return 3;
+        }
+        return 4;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::UnreachableStatement
+    ));
+}
+
+#[test]
+fn test_non_constant_range_bound() {
+    let code = r#"
+    fn main(pub xx: Field) {
+        for ii in 0..xx {
+            assert_eq(ii, 0);
+        }
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::NonConstantRangeBound
+    ));
+}
+
+#[test]
+fn test_bitwise_on_field() {
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) -> Field {
+        return xx ^ yy;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::InvalidBitwiseOperand(..)
+    ));
+}
+
+#[test]
+fn test_rotation_out_of_range() {
+    let code = r#"
+        fn main(pub xx: U64) -> U64 {
+            return rotate_left(xx, 64);
+        }
+        "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let res = CircuitWriter::generate_circuit(tast, KimchiVesta::new(false));
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidShiftAmount
+    ));
+}
+
+#[test]
+fn test_int_division_by_zero() {
+    let code = r#"
+        fn main(pub xx: U32, yy: U32) -> U32 {
+            return xx / yy;
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "10"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "0"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_division_by_zero_in_active_branch() {
+    let code = r#"
+        fn main(pub xx: Field, yy: Field) -> Field {
+            let mut res = xx;
+            if xx == 10 {
+                res = xx / yy;
+            }
+            return res;
+        }
+        "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    // the divisor can only be zero if the branch is not taken
+    let public_inputs = parse_inputs(r#"{"xx": "10"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "0"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitness(..)
+    ));
+}
+
+#[test]
+fn test_remainder_on_field() {
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) -> Field {
+        return xx % yy;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::InvalidRemainderOperand(..)
+    ));
+}
+
+#[test]
+fn test_mut_arg_on_immutable_var() {
+    let code = r#"
+    fn double(mut xx: Field) {
+        xx = xx * 2;
+    }
+
+    fn main(pub xx: Field) {
+        let yy = xx;
+        double(yy);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::ImmutableArgument
+    ));
+}
+
+#[test]
+fn test_nested_assignment_to_immutable_var() {
+    let code = r#"
+    struct Thing {
+        values: [Field; 2],
+    }
+
+    fn main(pub xx: Field) {
+        let thing = Thing { values: [0, 0] };
+        thing.values[1] = xx;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::AssignmentToImmutableVariable
+    ));
+}
+
+#[test]
+fn test_non_exhaustive_match() {
+    let code = r#"
+    enum Tx {
+        Transfer(Field, Field),
+        Mint(Field),
+        Burn,
+    }
+
+    fn main(pub tx: Tx) -> Field {
+        let amount = match tx {
+            Tx::Transfer(to, value) => value,
+            Tx::Mint(minted) => minted,
+        };
+        return amount;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::NonExhaustiveMatch(variant) if variant == "Burn"
+    ));
+}
+
+#[test]
+fn test_unreachable_match_arm() {
+    let code = r#"
+    enum Tx {
+        Mint(Field),
+        Burn,
+    }
+
+    fn main(pub tx: Tx) -> Field {
+        let amount = match tx {
+            Tx::Mint(minted) => minted,
+            _ => 0,
+            Tx::Burn => 1,
+        };
+        return amount;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::UnreachableMatchArm
+    ));
+}
+
+#[test]
+fn test_trait_not_implemented() {
+    let code = r#"
+    trait Hashable {
+        fn hash(self) -> Field;
+    }
+
+    struct Point {
+        xx: Field,
+    }
+
+    fn digest<T: Hashable>(value: T) -> Field {
+        return value.hash();
+    }
+
+    fn main(pub point: Point) -> Field {
+        return digest(point);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::TraitNotImplemented(_, bound) if bound == "Hashable"
+    ));
+}
+
+#[test]
+fn test_missing_trait_method() {
+    let code = r#"
+    trait Hashable {
+        fn hash(self) -> Field;
+        fn size(self) -> Field;
+    }
+
+    struct Point {
+        xx: Field,
+    }
+
+    impl Hashable for Point {
+        fn hash(self) -> Field {
+            return self.xx;
+        }
+    }
+
+    fn main(pub point: Point) -> Field {
+        return point.hash();
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::MissingTraitMethod(method, _) if method == "size"
+    ));
+}
+
+#[test]
+fn test_iteration_on_non_array() {
+    let code = r#"
+    fn main(pub xx: Field) -> Field {
+        let mut sum = 0;
+        for elem in xx {
+            sum = sum + elem;
+        }
+        return sum;
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::IterationOnNonArray(TyKind::BigInt)
+    ));
+}
+
+#[test]
+fn test_constraint_in_hint() {
+    let code = r#"
+    hint fn inverse(xx: Field) -> Field {
+        let inv = 1 / xx;
+        assert_eq(inv * xx, 1);
+        return inv;
+    }
+
+    fn main(pub xx: Field) -> Field {
+        return inverse(xx);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::ConstraintInHint(name) if name == "assert_eq"
+    ));
+}
+
+#[test]
+fn test_assert_message() {
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) {
+        let balance = xx + yy;
+        assert(balance != 0);
+        assert_eq(balance, 10, "balance too low");
+    }
+    "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<R1CS<R1csBls12381Field>>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, R1CS::new()).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "2"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "3"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitnessWithMessage(_, message) if message == "balance too low"
+    ));
+}
+
+#[test]
+fn test_assert_message_double_generic() {
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) {
+        assert(xx == yy, "not equal");
+        assert_eq(xx + 1, 3);
+    }
+    "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, KimchiVesta::new(true)).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "2"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "3"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitnessWithMessage(_, message) if message == "not equal"
+    ));
+}
+
+#[test]
+fn test_assert_message_pending_generic() {
+    // the first assertion is queued, and ends up in the same row as the second one
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) {
+        assert_eq(xx, 4, "xx mismatch");
+        assert_eq(yy, 3, "yy mismatch");
+        assert_eq(xx + yy, 5);
+    }
+    "#;
+
+    let mut sources = Sources::new();
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut sources,
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    )
+    .unwrap();
+
+    let compiled_circuit = compile(&sources, tast, KimchiVesta::new(true)).unwrap();
+
+    let public_inputs = parse_inputs(r#"{"xx": "2"}"#).unwrap();
+    let private_inputs = parse_inputs(r#"{"yy": "3"}"#).unwrap();
+    let res = compiled_circuit.generate_witness(public_inputs, private_inputs);
+
+    assert!(matches!(
+        res.err().expect("expected an error").kind,
+        ErrorKind::InvalidWitnessWithMessage(_, message) if message == "xx mismatch"
+    ));
+}
+
+#[test]
+fn test_unexpected_string_literal() {
+    let code = r#"
+    fn main(pub xx: Field) {
+        let msg = "hello";
+        assert_eq(xx, 1);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::UnexpectedStringLiteral
+    ));
+}
+
+#[test]
+fn test_log_arguments() {
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) {
+        log(xx, yy);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::MismatchFunctionArguments(2, 1)
+    ));
+}
+
+#[test]
+fn test_byte_string_length() {
+    let code = r#"
+    fn check(prefix: [Field; 4]) {
+        assert_eq(prefix[0], 109);
+    }
+
+    fn main(pub xx: Field) {
+        check(b"minaa");
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::ArgumentTypeMismatch(TyKind::Array(_, 4), TyKind::Array(_, 5))
+    ));
+}
+
+#[test]
+fn test_const_non_constant_value() {
+    let code = r#"
+    const table = [1, 2, unknown];
+
+    fn main(pub xx: Field) {
+        assert_eq(table[0], xx);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(res.unwrap_err().kind, ErrorKind::InvalidConstType));
+}
+
+#[test]
+fn test_const_type_mismatch() {
+    let code = r#"
+    const flags: [Bool; 2] = [true, 1];
+
+    fn main(pub xx: Field) {
+        assert(flags[0]);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::MismatchType(TyKind::Bool, TyKind::BigInt)
+    ));
+}
+
+#[test]
+fn test_const_integer_overflow() {
+    let code = r#"
+    const mask: U8 = 256;
+
+    fn main(pub xx: U8) {
+        assert_eq(xx, mask);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::IntegerOverflow(8)
+    ));
+}
+
+#[test]
+fn test_let_type_annotation() {
+    let code = r#"
+    fn main(pub xx: Field) {
+        let is_one: Field = xx == 1;
+        assert_eq(is_one, 1);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::MismatchType(TyKind::Field, TyKind::Bool)
+    ));
+}
+
+#[test]
+fn test_let_type_annotation_overflow() {
+    let code = r#"
+    fn main(pub xx: U8) {
+        let limit: U8 = 256;
+        assert(xx < limit);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::IntegerOverflow(8)
+    ));
+}
+
+#[test]
+fn test_type_alias_duplicate() {
+    let code = r#"
+    struct Point {
+        xx: Field,
+        yy: Field,
+    }
+
+    type Point = [Field; 2];
+
+    fn main(pub xx: Field) {
+        assert_eq(xx, 1);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::DuplicateDefinition(name) if name == "Point"
+    ));
+}
+
+#[test]
+fn test_slice_out_of_bounds() {
+    let code = r#"
+    fn main(pub xx: [Field; 3]) {
+        let tail = xx[1..4];
+        assert_eq(tail[0], 1);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::InvalidSliceBounds(1, 4, 3)
+    ));
+}
+
+#[test]
+fn test_slice_non_constant_bounds() {
+    let code = r#"
+    fn main(pub xx: [Field; 3], start: Field) {
+        let tail = xx[start..3];
+        assert_eq(tail[0], 1);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::NonConstantArraySize
+    ));
+}
+
+#[test]
+fn test_concat_non_array() {
+    let code = r#"
+    fn main(pub xx: [Field; 3], yy: Field) {
+        let all = concat(xx, yy);
+        assert_eq(all[0], 1);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::InvalidConcatArgument(TyKind::BigInt)
+    ));
+}
+
+#[test]
+fn test_invalid_main_return_type() {
+    let code = r#"
+    fn main(pub xx: Field, yy: Field) -> (Field, Bool) {
+        return (xx + yy, xx == yy);
+    }
+    "#;
+
+    let mut tast = TypeChecker::<KimchiVesta>::new();
+    let res = typecheck_next_file_inner(
+        &mut tast,
+        None,
+        &mut Sources::new(),
+        "example.no".to_string(),
+        code.to_string(),
+        0,
+    );
+
+    assert!(matches!(
+        res.unwrap_err().kind,
+        ErrorKind::InvalidMainReturnType(TyKind::Tuple(_))
+    ));
+}
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::Span,
+    error::{ErrorKind, Result},
+    lexer::{Token, TokenKind, Tokens},
+    syntax::is_type,
+};
+
+use super::{
+    types::{Ident, ModulePath, Ty},
+    CustomType, Expr, ParserCtx,
+};
+
+//~
+//~ ## Enum
+//~
+//~ Backus–Naur Form (BNF) grammar:
+//~
+//~ enum_def ::= "enum" type "{" variant { "," variant } [ "," ] "}"
+//~ variant ::= type [ "(" type { "," type } ")" ]
+//~
+
+#[derive(Debug)]
+pub struct EnumDef {
+    pub module: ModulePath, // name resolution
+    pub name: CustomType,
+    pub variants: Vec<(Ident, Vec<Ty>)>,
+    pub span: Span,
+}
+
+impl EnumDef {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // enum Foo { A(Field), B }
+        //      ^^^
+        let name = CustomType::parse(ctx, tokens)?;
+        let mut span = name.span;
+
+        // enum Foo { A(Field), B }
+        //          ^
+        tokens.bump_expected(ctx, TokenKind::LeftCurlyBracket)?;
+
+        let mut variants = vec![];
+        loop {
+            // enum Foo { A(Field), B }
+            //                        ^
+            if let Some(Token {
+                kind: TokenKind::RightCurlyBracket,
+                span: end,
+            }) = tokens.peek()
+            {
+                tokens.bump(ctx);
+                span = span.merge_with(end);
+                break;
+            }
+
+            // enum Foo { A(Field), B }
+            //            ^
+            let variant = Ident::parse(ctx, tokens)?;
+            if !is_type(&variant.value) {
+                return Err(ctx.error(ErrorKind::InvalidVariantName(variant.value), variant.span));
+            }
+
+            // enum Foo { A(Field), B }
+            //             ^^^^^^^
+            let payload = if matches!(
+                tokens.peek(),
+                Some(Token {
+                    kind: TokenKind::LeftParen,
+                    ..
+                })
+            ) {
+                tokens.bump(ctx); // (
+                parse_list(ctx, tokens, Ty::parse)?
+            } else {
+                vec![]
+            };
+
+            variants.push((variant, payload));
+
+            // enum Foo { A(Field), B }
+            //                    ^   ^
+            match tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)? {
+                Token {
+                    kind: TokenKind::Comma,
+                    ..
+                } => (),
+                Token {
+                    kind: TokenKind::RightCurlyBracket,
+                    span: end,
+                } => {
+                    span = span.merge_with(end);
+                    break;
+                }
+                token => {
+                    return Err(ctx.error(ErrorKind::ExpectedToken(TokenKind::Comma), token.span))
+                }
+            }
+        }
+
+        if variants.is_empty() {
+            return Err(ctx.error(
+                ErrorKind::UnexpectedError("an enum must have at least one variant"),
+                span,
+            ));
+        }
+
+        Ok(EnumDef {
+            module: ModulePath::Local,
+            name,
+            variants,
+            span,
+        })
+    }
+}
+
+/// Parses a comma-separated list of items, up to (and including) the closing parenthesis.
+fn parse_list<T>(
+    ctx: &mut ParserCtx,
+    tokens: &mut Tokens,
+    parse_item: impl Fn(&mut ParserCtx, &mut Tokens) -> Result<T>,
+) -> Result<Vec<T>> {
+    let mut items = vec![parse_item(ctx, tokens)?];
+
+    loop {
+        match tokens.bump_err(ctx, ErrorKind::MissingToken)? {
+            Token {
+                kind: TokenKind::Comma,
+                ..
+            } => items.push(parse_item(ctx, tokens)?),
+            Token {
+                kind: TokenKind::RightParen,
+                ..
+            } => return Ok(items),
+            token => {
+                return Err(ctx.error(ErrorKind::ExpectedToken(TokenKind::RightParen), token.span))
+            }
+        }
+    }
+}
+
+/// An arm of a `match` expression: `pattern => expr`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub pattern: MatchPattern,
+    pub expr: Expr,
+    pub span: Span,
+}
+
+impl MatchArm {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // Foo::A(x) => expr
+        // ^^^^^^^^^
+        let pattern = MatchPattern::parse(ctx, tokens)?;
+
+        // Foo::A(x) => expr
+        //           ^^
+        tokens.bump_expected(ctx, TokenKind::FatArrow)?;
+
+        // Foo::A(x) => expr
+        //              ^^^^
+        let expr = Expr::parse(ctx, tokens)?;
+        let span = pattern.span().merge_with(expr.span);
+
+        Ok(Self {
+            pattern,
+            expr,
+            span,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MatchPattern {
+    /// `Enum::Variant(binding1, binding2, ...)`
+    Variant {
+        custom: CustomType,
+        variant: Ident,
+        bindings: Vec<Ident>,
+    },
+
+    /// `_`
+    Wildcard(Span),
+}
+
+impl MatchPattern {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // _ => expr
+        // ^
+        if let Some(Token {
+            kind: TokenKind::Underscore,
+            span,
+        }) = tokens.peek()
+        {
+            tokens.bump(ctx);
+            return Ok(Self::Wildcard(span));
+        }
+
+        // Foo::A(x, y)
+        // ^^^
+        let custom = CustomType::parse(ctx, tokens)?;
+
+        // Foo::A(x, y)
+        //    ^^
+        tokens.bump_expected(ctx, TokenKind::DoubleColon)?;
+
+        // Foo::A(x, y)
+        //      ^
+        let variant = Ident::parse(ctx, tokens)?;
+
+        // Foo::A(x, y)
+        //       ^^^^^^
+        let bindings = if matches!(
+            tokens.peek(),
+            Some(Token {
+                kind: TokenKind::LeftParen,
+                ..
+            })
+        ) {
+            tokens.bump(ctx); // (
+            parse_list(ctx, tokens, Ident::parse)?
+        } else {
+            vec![]
+        };
+
+        Ok(Self::Variant {
+            custom,
+            variant,
+            bindings,
+        })
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            MatchPattern::Variant {
+                custom,
+                variant,
+                bindings,
+            } => {
+                let end = bindings.last().map(|b| b.span).unwrap_or(variant.span);
+                custom.span.merge_with(end)
+            }
+            MatchPattern::Wildcard(span) => *span,
+        }
+    }
+}
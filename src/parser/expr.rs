@@ -9,8 +9,8 @@ use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    types::{parse_fn_call_args, parse_type_declaration, Ident, ModulePath},
-    CustomType, ParserCtx,
+    types::{parse_fn_call_args, parse_type_declaration, Ident, ModulePath, Ty},
+    CustomType, MatchArm, ParserCtx,
 };
 
 //~
@@ -21,16 +21,24 @@ use super::{
 //~ expr ::=
 //~     | expr { bin_op expr }
 //~     | "-" expr
+//~     | expr "as" type
 //~     | "(" expr ")"
 //~     | numeric
 //~     | ident
 //~     | fn_call
 //~     | array_access
+//~     | array_slice
+//~     | enum_variant
+//~     | match
 //~ bin_op ::= "+" | "-" | "/" | "*" | "=="
 //~ numeric ::= /[0-9]+/
 //~ ident ::= /[A-Za-z_][A-Za-z_0-9]*/
 //~ fn_call ::= ident "(" expr { "," expr } ")"
 //~ array_access ::= ident "[" expr "]"
+//~ array_slice ::= ident "[" expr ".." expr "]"
+//~ enum_variant ::= type "::" type [ "(" expr { "," expr } ")" ]
+//~ match ::= "match" expr "{" match_arm { "," match_arm } [ "," ] "}"
+//~ match_arm ::= ( type "::" type [ "(" ident { "," ident } ")" ] | "_" ) "=>" expr
 //~
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +100,12 @@ pub enum ExprKind {
     /// any numbers
     BigUInt(BigUint),
 
+    /// `"something"`, only used as the message of an assertion
+    StringLiteral(String),
+
+    /// `b"something"`, an array of fields holding one byte each
+    ByteString(Vec<u8>),
+
     /// a variable or a type. For example, `mod::A`, `x`, `y`, etc.
     // TODO: change to `identifier` or `path`?
     Variable { module: ModulePath, name: Ident },
@@ -100,9 +114,20 @@ pub enum ExprKind {
     /// `lhs[idx]`
     ArrayAccess { array: Box<Expr>, idx: Box<Expr> },
 
+    /// A slice of an array, with bounds known at compile time:
+    /// `array[start..end]`
+    ArraySlice {
+        array: Box<Expr>,
+        start: Box<Expr>,
+        end: Box<Expr>,
+    },
+
     /// `[ ... ]`
     ArrayDeclaration(Vec<Expr>),
 
+    /// `[item; size]`
+    RepeatedArrayDeclaration { item: Box<Expr>, size: Box<Expr> },
+
     /// `name { fields }`
     CustomTypeDeclaration {
         custom: CustomType,
@@ -118,6 +143,28 @@ pub enum ExprKind {
         then_: Box<Expr>,
         else_: Box<Expr>,
     },
+
+    /// `lhs as typ`
+    Cast { lhs: Box<Expr>, typ: Ty },
+
+    /// `(item1, item2, ...)`
+    TupleDeclaration(Vec<Expr>),
+
+    /// `lhs.idx`, for example `tuple.0`
+    TupleAccess { lhs: Box<Expr>, idx: usize },
+
+    /// `Enum::Variant(args)`
+    EnumVariant {
+        custom: CustomType,
+        variant: Ident,
+        args: Vec<Expr>,
+    },
+
+    /// `match expr { Enum::Variant(bindings) => expr, ... }`
+    Match {
+        expr: Box<Expr>,
+        arms: Vec<MatchArm>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -126,10 +173,20 @@ pub enum Op2 {
     Subtraction,
     Multiplication,
     Division,
+    Remainder,
     Equality,
     Inequality,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
     BoolAnd,
     BoolOr,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
 }
 
 impl Expr {
@@ -142,6 +199,12 @@ impl Expr {
             // numeric
             TokenKind::BigUInt(b) => Expr::new(ctx, ExprKind::BigUInt(b), span),
 
+            // "something"
+            TokenKind::Literal(s) => Expr::new(ctx, ExprKind::StringLiteral(s), span),
+
+            // b"something"
+            TokenKind::Bytes(s) => Expr::new(ctx, ExprKind::ByteString(s.into_bytes()), span),
+
             // identifier
             TokenKind::Identifier(value) => {
                 let maybe_module = Ident::new(value, span);
@@ -166,6 +229,41 @@ impl Expr {
                             _ => panic!("cannot qualify a non-identifier"),
                         };
 
+                        // modules are lowercase, so this is an enum variant
+                        // Enum::Variant(args)
+                        //               ^^^^
+                        if is_type(&maybe_module.value) {
+                            let custom = CustomType {
+                                module: ModulePath::Local,
+                                name: maybe_module.value,
+                                span: maybe_module.span,
+                            };
+
+                            let (args, end) = if matches!(
+                                tokens.peek(),
+                                Some(Token {
+                                    kind: TokenKind::LeftParen,
+                                    ..
+                                })
+                            ) {
+                                parse_fn_call_args(ctx, tokens)?
+                            } else {
+                                (vec![], name.span)
+                            };
+
+                            let span = custom.span.merge_with(end);
+                            let expr = Expr::new(
+                                ctx,
+                                ExprKind::EnumVariant {
+                                    custom,
+                                    variant: name,
+                                    args,
+                                },
+                                span,
+                            );
+                            return expr.parse_rhs(ctx, tokens);
+                        }
+
                         Expr::new(
                             ctx,
                             ExprKind::Variable {
@@ -198,6 +296,36 @@ impl Expr {
             // parenthesis
             TokenKind::LeftParen => {
                 let mut expr = Expr::parse(ctx, tokens)?;
+
+                // tuple
+                // (item1, item2, ...)
+                //       ^
+                if matches!(
+                    tokens.peek(),
+                    Some(Token {
+                        kind: TokenKind::Comma,
+                        ..
+                    })
+                ) {
+                    let mut items = vec![expr];
+                    while matches!(
+                        tokens.peek(),
+                        Some(Token {
+                            kind: TokenKind::Comma,
+                            ..
+                        })
+                    ) {
+                        tokens.bump(ctx); // ,
+                        items.push(Expr::parse(ctx, tokens)?);
+                    }
+
+                    let right_paren = tokens.bump_expected(ctx, TokenKind::RightParen)?;
+                    let span = span.merge_with(right_paren.span);
+
+                    return Expr::new(ctx, ExprKind::TupleDeclaration(items), span)
+                        .parse_rhs(ctx, tokens);
+                }
+
                 tokens.bump_expected(ctx, TokenKind::RightParen)?;
 
                 if let ExprKind::BinaryOp { protected, .. } = &mut expr.kind {
@@ -235,6 +363,7 @@ impl Expr {
                         | ExprKind::BigUInt { .. }
                         | ExprKind::FieldAccess { .. }
                         | ExprKind::ArrayAccess { .. }
+                        | ExprKind::TupleAccess { .. }
                 ) {
                     panic!("_then_ branch of ternary operator cannot be more than a variable")
                 }
@@ -262,6 +391,7 @@ impl Expr {
                         | ExprKind::BigUInt { .. }
                         | ExprKind::FieldAccess { .. }
                         | ExprKind::ArrayAccess { .. }
+                        | ExprKind::TupleAccess { .. }
                 ) {
                     panic!("_else_ branch of ternary operator cannot be more than a variable")
                 }
@@ -275,6 +405,52 @@ impl Expr {
                 Expr::new(ctx, ExprKind::IfElse { cond, then_, else_ }, span)
             }
 
+            // `match expr { Enum::Variant(x) => expr1, _ => expr2 }`
+            TokenKind::Keyword(Keyword::Match) => {
+                // match expr { ... }
+                //       ^^^^
+                let expr = Box::new(Expr::parse(ctx, tokens)?);
+
+                // match expr { ... }
+                //            ^
+                tokens.bump_expected(ctx, TokenKind::LeftCurlyBracket)?;
+
+                let mut arms = vec![];
+                let end = loop {
+                    // match expr { ... }
+                    //                  ^
+                    if let Some(Token {
+                        kind: TokenKind::RightCurlyBracket,
+                        span,
+                    }) = tokens.peek()
+                    {
+                        tokens.bump(ctx);
+                        break span;
+                    }
+
+                    // match expr { Enum::Variant(x) => expr1, _ => expr2 }
+                    //              ^^^^^^^^^^^^^^^^^^^^^^^^^
+                    arms.push(MatchArm::parse(ctx, tokens)?);
+
+                    // match expr { Enum::Variant(x) => expr1, _ => expr2 }
+                    //                                       ^            ^
+                    let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+                    match token.kind {
+                        TokenKind::Comma => (),
+                        TokenKind::RightCurlyBracket => break token.span,
+                        _ => {
+                            return Err(
+                                ctx.error(ErrorKind::ExpectedToken(TokenKind::Comma), token.span)
+                            )
+                        }
+                    }
+                };
+
+                let span = span.merge_with(end);
+
+                Expr::new(ctx, ExprKind::Match { expr, arms }, span)
+            }
+
             // negation (logical NOT)
             TokenKind::Exclamation => {
                 let expr = Expr::parse(ctx, tokens)?;
@@ -307,6 +483,33 @@ impl Expr {
                     // [1, 2];
                     //  ^
                     let item = Expr::parse(ctx, tokens)?;
+
+                    // [0; 16];
+                    //   ^^^^
+                    if items.is_empty()
+                        && matches!(
+                            tokens.peek(),
+                            Some(Token {
+                                kind: TokenKind::SemiColon,
+                                ..
+                            })
+                        )
+                    {
+                        tokens.bump(ctx); // ;
+                        let size = Expr::parse(ctx, tokens)?;
+                        let end = tokens.bump_expected(ctx, TokenKind::RightBracket)?;
+
+                        return Expr::new(
+                            ctx,
+                            ExprKind::RepeatedArrayDeclaration {
+                                item: Box::new(item),
+                                size: Box::new(size),
+                            },
+                            span.merge_with(end.span),
+                        )
+                        .parse_rhs(ctx, tokens);
+                    }
+
                     items.push(item);
 
                     // [1, 2];
@@ -364,7 +567,8 @@ impl Expr {
                     &self.kind,
                     ExprKind::Variable { .. }
                         | ExprKind::ArrayAccess { .. }
-                        | ExprKind::FieldAccess { .. },
+                        | ExprKind::FieldAccess { .. }
+                        | ExprKind::TupleAccess { .. },
                 ) {
                     return Err(ctx.error(
                         ErrorKind::InvalidAssignmentExpression,
@@ -391,11 +595,21 @@ impl Expr {
                     TokenKind::Plus
                     | TokenKind::Minus
                     | TokenKind::Star
+                    | TokenKind::Percent
                     | TokenKind::Slash
                     | TokenKind::DoubleEqual
                     | TokenKind::NotEqual
+                    | TokenKind::Less
+                    | TokenKind::LessEqual
+                    | TokenKind::Greater
+                    | TokenKind::GreaterEqual
                     | TokenKind::DoubleAmpersand
                     | TokenKind::DoublePipe
+                    | TokenKind::Ampersand
+                    | TokenKind::Pipe
+                    | TokenKind::Caret
+                    | TokenKind::DoubleLess
+                    | TokenKind::DoubleGreater
                     | TokenKind::Exclamation,
                 ..
             }) => {
@@ -405,11 +619,21 @@ impl Expr {
                     TokenKind::Plus => Op2::Addition,
                     TokenKind::Minus => Op2::Subtraction,
                     TokenKind::Star => Op2::Multiplication,
+                    TokenKind::Percent => Op2::Remainder,
                     TokenKind::Slash => Op2::Division,
                     TokenKind::DoubleEqual => Op2::Equality,
                     TokenKind::NotEqual => Op2::Inequality,
+                    TokenKind::Less => Op2::LessThan,
+                    TokenKind::LessEqual => Op2::LessThanOrEqual,
+                    TokenKind::Greater => Op2::GreaterThan,
+                    TokenKind::GreaterEqual => Op2::GreaterThanOrEqual,
                     TokenKind::DoubleAmpersand => Op2::BoolAnd,
                     TokenKind::DoublePipe => Op2::BoolOr,
+                    TokenKind::Ampersand => Op2::BitAnd,
+                    TokenKind::Pipe => Op2::BitOr,
+                    TokenKind::Caret => Op2::BitXor,
+                    TokenKind::DoubleLess => Op2::ShiftLeft,
+                    TokenKind::DoubleGreater => Op2::ShiftRight,
                     _ => unreachable!(),
                 };
 
@@ -446,8 +670,9 @@ impl Expr {
             }) => {
                 let ident = match &self.kind {
                     ExprKind::Variable { module, name } => {
-                        // probably an if condition
-                        if !is_type(&name.value) {
+                        // probably an if condition or a loop bound
+                        // (note that single letters are reserved for generic sizes, e.g. `N`)
+                        if !is_type(&name.value) || name.value.len() == 1 {
                             return Ok(self);
                         }
 
@@ -480,7 +705,9 @@ impl Expr {
                 // sanity check
                 if !matches!(
                     self.kind,
-                    ExprKind::Variable { .. } | ExprKind::FieldAccess { .. }
+                    ExprKind::Variable { .. }
+                        | ExprKind::FieldAccess { .. }
+                        | ExprKind::TupleAccess { .. }
                 ) {
                     panic!("an array access can only follow a variable");
                 }
@@ -489,6 +716,33 @@ impl Expr {
                 //       ^^^
                 let idx = Expr::parse(ctx, tokens)?;
 
+                // array[start..end]
+                //            ^^^^^
+                if matches!(
+                    tokens.peek(),
+                    Some(Token {
+                        kind: TokenKind::DoubleDot,
+                        ..
+                    })
+                ) {
+                    tokens.bump(ctx); // ..
+                    let end = Expr::parse(ctx, tokens)?;
+                    let last = tokens.bump_expected(ctx, TokenKind::RightBracket)?;
+
+                    let span = self.span.merge_with(last.span);
+
+                    return Expr::new(
+                        ctx,
+                        ExprKind::ArraySlice {
+                            array: Box::new(self),
+                            start: Box::new(idx),
+                            end: Box::new(end),
+                        },
+                        span,
+                    )
+                    .parse_rhs(ctx, tokens);
+                }
+
                 // array[idx]
                 //          ^
                 tokens.bump_expected(ctx, TokenKind::RightBracket)?;
@@ -541,12 +795,53 @@ impl Expr {
             }) => {
                 let period = tokens.bump(ctx).unwrap(); // .
 
+                // tuple access
+                // lhs.0
+                //     ^
+                if let Some(Token {
+                    kind: TokenKind::BigUInt(idx),
+                    span: idx_span,
+                }) = tokens.peek()
+                {
+                    tokens.bump(ctx); // 0
+
+                    // sanitize
+                    if !matches!(
+                        &self.kind,
+                        ExprKind::FieldAccess { .. }
+                            | ExprKind::Variable { .. }
+                            | ExprKind::ArrayAccess { .. }
+                            | ExprKind::TupleAccess { .. }
+                            | ExprKind::FnCall { .. }
+                            | ExprKind::MethodCall { .. }
+                    ) {
+                        let span = self.span.merge_with(period.span);
+                        return Err(ctx.error(ErrorKind::InvalidFieldAccessExpression, span));
+                    }
+
+                    let idx: usize = idx
+                        .try_into()
+                        .map_err(|_| ctx.error(ErrorKind::InvalidExpression, idx_span))?;
+                    let span = self.span.merge_with(idx_span);
+
+                    let tuple_access = Expr::new(
+                        ctx,
+                        ExprKind::TupleAccess {
+                            lhs: Box::new(self),
+                            idx,
+                        },
+                        span,
+                    );
+                    return tuple_access.parse_rhs(ctx, tokens);
+                }
+
                 // sanitize
                 if !matches!(
                     &self.kind,
                     ExprKind::FieldAccess { .. }
                         | ExprKind::Variable { .. }
                         | ExprKind::ArrayAccess { .. }
+                        | ExprKind::TupleAccess { .. }
                 ) {
                     let span = self.span.merge_with(period.span);
                     return Err(ctx.error(ErrorKind::InvalidFieldAccessExpression, span));
@@ -598,6 +893,30 @@ impl Expr {
                 }
             }
 
+            // type conversion
+            // lhs as typ
+            //     ^^
+            Some(Token {
+                kind: TokenKind::Keyword(Keyword::As),
+                ..
+            }) => {
+                tokens.bump(ctx); // as
+
+                // lhs as typ
+                //        ^^^
+                let typ = Ty::parse(ctx, tokens)?;
+                let span = self.span.merge_with(typ.span);
+
+                Expr::new(
+                    ctx,
+                    ExprKind::Cast {
+                        lhs: Box::new(self),
+                        typ,
+                    },
+                    span,
+                )
+            }
+
             // it looks like the lhs is a valid expression in itself
             _ => return Ok(self),
         };
@@ -1,18 +1,21 @@
 use crate::{
-    backends::Backend,
     constants::Span,
     error::{Error, ErrorKind, Result},
     lexer::{Keyword, Token, TokenKind, Tokens},
 };
 
-pub use self::types::{ConstDef, FunctionDef, Root, RootKind, UsePath};
+pub use self::types::{ConstDef, FunctionDef, Root, RootKind, TypeAliasDef, UsePath};
 
+pub mod enums;
 pub mod expr;
 pub mod structs;
+pub mod traits;
 pub mod types;
 
+pub use enums::{EnumDef, MatchArm, MatchPattern};
 pub use expr::{Expr, ExprKind, Op2};
 pub use structs::{CustomType, StructDef};
+pub use traits::{ImplDef, TraitDef};
 
 //~
 //~ # Grammar
@@ -96,14 +99,10 @@ impl ParserCtx {
 //
 
 #[derive(Debug, Default)]
-pub struct AST<B: Backend>(pub Vec<Root<B::Field>>);
-
-impl<B: Backend> AST<B> {
-    pub fn parse(
-        filename_id: usize,
-        mut tokens: Tokens,
-        node_id: usize,
-    ) -> Result<(AST<B>, usize)> {
+pub struct AST(pub Vec<Root>);
+
+impl AST {
+    pub fn parse(filename_id: usize, mut tokens: Tokens, node_id: usize) -> Result<(AST, usize)> {
         let mut ast = vec![];
         let ctx = &mut ParserCtx::new(filename_id, node_id);
 
@@ -147,6 +146,16 @@ impl<B: Backend> AST<B> {
                     });
                 }
 
+                // `type Path = [Field; 16];`
+                TokenKind::Keyword(Keyword::Type) => {
+                    let alias = TypeAliasDef::parse(ctx, &mut tokens)?;
+
+                    ast.push(Root {
+                        kind: RootKind::TypeAliasDef(alias),
+                        span: token.span,
+                    });
+                }
+
                 // `fn main() { }`
                 TokenKind::Keyword(Keyword::Fn) => {
                     function_observed = true;
@@ -158,6 +167,20 @@ impl<B: Backend> AST<B> {
                     });
                 }
 
+                // `hint fn sqrt(x: Field) -> Field { }`
+                TokenKind::Keyword(Keyword::Hint) => {
+                    function_observed = true;
+
+                    tokens.bump_expected(ctx, TokenKind::Keyword(Keyword::Fn))?;
+
+                    let mut func = FunctionDef::parse(ctx, &mut tokens)?;
+                    func.sig.hint = true;
+                    ast.push(Root {
+                        kind: RootKind::FunctionDef(func),
+                        span: token.span,
+                    });
+                }
+
                 // `struct Foo { a: Field, b: Field }`
                 TokenKind::Keyword(Keyword::Struct) => {
                     let s = StructDef::parse(ctx, &mut tokens)?;
@@ -167,6 +190,35 @@ impl<B: Backend> AST<B> {
                     });
                 }
 
+                // `enum Foo { A(Field), B }`
+                TokenKind::Keyword(Keyword::Enum) => {
+                    let e = EnumDef::parse(ctx, &mut tokens)?;
+                    ast.push(Root {
+                        kind: RootKind::EnumDef(e),
+                        span: token.span,
+                    });
+                }
+
+                // `trait Hashable { fn hash(self) -> Field; }`
+                TokenKind::Keyword(Keyword::Trait) => {
+                    let t = TraitDef::parse(ctx, &mut tokens)?;
+                    ast.push(Root {
+                        kind: RootKind::TraitDef(t),
+                        span: token.span,
+                    });
+                }
+
+                // `impl Hashable for Foo { fn hash(self) -> Field { ... } }`
+                TokenKind::Keyword(Keyword::Impl) => {
+                    function_observed = true;
+
+                    let i = ImplDef::parse(ctx, &mut tokens)?;
+                    ast.push(Root {
+                        kind: RootKind::ImplDef(i),
+                        span: token.span,
+                    });
+                }
+
                 // `// some comment`
                 TokenKind::Comment(comment) => {
                     ast.push(Root {
@@ -0,0 +1,239 @@
+use crate::{
+    constants::Span,
+    error::{ErrorKind, Result},
+    lexer::{Keyword, TokenKind, Tokens},
+    syntax::is_type,
+};
+
+use super::{
+    types::{FnSig, FuncOrMethod, Ident, ModulePath},
+    CustomType, FunctionDef, ParserCtx,
+};
+
+//~
+//~ ## Traits
+//~
+//~ Backus–Naur Form (BNF) grammar:
+//~
+//~ trait_def ::= "trait" type "{" { "fn" method_sig ";" } "}"
+//~ impl_def ::= "impl" trait_path "for" type "{" { "fn" method_sig fn_body } "}"
+//~ trait_path ::= [ ident "::" ] type
+//~ method_sig ::= ident "(" "self" { "," param } ")" [ return_val ]
+//~
+
+#[derive(Debug)]
+pub struct TraitDef {
+    pub module: ModulePath, // name resolution
+    pub name: CustomType,
+    pub methods: Vec<FnSig>,
+    pub span: Span,
+}
+
+impl TraitDef {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // trait Hashable { fn hash(self) -> Field; }
+        //       ^^^^^^^^
+        let name = CustomType::parse(ctx, tokens)?;
+        let mut span = name.span;
+
+        // trait Hashable { fn hash(self) -> Field; }
+        //                ^
+        tokens.bump_expected(ctx, TokenKind::LeftCurlyBracket)?;
+
+        let mut methods = vec![];
+        loop {
+            // trait Hashable { fn hash(self) -> Field; }
+            //                  ^^                      ^
+            let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+            match token.kind {
+                TokenKind::RightCurlyBracket => {
+                    span = span.merge_with(token.span);
+                    break;
+                }
+                TokenKind::Keyword(Keyword::Fn) => (),
+                _ => {
+                    return Err(ctx.error(
+                        ErrorKind::ExpectedToken(TokenKind::Keyword(Keyword::Fn)),
+                        token.span,
+                    ))
+                }
+            }
+
+            // trait Hashable { fn hash(self) -> Field; }
+            //                     ^^^^^^^^^^^^^^^^^^^
+            let self_type = CustomType {
+                module: ModulePath::Local,
+                name: name.name.clone(),
+                span: name.span,
+            };
+            methods.push(parse_method_sig(ctx, tokens, &self_type)?);
+
+            // trait Hashable { fn hash(self) -> Field; }
+            //                                        ^
+            tokens.bump_expected(ctx, TokenKind::SemiColon)?;
+        }
+
+        Ok(Self {
+            module: ModulePath::Local,
+            name,
+            methods,
+            span,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplDef {
+    /// The trait being implemented.
+    pub trait_name: CustomType,
+    /// The struct implementing the trait.
+    pub custom: CustomType,
+    pub methods: Vec<FunctionDef>,
+    pub span: Span,
+}
+
+impl ImplDef {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // impl Hashable for Point { ... }
+        //      ^^^^^^^^
+        let trait_name = parse_trait_path(ctx, tokens)?;
+        let mut span = trait_name.span;
+
+        // impl Hashable for Point { ... }
+        //               ^^^
+        tokens.bump_expected(ctx, TokenKind::Keyword(Keyword::For))?;
+
+        // impl Hashable for Point { ... }
+        //                   ^^^^^
+        let custom = CustomType::parse(ctx, tokens)?;
+
+        // impl Hashable for Point { ... }
+        //                         ^
+        tokens.bump_expected(ctx, TokenKind::LeftCurlyBracket)?;
+
+        let mut methods = vec![];
+        loop {
+            // impl Hashable for Point { fn hash(self) -> Field { ... } }
+            //                           ^^                             ^
+            let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+            match token.kind {
+                TokenKind::RightCurlyBracket => {
+                    span = span.merge_with(token.span);
+                    break;
+                }
+                TokenKind::Keyword(Keyword::Fn) => (),
+                _ => {
+                    return Err(ctx.error(
+                        ErrorKind::ExpectedToken(TokenKind::Keyword(Keyword::Fn)),
+                        token.span,
+                    ))
+                }
+            }
+
+            // impl Hashable for Point { fn hash(self) -> Field { ... } }
+            //                              ^^^^^^^^^^^^^^^^^^^
+            let sig = parse_method_sig(ctx, tokens, &custom)?;
+
+            // impl Hashable for Point { fn hash(self) -> Field { ... } }
+            //                                                  ^^^^^^^
+            let body = FunctionDef::parse_fn_body(ctx, tokens)?;
+            let method_span = match body.last() {
+                Some(stmt) => token.span.merge_with(stmt.span),
+                None => {
+                    return Err(ctx.error(
+                        ErrorKind::InvalidFunctionSignature("expected function body"),
+                        ctx.last_span(),
+                    ))
+                }
+            };
+
+            methods.push(FunctionDef {
+                sig,
+                body,
+                span: method_span,
+            });
+        }
+
+        Ok(Self {
+            trait_name,
+            custom,
+            methods,
+            span,
+        })
+    }
+}
+
+/// Parses the name of a trait, which might be imported from another module
+/// (e.g. `Hashable` or `hashes::Hashable`).
+pub fn parse_trait_path(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<CustomType> {
+    // module::Trait   or   Trait
+    // ^^^^^^               ^^^^^
+    let first = Ident::parse(ctx, tokens)?;
+    if is_type(&first.value) {
+        return Ok(CustomType {
+            module: ModulePath::Local,
+            name: first.value,
+            span: first.span,
+        });
+    }
+
+    // module::Trait
+    //       ^^
+    tokens.bump_expected(ctx, TokenKind::DoubleColon)?;
+
+    // module::Trait
+    //         ^^^^^
+    let name = Ident::parse(ctx, tokens)?;
+    if !is_type(&name.value) {
+        return Err(ctx.error(ErrorKind::InvalidType, name.span));
+    }
+
+    Ok(CustomType {
+        module: ModulePath::Alias(first.clone()),
+        name: name.value,
+        span: first.span.merge_with(name.span),
+    })
+}
+
+/// Parses the signature of a method declared within a trait or an `impl` block,
+/// where the type of `self` is implied.
+fn parse_method_sig(
+    ctx: &mut ParserCtx,
+    tokens: &mut Tokens,
+    self_type: &CustomType,
+) -> Result<FnSig> {
+    // fn hash(self) -> Field
+    //    ^^^^
+    let name = tokens.bump_ident(
+        ctx,
+        ErrorKind::InvalidFunctionSignature("expected function name"),
+    )?;
+
+    // fn hash(self) -> Field
+    //        ^^^^^^
+    let kind = FuncOrMethod::Method(self_type.clone());
+    let arguments = FunctionDef::parse_args(ctx, tokens, &kind)?;
+
+    // the methods of a trait are called on values implementing it
+    if !matches!(arguments.first(), Some(arg) if arg.name.value == "self") {
+        return Err(ctx.error(
+            ErrorKind::InvalidFunctionSignature(
+                "a trait method must take `self` as first argument",
+            ),
+            name.span,
+        ));
+    }
+
+    // fn hash(self) -> Field
+    //               ^^^^^^^^
+    let return_type = FunctionDef::parse_fn_return_type(ctx, tokens)?;
+
+    Ok(FnSig {
+        kind,
+        name,
+        type_params: vec![],
+        arguments,
+        return_type,
+        hint: false,
+    })
+}
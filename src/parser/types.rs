@@ -1,11 +1,11 @@
 use educe::Educe;
 use std::{
+    collections::HashMap,
     fmt::Display,
     hash::{Hash, Hasher},
-    str::FromStr,
 };
 
-use ark_ff::{Field, Zero};
+use ark_ff::Zero;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -17,7 +17,10 @@ use crate::{
     syntax::is_type,
 };
 
-use super::{CustomType, Expr, ExprKind, ParserCtx, StructDef};
+use super::{
+    traits::parse_trait_path, CustomType, EnumDef, Expr, ExprKind, ImplDef, ParserCtx, StructDef,
+    TraitDef,
+};
 
 pub fn parse_type_declaration(
     ctx: &mut ParserCtx,
@@ -144,10 +147,13 @@ pub fn parse_fn_call_args(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<(V
 //~ Backus–Naur Form (BNF) grammar:
 //~
 //~ type ::=
-//~     | /[A-Z] (A-Za-z0-9)*/
+//~     | /[A-Z] (A-Za-z0-9)*/ [ "<" size { "," size } ">" ]
 //~     | "[" type ";" numeric "]"
+//~     | "[" type ";" generic "]"
 //~
+//~ size ::= numeric | generic
 //~ numeric ::= /[0-9]+/
+//~ generic ::= /[A-Z] (A-Za-z0-9)*/
 //~
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -177,8 +183,14 @@ pub enum TyKind {
     // TODO: Field { constant: bool },
     Field,
 
-    /// Custom / user-defined types
-    Custom { module: ModulePath, name: String },
+    /// Custom / user-defined types.
+    /// The sizes are the ones given to the generic array sizes of a struct, if any
+    /// (e.g. `81` in `Sudoku<81>`, for `struct Sudoku { inner: [Field; N] }`).
+    Custom {
+        module: ModulePath,
+        name: String,
+        sizes: Vec<ArraySize>,
+    },
 
     /// This could be the same as Field, but we use this to also track the fact that it's a constant.
     // TODO: get rid of this type tho no?
@@ -187,17 +199,52 @@ pub enum TyKind {
     /// An array of a fixed size.
     Array(Box<TyKind>, u32),
 
+    /// An array whose size is a generic parameter (e.g. `[Field; N]`).
+    /// The size is inferred at each call site of the function.
+    GenericSizedArray(Box<TyKind>, String),
+
     /// A boolean (`true` or `false`).
     Bool,
-    // Tuple(Vec<TyKind>),
-    // Bool,
-    // U8,
-    // U16,
-    // U32,
-    // U64,
+
+    /// An unsigned 8-bit integer.
+    /// Integers are range-checked when they enter the circuit,
+    /// and arithmetic on them fails if it overflows.
+    U8,
+
+    /// An unsigned 16-bit integer.
+    U16,
+
+    /// An unsigned 32-bit integer.
+    U32,
+
+    /// An unsigned 64-bit integer.
+    U64,
+
+    /// A tuple of types, for example `(Field, Bool)`.
+    Tuple(Vec<TyKind>),
+
+    /// A generic type (e.g. `T` in `fn digest<T: Hashable>(value: T)`),
+    /// standing for any struct implementing the trait it is bound to.
+    /// The function is monomorphized for each type it is called with.
+    Generic {
+        name: String,
+        trait_module: ModulePath,
+        trait_name: String,
+    },
 }
 
 impl TyKind {
+    /// Returns the bit width of an unsigned integer type, or `None` if it's not one.
+    pub fn int_bitlen(&self) -> Option<usize> {
+        match self {
+            TyKind::U8 => Some(8),
+            TyKind::U16 => Some(16),
+            TyKind::U32 => Some(32),
+            TyKind::U64 => Some(64),
+            _ => None,
+        }
+    }
+
     pub fn match_expected(&self, expected: &TyKind) -> bool {
         match (self, expected) {
             (TyKind::BigInt, TyKind::Field) => true,
@@ -205,12 +252,24 @@ impl TyKind {
                 lhs_size == rhs_size && lhs.match_expected(rhs)
             }
             (
-                TyKind::Custom { module, name },
+                TyKind::GenericSizedArray(lhs, lhs_size),
+                TyKind::GenericSizedArray(rhs, rhs_size),
+            ) => lhs_size == rhs_size && lhs.match_expected(rhs),
+            (TyKind::Tuple(lhs), TyKind::Tuple(rhs)) => {
+                lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(l, r)| l.match_expected(r))
+            }
+            (
+                TyKind::Custom {
+                    module,
+                    name,
+                    sizes,
+                },
                 TyKind::Custom {
                     module: expected_module,
                     name: expected_name,
+                    sizes: expected_sizes,
                 },
-            ) => module == expected_module && name == expected_name,
+            ) => module == expected_module && name == expected_name && sizes == expected_sizes,
             (x, y) if x == y => true,
             _ => false,
         }
@@ -223,41 +282,314 @@ impl TyKind {
                 lhs_size == rhs_size && lhs.match_expected(rhs)
             }
             (
-                TyKind::Custom { module, name },
+                TyKind::GenericSizedArray(lhs, lhs_size),
+                TyKind::GenericSizedArray(rhs, rhs_size),
+            ) => lhs_size == rhs_size && lhs.match_expected(rhs),
+            (TyKind::Tuple(lhs), TyKind::Tuple(rhs)) => {
+                lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(l, r)| l.same_as(r))
+            }
+            (
+                TyKind::Custom {
+                    module,
+                    name,
+                    sizes,
+                },
                 TyKind::Custom {
                     module: expected_module,
                     name: expected_name,
+                    sizes: expected_sizes,
                 },
-            ) => module == expected_module && name == expected_name,
+            ) => module == expected_module && name == expected_name && sizes == expected_sizes,
             (x, y) if x == y => true,
             _ => false,
         }
     }
+
+    /// Returns the names of the generic array sizes appearing in this type
+    /// (e.g. `N` for `[Field; N]`).
+    pub fn generic_sizes(&self) -> Vec<String> {
+        match self {
+            TyKind::GenericSizedArray(typ, name) => {
+                let mut names = typ.generic_sizes();
+                names.push(name.clone());
+                names
+            }
+            TyKind::Array(typ, _) => typ.generic_sizes(),
+            TyKind::Tuple(typs) => typs.iter().flat_map(TyKind::generic_sizes).collect(),
+            TyKind::Custom { sizes, .. } => sizes
+                .iter()
+                .filter_map(|size| match size {
+                    ArraySize::Generic(name) => Some(name.clone()),
+                    ArraySize::Fixed(_) => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Infers the generic array sizes appearing in this (expected) type,
+    /// by matching it against an observed type.
+    /// On conflict (a generic bound to two different sizes), returns the name of the generic.
+    pub fn bind_generic_sizes(
+        &self,
+        observed: &TyKind,
+        bindings: &mut HashMap<String, ArraySize>,
+    ) -> std::result::Result<(), String> {
+        match (self, observed) {
+            (TyKind::GenericSizedArray(typ, name), TyKind::Array(obs_typ, size)) => {
+                typ.bind_generic_sizes(obs_typ, bindings)?;
+                bind_generic_size(bindings, name, ArraySize::Fixed(*size))
+            }
+            (
+                TyKind::GenericSizedArray(typ, name),
+                TyKind::GenericSizedArray(obs_typ, obs_name),
+            ) => {
+                typ.bind_generic_sizes(obs_typ, bindings)?;
+                bind_generic_size(bindings, name, ArraySize::Generic(obs_name.clone()))
+            }
+            (TyKind::Array(typ, _), TyKind::Array(obs_typ, _)) => {
+                typ.bind_generic_sizes(obs_typ, bindings)
+            }
+            (TyKind::Tuple(typs), TyKind::Tuple(obs_typs)) => {
+                for (typ, obs_typ) in typs.iter().zip(obs_typs) {
+                    typ.bind_generic_sizes(obs_typ, bindings)?;
+                }
+                Ok(())
+            }
+            (
+                TyKind::Custom { sizes, .. },
+                TyKind::Custom {
+                    sizes: obs_sizes, ..
+                },
+            ) => {
+                for (size, obs_size) in sizes.iter().zip(obs_sizes) {
+                    if let ArraySize::Generic(name) = size {
+                        bind_generic_size(bindings, name, obs_size.clone())?;
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Replaces the generic array sizes appearing in this type with the sizes they are bound to.
+    /// Generics that are not bound are left as is.
+    pub fn resolve_generic_sizes(&self, bindings: &HashMap<String, ArraySize>) -> TyKind {
+        match self {
+            TyKind::GenericSizedArray(typ, name) => {
+                let typ = Box::new(typ.resolve_generic_sizes(bindings));
+                match bindings.get(name) {
+                    Some(ArraySize::Fixed(size)) => TyKind::Array(typ, *size),
+                    Some(ArraySize::Generic(other)) => {
+                        TyKind::GenericSizedArray(typ, other.clone())
+                    }
+                    None => TyKind::GenericSizedArray(typ, name.clone()),
+                }
+            }
+            TyKind::Array(typ, size) => {
+                TyKind::Array(Box::new(typ.resolve_generic_sizes(bindings)), *size)
+            }
+            TyKind::Tuple(typs) => TyKind::Tuple(
+                typs.iter()
+                    .map(|typ| typ.resolve_generic_sizes(bindings))
+                    .collect(),
+            ),
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => TyKind::Custom {
+                module: module.clone(),
+                name: name.clone(),
+                sizes: sizes
+                    .iter()
+                    .map(|size| match size {
+                        ArraySize::Generic(generic) => {
+                            bindings.get(generic).cloned().unwrap_or(size.clone())
+                        }
+                        ArraySize::Fixed(_) => size.clone(),
+                    })
+                    .collect(),
+            },
+            typ => typ.clone(),
+        }
+    }
+
+    /// Returns the names of the generic types appearing in this type
+    /// (e.g. `T` for `[T; 3]`).
+    pub fn generic_types(&self) -> Vec<String> {
+        match self {
+            TyKind::Generic { name, .. } => vec![name.clone()],
+            TyKind::Array(typ, _) | TyKind::GenericSizedArray(typ, _) => typ.generic_types(),
+            TyKind::Tuple(typs) => typs.iter().flat_map(TyKind::generic_types).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Infers the generic types appearing in this (expected) type,
+    /// by matching it against an observed type.
+    /// On conflict (a generic bound to two different types), returns the name of the generic.
+    pub fn bind_generic_types(
+        &self,
+        observed: &TyKind,
+        bindings: &mut HashMap<String, TyKind>,
+    ) -> std::result::Result<(), String> {
+        match (self, observed) {
+            (TyKind::Generic { name, .. }, observed) => match bindings.get(name) {
+                Some(bound) if bound != observed => Err(name.clone()),
+                Some(_) => Ok(()),
+                None => {
+                    bindings.insert(name.clone(), observed.clone());
+                    Ok(())
+                }
+            },
+            (
+                TyKind::Array(typ, _) | TyKind::GenericSizedArray(typ, _),
+                TyKind::Array(obs_typ, _) | TyKind::GenericSizedArray(obs_typ, _),
+            ) => typ.bind_generic_types(obs_typ, bindings),
+            (TyKind::Tuple(typs), TyKind::Tuple(obs_typs)) => {
+                for (typ, obs_typ) in typs.iter().zip(obs_typs) {
+                    typ.bind_generic_types(obs_typ, bindings)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Replaces the generic types appearing in this type with the types they are bound to.
+    /// Generics that are not bound are left as is.
+    pub fn resolve_generic_types(&self, bindings: &HashMap<String, TyKind>) -> TyKind {
+        match self {
+            TyKind::Generic { name, .. } => bindings.get(name).unwrap_or(self).clone(),
+            TyKind::Array(typ, size) => {
+                TyKind::Array(Box::new(typ.resolve_generic_types(bindings)), *size)
+            }
+            TyKind::GenericSizedArray(typ, size) => TyKind::GenericSizedArray(
+                Box::new(typ.resolve_generic_types(bindings)),
+                size.clone(),
+            ),
+            TyKind::Tuple(typs) => TyKind::Tuple(
+                typs.iter()
+                    .map(|typ| typ.resolve_generic_types(bindings))
+                    .collect(),
+            ),
+            typ => typ.clone(),
+        }
+    }
+
+    /// Turns the types named after one of the given type parameters into generic types.
+    fn apply_type_params(&mut self, type_params: &[TypeParam]) {
+        match self {
+            TyKind::Custom {
+                module: ModulePath::Local,
+                name,
+                sizes,
+            } if sizes.is_empty() => {
+                if let Some(param) = type_params.iter().find(|param| param.name.value == *name) {
+                    *self = TyKind::Generic {
+                        name: param.name.value.clone(),
+                        trait_module: param.bound.module.clone(),
+                        trait_name: param.bound.name.clone(),
+                    };
+                }
+            }
+            TyKind::Array(typ, _) | TyKind::GenericSizedArray(typ, _) => {
+                typ.apply_type_params(type_params)
+            }
+            TyKind::Tuple(typs) => {
+                for typ in typs {
+                    typ.apply_type_params(type_params);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// The size a generic array size (e.g. `N` in `[Field; N]`) is bound to at a call site,
+/// or the one given to a generic struct (e.g. `81` in `Sudoku<81>`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ArraySize {
+    /// A known size.
+    Fixed(u32),
+
+    /// The generic size of the caller (when a generic function calls another one).
+    Generic(String),
+}
+
+fn bind_generic_size(
+    bindings: &mut HashMap<String, ArraySize>,
+    name: &str,
+    size: ArraySize,
+) -> std::result::Result<(), String> {
+    match bindings.get(name) {
+        Some(bound) if *bound != size => Err(name.to_string()),
+        Some(_) => Ok(()),
+        None => {
+            bindings.insert(name.to_string(), size);
+            Ok(())
+        }
+    }
+}
+
+impl Display for ArraySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArraySize::Fixed(size) => write!(f, "{size}"),
+            ArraySize::Generic(name) => write!(f, "{name}"),
+        }
+    }
 }
 
 impl Display for TyKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            TyKind::Custom { module, name } => match module {
-                ModulePath::Absolute(user_repo) => write!(
-                    f,
-                    "a `{module}::{submodule}::{name}` struct",
-                    name = name,
-                    module = user_repo.user,
-                    submodule = user_repo.repo
-                ),
-                ModulePath::Alias(module) => write!(
-                    f,
-                    "a `{module}::{name}` struct",
-                    name = name,
-                    module = module.value
-                ),
-                ModulePath::Local => write!(f, "a `{}` struct", name),
-            },
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => {
+                let name = if sizes.is_empty() {
+                    name.clone()
+                } else {
+                    let sizes: Vec<_> = sizes.iter().map(|size| size.to_string()).collect();
+                    format!("{name}<{}>", sizes.join(", "))
+                };
+                match module {
+                    ModulePath::Absolute(user_repo) => write!(
+                        f,
+                        "a `{module}::{submodule}::{name}` struct",
+                        name = name,
+                        module = user_repo.user,
+                        submodule = user_repo.repo
+                    ),
+                    ModulePath::Alias(module) => write!(
+                        f,
+                        "a `{module}::{name}` struct",
+                        name = name,
+                        module = module.value
+                    ),
+                    ModulePath::Local => write!(f, "a `{}` struct", name),
+                }
+            }
             TyKind::Field => write!(f, "Field"),
             TyKind::BigInt => write!(f, "BigInt"),
             TyKind::Array(ty, size) => write!(f, "[{}; {}]", ty, size),
+            TyKind::GenericSizedArray(ty, size) => write!(f, "[{}; {}]", ty, size),
             TyKind::Bool => write!(f, "Bool"),
+            TyKind::U8 => write!(f, "U8"),
+            TyKind::U16 => write!(f, "U16"),
+            TyKind::U32 => write!(f, "U32"),
+            TyKind::U64 => write!(f, "U64"),
+            TyKind::Tuple(types) => {
+                let types: Vec<_> = types.iter().map(|typ| typ.to_string()).collect();
+                write!(f, "({})", types.join(", "))
+            }
+            TyKind::Generic {
+                name, trait_name, ..
+            } => write!(f, "a generic `{name}: {trait_name}`"),
         }
     }
 }
@@ -265,18 +597,74 @@ impl Display for TyKind {
 impl Ty {
     pub fn reserved_types(module: ModulePath, name: Ident) -> TyKind {
         match name.value.as_ref() {
-            "Field" | "Bool" if !matches!(module, ModulePath::Local) => {
+            "Field" | "Bool" | "U8" | "U16" | "U32" | "U64"
+                if !matches!(module, ModulePath::Local) =>
+            {
                 panic!("reserved types cannot be in a module (TODO: better error)")
             }
             "Field" => TyKind::Field,
             "Bool" => TyKind::Bool,
+            "U8" => TyKind::U8,
+            "U16" => TyKind::U16,
+            "U32" => TyKind::U32,
+            "U64" => TyKind::U64,
             _ => TyKind::Custom {
                 module,
                 name: name.value,
+                sizes: vec![],
             },
         }
     }
 
+    /// Parses the sizes given to the generic array sizes of a struct (e.g. `<81>` in `Sudoku<81>`),
+    /// if any.
+    fn parse_struct_sizes(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Vec<ArraySize>> {
+        // Type<size, ...>
+        //     ^
+        if !matches!(
+            tokens.peek(),
+            Some(Token {
+                kind: TokenKind::Less,
+                ..
+            })
+        ) {
+            return Ok(vec![]);
+        }
+        tokens.bump(ctx);
+
+        let mut sizes = vec![];
+        loop {
+            // Type<size, ...>
+            //      ^^^^
+            let siz = tokens.bump_err(ctx, ErrorKind::InvalidToken)?;
+            let size = match siz.kind {
+                TokenKind::BigUInt(b) => ArraySize::Fixed(
+                    b.try_into()
+                        .map_err(|_e| ctx.error(ErrorKind::InvalidArraySize, siz.span))?,
+                ),
+                TokenKind::Identifier(name) if is_type(&name) => ArraySize::Generic(name),
+                _ => {
+                    return Err(ctx.error(
+                        ErrorKind::ExpectedToken(TokenKind::BigUInt(num_bigint::BigUint::zero())),
+                        siz.span,
+                    ));
+                }
+            };
+            sizes.push(size);
+
+            // Type<size, ...>
+            //          ^    ^
+            let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+            match token.kind {
+                TokenKind::Comma => (),
+                TokenKind::Greater => return Ok(sizes),
+                _ => {
+                    return Err(ctx.error(ErrorKind::ExpectedToken(TokenKind::Greater), token.span))
+                }
+            }
+        }
+    }
+
     pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
         let token = tokens.bump_err(ctx, ErrorKind::MissingType)?;
 
@@ -310,7 +698,13 @@ impl Ty {
                     (ModulePath::Alias(maybe_module), name, span)
                 };
 
-                let ty_kind = Self::reserved_types(module, name);
+                let mut ty_kind = Self::reserved_types(module, name);
+
+                // Type<size, ...>
+                //     ^^^^^^^^^^^
+                if let TyKind::Custom { sizes, .. } = &mut ty_kind {
+                    *sizes = Self::parse_struct_sizes(ctx, tokens)?;
+                }
 
                 Ok(Self {
                     kind: ty_kind,
@@ -335,10 +729,17 @@ impl Ty {
                 // [type; size]
                 //         ^
                 let siz = tokens.bump_err(ctx, ErrorKind::InvalidToken)?;
-                let siz: u32 = match siz.kind {
-                    TokenKind::BigUInt(b) => b
-                        .try_into()
-                        .map_err(|_e| ctx.error(ErrorKind::InvalidArraySize, siz.span))?,
+                let kind = match siz.kind {
+                    TokenKind::BigUInt(b) => {
+                        let siz: u32 = b
+                            .try_into()
+                            .map_err(|_e| ctx.error(ErrorKind::InvalidArraySize, siz.span))?;
+                        TyKind::Array(Box::new(ty.kind), siz)
+                    }
+                    // [type; N]
+                    TokenKind::Identifier(name) if is_type(&name) => {
+                        TyKind::GenericSizedArray(Box::new(ty.kind), name)
+                    }
                     _ => {
                         return Err(ctx.error(
                             ErrorKind::ExpectedToken(TokenKind::BigUInt(
@@ -355,10 +756,48 @@ impl Ty {
 
                 let span = span.merge_with(right_paren.span);
 
-                Ok(Ty {
-                    kind: TyKind::Array(Box::new(ty.kind), siz),
-                    span,
-                })
+                Ok(Ty { kind, span })
+            }
+
+            // tuple
+            // (type1, type2, ...)
+            // ^
+            TokenKind::LeftParen => {
+                let span = token.span;
+                let mut types = vec![];
+
+                loop {
+                    // (type1, type2, ...)
+                    //  ^^^^^
+                    let ty = Ty::parse(ctx, tokens)?;
+                    types.push(ty.kind);
+
+                    // (type1, type2, ...)
+                    //       ^           ^
+                    let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+                    match token.kind {
+                        TokenKind::Comma => (),
+                        TokenKind::RightParen => {
+                            // a tuple needs at least two elements
+                            if types.len() < 2 {
+                                return Err(
+                                    ctx.error(ErrorKind::InvalidType, span.merge_with(token.span))
+                                );
+                            }
+
+                            return Ok(Ty {
+                                kind: TyKind::Tuple(types),
+                                span: span.merge_with(token.span),
+                            });
+                        }
+                        _ => {
+                            return Err(ctx.error(
+                                ErrorKind::ExpectedToken(TokenKind::RightParen),
+                                token.span,
+                            ))
+                        }
+                    }
+                }
             }
 
             // unrecognized
@@ -372,7 +811,10 @@ impl Ty {
 //~
 //~ Backus–Naur Form (BNF) grammar:
 //~
-//~ fn_sig ::= ident "(" param { "," param } ")" [ return_val ]
+//~ fn_def ::= [ "hint" ] "fn" fn_sig "{" { statement } "}"
+//~ fn_sig ::= ident [ type_params ] "(" param { "," param } ")" [ return_val ]
+//~ type_params ::= "<" type_param { "," type_param } ">"
+//~ type_param ::= type ":" [ ident "::" ] type
 //~ return_val ::= "->" type
 //~ param ::= { "pub" } ident ":" type
 //~
@@ -381,19 +823,106 @@ impl FnSig {
     pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
         let (name, kind) = FuncOrMethod::parse(ctx, tokens)?;
 
-        let arguments = FunctionDef::parse_args(ctx, tokens, &kind)?;
+        let type_params = TypeParam::parse_list(ctx, tokens)?;
 
-        let return_type = FunctionDef::parse_fn_return_type(ctx, tokens)?;
+        let mut arguments = FunctionDef::parse_args(ctx, tokens, &kind)?;
+
+        let mut return_type = FunctionDef::parse_fn_return_type(ctx, tokens)?;
+
+        // the types named after a type parameter are generic
+        if !type_params.is_empty() {
+            let typs = arguments
+                .iter_mut()
+                .map(|arg| &mut arg.typ)
+                .chain(return_type.as_mut());
+            for typ in typs {
+                typ.kind.apply_type_params(&type_params);
+            }
+        }
 
         Ok(Self {
             kind,
             name,
+            type_params,
             arguments,
             return_type,
+            hint: false,
         })
     }
 }
 
+/// A generic type parameter of a function, bound to a trait (e.g. `T: Hashable`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeParam {
+    pub name: Ident,
+    pub bound: CustomType,
+}
+
+impl TypeParam {
+    /// Parses the (optional) type parameters of a function.
+    pub fn parse_list(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Vec<Self>> {
+        // fn digest<T: Hashable, U: Hashable>(
+        //          ^
+        if !matches!(
+            tokens.peek(),
+            Some(Token {
+                kind: TokenKind::Less,
+                ..
+            })
+        ) {
+            return Ok(vec![]);
+        }
+        tokens.bump(ctx);
+
+        let mut type_params: Vec<TypeParam> = vec![];
+        loop {
+            // fn digest<T: Hashable, U: Hashable>(
+            //           ^^^^^^^^^^^
+            let type_param = Self::parse(ctx, tokens)?;
+            if type_params
+                .iter()
+                .any(|param| param.name == type_param.name)
+            {
+                return Err(ctx.error(
+                    ErrorKind::DuplicateDefinition(type_param.name.value),
+                    type_param.name.span,
+                ));
+            }
+            type_params.push(type_param);
+
+            // fn digest<T: Hashable, U: Hashable>(
+            //                      ^            ^
+            let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+            match token.kind {
+                TokenKind::Comma => (),
+                TokenKind::Greater => return Ok(type_params),
+                _ => {
+                    return Err(ctx.error(ErrorKind::ExpectedToken(TokenKind::Greater), token.span))
+                }
+            }
+        }
+    }
+
+    fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // T: Hashable
+        // ^
+        let name = Ident::parse(ctx, tokens)?;
+        if !is_type(&name.value) {
+            return Err(ctx.error(ErrorKind::InvalidTypeParam(name.value), name.span));
+        }
+
+        // T: Hashable
+        //  ^
+        tokens.bump_expected(ctx, TokenKind::Colon)?;
+
+        // T: Hashable   or   T: module::Hashable
+        //    ^^^^^^^^           ^^^^^^^^^^^^^^^^
+        let bound = parse_trait_path(ctx, tokens)?;
+
+        Ok(Self { name, bound })
+    }
+}
+
 /// Any kind of text that can represent a type, a variable, a function name, etc.
 #[derive(Debug, Default, Clone, Eq, Serialize, Deserialize, Educe)]
 #[educe(Hash, PartialEq)]
@@ -429,6 +958,7 @@ impl Ident {
 pub enum AttributeKind {
     Pub,
     Const,
+    Mut,
 }
 
 impl AttributeKind {
@@ -439,6 +969,10 @@ impl AttributeKind {
     pub fn is_constant(&self) -> bool {
         matches!(self, Self::Const)
     }
+
+    pub fn is_mutable(&self) -> bool {
+        matches!(self, Self::Mut)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -455,6 +989,10 @@ impl Attribute {
     pub fn is_constant(&self) -> bool {
         self.kind.is_constant()
     }
+
+    pub fn is_mutable(&self) -> bool {
+        self.kind.is_mutable()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -486,9 +1024,13 @@ impl Default for FuncOrMethod {
 pub struct FnSig {
     pub kind: FuncOrMethod,
     pub name: Ident,
+    /// The generic types of the function, bound to traits (e.g. `<T: Hashable>`).
+    pub type_params: Vec<TypeParam>,
     /// (pub, ident, type)
     pub arguments: Vec<FnArg>,
     pub return_type: Option<Ty>,
+    /// Set for a `hint fn`, whose body is only run at witness generation time.
+    pub hint: bool,
 }
 
 pub struct Method {
@@ -527,6 +1069,15 @@ impl FnArg {
             .map(|attr| attr.is_constant())
             .unwrap_or(false)
     }
+
+    /// Mutable arguments are passed by reference:
+    /// their value at the end of the function is written back to the caller's variable.
+    pub fn is_mutable(&self) -> bool {
+        self.attribute
+            .as_ref()
+            .map(|attr| attr.is_mutable())
+            .unwrap_or(false)
+    }
 }
 
 impl FuncOrMethod {
@@ -623,6 +1174,17 @@ impl FunctionDef {
                         arg_name,
                     )
                 }
+                // mutable input
+                TokenKind::Keyword(Keyword::Mut) => {
+                    let arg_name = Ident::parse(ctx, tokens)?;
+                    (
+                        Some(Attribute {
+                            kind: AttributeKind::Mut,
+                            span: token.span,
+                        }),
+                        arg_name,
+                    )
+                }
                 // private input
                 TokenKind::Identifier(name) => (
                     None,
@@ -660,10 +1222,12 @@ impl FunctionDef {
                     ));
                 }
 
+                // (the sizes of a generic struct are filled by the type checker)
                 Ty {
                     kind: TyKind::Custom {
                         module: ModulePath::Local,
                         name: self_name.name.clone(),
+                        sizes: vec![],
                     },
                     span: self_name.span,
                 }
@@ -683,7 +1247,11 @@ impl FunctionDef {
 
             let span = if let Some(attr) = &attribute {
                 if &arg_name.value == "self" {
-                    return Err(ctx.error(ErrorKind::SelfHasAttribute, arg_name.span));
+                    // (`mut self` is the only attribute allowed on `self`)
+                    if !attr.is_mutable() {
+                        return Err(ctx.error(ErrorKind::SelfHasAttribute, arg_name.span));
+                    }
+                    attr.span.merge_with(arg_name.span)
                 } else {
                     attr.span.merge_with(arg_typ.span)
                 }
@@ -858,14 +1426,26 @@ pub fn is_valid_fn_type(name: &str) -> bool {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Range {
-    pub start: u32,
-    pub end: u32,
+    pub start: Expr,
+    pub end: Expr,
     pub span: Span,
 }
 
 impl Range {
-    pub fn range(&self) -> std::ops::Range<u32> {
-        self.start..self.end
+    /// Parses the bound of a range.
+    /// Bounds are expressions that must be known at compile time
+    /// (e.g. `5`, `N`, `size - 1`), which is enforced by the type checker.
+    fn parse_bound(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Expr> {
+        let bound = Expr::parse(ctx, tokens)?;
+
+        if let ExprKind::BigUInt(n) = &bound.kind {
+            let _: u32 = n
+                .clone()
+                .try_into()
+                .map_err(|_e| ctx.error(ErrorKind::InvalidRangeSize, bound.span))?;
+        }
+
+        Ok(bound)
     }
 }
 
@@ -875,11 +1455,64 @@ pub struct Stmt {
     pub span: Span,
 }
 
+/// The variable(s) introduced by a `let` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Pattern {
+    /// `let x = ...`
+    Ident(Ident),
+
+    /// `let (x, y) = ...`
+    Tuple(Vec<Ident>),
+}
+
+impl Pattern {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        match tokens.peek() {
+            // (x, y)
+            // ^
+            Some(Token {
+                kind: TokenKind::LeftParen,
+                ..
+            }) => {
+                tokens.bump(ctx); // (
+
+                let mut names = vec![];
+                loop {
+                    // (x, y)
+                    //  ^
+                    names.push(Ident::parse(ctx, tokens)?);
+
+                    // (x, y)
+                    //   ^  ^
+                    let token = tokens.bump_err(ctx, ErrorKind::InvalidEndOfLine)?;
+                    match token.kind {
+                        TokenKind::Comma => (),
+                        TokenKind::RightParen => break,
+                        _ => {
+                            return Err(ctx.error(
+                                ErrorKind::ExpectedToken(TokenKind::RightParen),
+                                token.span,
+                            ))
+                        }
+                    }
+                }
+
+                Ok(Pattern::Tuple(names))
+            }
+
+            // x
+            _ => Ok(Pattern::Ident(Ident::parse(ctx, tokens)?)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StmtKind {
     Assign {
         mutable: bool,
-        lhs: Ident,
+        lhs: Pattern,
+        /// The type of the variable, if specified (e.g. `let x: Field = 5;`).
+        typ: Option<Ty>,
         rhs: Box<Expr>,
     },
     Expr(Box<Expr>),
@@ -892,9 +1525,59 @@ pub enum StmtKind {
         range: Range,
         body: Vec<Stmt>,
     },
+
+    // `for var in array { <body> }` or `for (index, var) in array.enumerate() { <body> }`
+    ForIn {
+        index: Option<Ident>,
+        var: Ident,
+        array: Box<Expr>,
+        body: Vec<Stmt>,
+    },
+
+    // `if cond { <then_> } else { <else_> }`
+    IfElse {
+        cond: Box<Expr>,
+        then_: Vec<Stmt>,
+        else_: Vec<Stmt>,
+    },
 }
 
 impl Stmt {
+    /// Parses the body of a loop, including its curly brackets.
+    fn parse_loop_body(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Vec<Stmt>> {
+        // for i in 0..5 { ... }
+        //               ^
+        tokens.bump_expected(ctx, TokenKind::LeftCurlyBracket)?;
+
+        // for i in 0..5 { ... }
+        //                 ^^^
+        let mut body = vec![];
+
+        loop {
+            // for i in 0..5 { ... }
+            //                     ^
+            let next_token = tokens.peek();
+            if matches!(
+                next_token,
+                Some(Token {
+                    kind: TokenKind::RightCurlyBracket,
+                    ..
+                })
+            ) {
+                tokens.bump(ctx);
+                break;
+            }
+
+            // parse next statement
+            // TODO: should we prevent `return` here?
+            // TODO: in general, do we prevent early returns atm?
+            let statement = Stmt::parse(ctx, tokens)?;
+            body.push(statement);
+        }
+
+        Ok(body)
+    }
+
     /// Returns a list of statement parsed until seeing the end of a block (`}`).
     pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
         match tokens.peek() {
@@ -925,7 +1608,20 @@ impl Stmt {
 
                 // let mut x = 5;
                 //         ^
-                let lhs = Ident::parse(ctx, tokens)?;
+                let lhs = Pattern::parse(ctx, tokens)?;
+
+                // let mut x: Field = 5;
+                //          ^^^^^^^
+                let typ = match tokens.peek() {
+                    Some(Token {
+                        kind: TokenKind::Colon,
+                        ..
+                    }) => {
+                        tokens.bump(ctx);
+                        Some(Ty::parse(ctx, tokens)?)
+                    }
+                    _ => None,
+                };
 
                 // let mut x = 5;
                 //           ^
@@ -942,7 +1638,12 @@ impl Stmt {
 
                 //
                 Ok(Stmt {
-                    kind: StmtKind::Assign { mutable, lhs, rhs },
+                    kind: StmtKind::Assign {
+                        mutable,
+                        lhs,
+                        typ,
+                        rhs,
+                    },
                     span,
                 })
             }
@@ -954,114 +1655,140 @@ impl Stmt {
             }) => {
                 tokens.bump(ctx);
 
-                // for i in 0..5 { ... }
-                //     ^
-                let var = Ident::parse(ctx, tokens)?;
+                // for i in 0..5 { ... }   or   for (i, x) in arr.enumerate() { ... }
+                //     ^                            ^^^^^^
+                let pattern = Pattern::parse(ctx, tokens)?;
 
                 // for i in 0..5 { ... }
                 //       ^^
                 tokens.bump_expected(ctx, TokenKind::Keyword(Keyword::In))?;
 
-                // for i in 0..5 { ... }
-                //          ^
-                let (start, start_span) = match tokens.bump(ctx) {
+                // for i in 0..5 { ... }   or   for x in arr { ... }
+                //          ^                            ^^^
+                let start = Range::parse_bound(ctx, tokens)?;
+
+                let kind = if matches!(
+                    tokens.peek(),
                     Some(Token {
-                        kind: TokenKind::BigUInt(n),
-                        span,
-                    }) => {
-                        let start: u32 = n
-                            .try_into()
-                            .map_err(|_e| ctx.error(ErrorKind::InvalidRangeSize, span))?;
-                        (start, span)
-                    }
-                    _ => {
-                        return Err(ctx.error(
-                            ErrorKind::ExpectedToken(TokenKind::BigUInt(
-                                num_bigint::BigUint::zero(),
-                            )),
-                            ctx.last_span(),
-                        ))
-                    }
-                };
+                        kind: TokenKind::DoubleDot,
+                        ..
+                    })
+                ) {
+                    // for i in 0..5 { ... }
+                    //           ^^
+                    tokens.bump(ctx);
 
-                // for i in 0..5 { ... }
-                //           ^^
-                tokens.bump_expected(ctx, TokenKind::DoubleDot)?;
+                    // for i in 0..5 { ... }
+                    //             ^
+                    let end = Range::parse_bound(ctx, tokens)?;
+
+                    let var = match pattern {
+                        Pattern::Ident(var) => var,
+                        Pattern::Tuple(_) => {
+                            return Err(ctx.error(ErrorKind::InvalidForLoopPattern, span))
+                        }
+                    };
 
-                // for i in 0..5 { ... }
-                //             ^
-                let (end, end_span) = match tokens.bump(ctx) {
-                    Some(Token {
-                        kind: TokenKind::BigUInt(n),
-                        span,
-                    }) => {
-                        let end: u32 = n
-                            .try_into()
-                            .map_err(|_e| ctx.error(ErrorKind::InvalidRangeSize, span))?;
-                        (end, span)
-                    }
-                    _ => {
-                        return Err(ctx.error(
-                            ErrorKind::ExpectedToken(TokenKind::BigUInt(
-                                num_bigint::BigUint::zero(),
-                            )),
-                            ctx.last_span(),
-                        ))
+                    let range = Range {
+                        span: start.span.merge_with(end.span),
+                        start,
+                        end,
+                    };
+
+                    // for i in 0..5 { ... }
+                    //               ^^^^^^^
+                    let body = Self::parse_loop_body(ctx, tokens)?;
+
+                    StmtKind::ForLoop { var, range, body }
+                } else {
+                    // for x in arr { ... }   or   for (i, x) in arr.enumerate() { ... }
+                    //          ^^^                              ^^^^^^^^^^^^^^^
+                    let (index, var, array) = match (pattern, start.kind) {
+                        (Pattern::Ident(var), kind) => (None, var, Expr { kind, ..start }),
+                        (
+                            Pattern::Tuple(names),
+                            ExprKind::MethodCall {
+                                lhs,
+                                method_name,
+                                args,
+                            },
+                        ) if names.len() == 2
+                            && method_name.value == "enumerate"
+                            && args.is_empty() =>
+                        {
+                            let mut names = names.into_iter();
+                            (names.next(), names.next().unwrap(), *lhs)
+                        }
+                        _ => return Err(ctx.error(ErrorKind::InvalidForLoopPattern, span)),
+                    };
+
+                    // for x in arr { ... }
+                    //              ^^^^^^^
+                    let body = Self::parse_loop_body(ctx, tokens)?;
+
+                    StmtKind::ForIn {
+                        index,
+                        var,
+                        array: Box::new(array),
+                        body,
                     }
                 };
 
-                let range = Range {
-                    start,
-                    end,
-                    span: start_span.merge_with(end_span),
-                };
+                //
+                Ok(Stmt { kind, span })
+            }
 
-                // for i in 0..5 { ... }
-                //               ^
-                tokens.bump_expected(ctx, TokenKind::LeftCurlyBracket)?;
+            // if/else
+            Some(Token {
+                kind: TokenKind::Keyword(Keyword::If),
+                span,
+            }) => {
+                tokens.bump(ctx);
 
-                // for i in 0..5 { ... }
-                //                 ^^^
-                let mut body = vec![];
+                // if cond { ... } else { ... }
+                //    ^^^^
+                let cond = Box::new(Expr::parse(ctx, tokens)?);
 
-                loop {
-                    // for i in 0..5 { ... }
-                    //                     ^
-                    let next_token = tokens.peek();
+                // if cond { ... } else { ... }
+                //         ^^^^^^^
+                let then_ = FunctionDef::parse_fn_body(ctx, tokens)?;
+
+                // if cond { ... } else { ... }
+                //                 ^^^^
+                let else_ = if matches!(
+                    tokens.peek(),
+                    Some(Token {
+                        kind: TokenKind::Keyword(Keyword::Else),
+                        ..
+                    })
+                ) {
+                    tokens.bump(ctx);
+
+                    // if cond { ... } else if cond2 { ... }
+                    //                      ^^
                     if matches!(
-                        next_token,
+                        tokens.peek(),
                         Some(Token {
-                            kind: TokenKind::RightCurlyBracket,
+                            kind: TokenKind::Keyword(Keyword::If),
                             ..
                         })
                     ) {
-                        tokens.bump(ctx);
-                        break;
+                        vec![Stmt::parse(ctx, tokens)?]
+                    } else {
+                        // if cond { ... } else { ... }
+                        //                      ^^^^^^^
+                        FunctionDef::parse_fn_body(ctx, tokens)?
                     }
+                } else {
+                    vec![]
+                };
 
-                    // parse next statement
-                    // TODO: should we prevent `return` here?
-                    // TODO: in general, do we prevent early returns atm?
-                    let statement = Stmt::parse(ctx, tokens)?;
-                    body.push(statement);
-                }
-
-                //
                 Ok(Stmt {
-                    kind: StmtKind::ForLoop { var, range, body },
+                    kind: StmtKind::IfElse { cond, then_, else_ },
                     span,
                 })
             }
 
-            // if/else
-            Some(Token {
-                kind: TokenKind::Keyword(Keyword::If),
-                span: _,
-            }) => {
-                // TODO: wait, this should be implemented as an expression! not a statement
-                panic!("if statements are not implemented yet. Use if expressions instead (e.g. `x = if cond {{ 1 }} else {{ 2 }};`)");
-            }
-
             // return
             Some(Token {
                 kind: TokenKind::Keyword(Keyword::Return),
@@ -1119,11 +1846,8 @@ impl Stmt {
 #[derive(Debug)]
 
 /// Things you can have in a scope (including the root scope).
-pub struct Root<F>
-where
-    F: Field,
-{
-    pub kind: RootKind<F>,
+pub struct Root {
+    pub kind: RootKind,
     pub span: Span,
 }
 
@@ -1176,12 +1900,16 @@ impl UsePath {
 }
 
 #[derive(Debug)]
-pub enum RootKind<F: Field> {
+pub enum RootKind {
     Use(UsePath),
     FunctionDef(FunctionDef),
     Comment(String),
     StructDef(StructDef),
-    ConstDef(ConstDef<F>),
+    EnumDef(EnumDef),
+    TraitDef(TraitDef),
+    ImplDef(ImplDef),
+    ConstDef(ConstDef),
+    TypeAliasDef(TypeAliasDef),
 }
 
 //
@@ -1189,41 +1917,45 @@ pub enum RootKind<F: Field> {
 //
 
 #[derive(Debug)]
-pub struct ConstDef<F>
-where
-    F: Field,
-{
+pub struct ConstDef {
     pub module: ModulePath, // name resolution
     pub name: Ident,
-    pub value: F,
+    /// The type of the constant, if specified (e.g. `const foo: [Field; 2] = [1, 2];`).
+    pub typ: Option<Ty>,
+    /// The value of the constant, evaluated at compile time by the type checker.
+    pub value: Expr,
     pub span: Span,
 }
 
-impl<F: Field + FromStr> ConstDef<F> {
+impl ConstDef {
     pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
-        // const foo = 42;
+        // const foo: Field = 42;
         //       ^^^
         let name = Ident::parse(ctx, tokens)?;
 
-        // const foo = 42;
-        //           ^
+        // const foo: Field = 42;
+        //          ^^^^^^^
+        let typ = match tokens.peek() {
+            Some(Token {
+                kind: TokenKind::Colon,
+                ..
+            }) => {
+                tokens.bump(ctx);
+                Some(Ty::parse(ctx, tokens)?)
+            }
+            _ => None,
+        };
+
+        // const foo: Field = 42;
+        //                  ^
         tokens.bump_expected(ctx, TokenKind::Equal)?;
 
-        // const foo = 42;
-        //             ^^
+        // const foo: Field = 42;
+        //                    ^^
         let value = Expr::parse(ctx, tokens)?;
-        let value = match &value.kind {
-            ExprKind::BigUInt(n) => n
-                .to_string()
-                .parse()
-                .map_err(|_e| ctx.error(ErrorKind::InvalidField(n.to_string()), value.span))?,
-            _ => {
-                return Err(ctx.error(ErrorKind::InvalidConstType, value.span));
-            }
-        };
 
-        // const foo = 42;
-        //               ^
+        // const foo: Field = 42;
+        //                      ^
         tokens.bump_expected(ctx, TokenKind::SemiColon)?;
 
         //
@@ -1231,8 +1963,50 @@ impl<F: Field + FromStr> ConstDef<F> {
         Ok(ConstDef {
             module: ModulePath::Local,
             name,
+            typ,
             value,
             span,
         })
     }
 }
+
+//
+// Type alias
+//
+
+#[derive(Debug)]
+pub struct TypeAliasDef {
+    pub module: ModulePath, // name resolution
+    pub name: CustomType,
+    /// The type being aliased, which replaces the alias during name resolution.
+    pub typ: Ty,
+    pub span: Span,
+}
+
+impl TypeAliasDef {
+    pub fn parse(ctx: &mut ParserCtx, tokens: &mut Tokens) -> Result<Self> {
+        // type Path = [Field; 16];
+        //      ^^^^
+        let name = CustomType::parse(ctx, tokens)?;
+
+        // type Path = [Field; 16];
+        //           ^
+        tokens.bump_expected(ctx, TokenKind::Equal)?;
+
+        // type Path = [Field; 16];
+        //             ^^^^^^^^^^^
+        let typ = Ty::parse(ctx, tokens)?;
+
+        // type Path = [Field; 16];
+        //                        ^
+        tokens.bump_expected(ctx, TokenKind::SemiColon)?;
+
+        let span = name.span.merge_with(typ.span);
+        Ok(TypeAliasDef {
+            module: ModulePath::Local,
+            name,
+            typ,
+            span,
+        })
+    }
+}
@@ -1,18 +1,23 @@
 use std::collections::HashSet;
 
-use ark_ff::One;
+use ark_ff::{One, Zero};
+use num_bigint::BigUint;
 use once_cell::sync::Lazy;
 
 use crate::{
     backends::Backend,
     circuit_writer::{CircuitWriter, VarInfo},
     constants::Span,
+    constraints::{
+        bitwise::{self, Shift},
+        boolean, comparison, field, uint,
+    },
     error::{Error, ErrorKind, Result},
     imports::FnKind,
     lexer::Token,
     parser::{
         types::{FnSig, TyKind},
-        ParserCtx,
+        Expr, ExprKind, ParserCtx,
     },
     type_checker::FnInfo,
     var::{ConstOrCell, Var},
@@ -29,9 +34,24 @@ pub const QUALIFIED_BUILTINS: &str = "std/builtins";
 
 const ASSERT_FN: &str = "assert(condition: Bool)";
 const ASSERT_EQ_FN: &str = "assert_eq(lhs: Field, rhs: Field)";
+// note: `log` accepts a value of any type, which is special-cased by the type checker
+const LOG_FN: &str = "log(value: Field)";
+// note: the size of the array returned by `concat` is computed by the type checker
+const CONCAT_FN: &str = "concat(lhs: [Field; M], rhs: [Field; N])";
+const LESS_THAN_FN: &str = "less_than(lhs: Field, rhs: Field, const bitlen: Field) -> Bool";
+const ROTATE_LEFT_FN: &str = "rotate_left(value: U64, const amount: Field) -> U64";
+const ROTATE_RIGHT_FN: &str = "rotate_right(value: U64, const amount: Field) -> U64";
 
 /// List of builtin function signatures.
-pub const BUILTIN_SIGS: &[&str] = &[ASSERT_FN, ASSERT_EQ_FN];
+pub const BUILTIN_SIGS: &[&str] = &[
+    ASSERT_FN,
+    ASSERT_EQ_FN,
+    LOG_FN,
+    CONCAT_FN,
+    LESS_THAN_FN,
+    ROTATE_LEFT_FN,
+    ROTATE_RIGHT_FN,
+];
 
 // Unique set of builtin function names, derived from function signatures.
 pub static BUILTIN_FN_NAMES: Lazy<HashSet<String>> = Lazy::new(|| {
@@ -54,6 +74,11 @@ pub fn get_builtin_fn<B: Backend>(name: &str) -> Option<FnInfo<B>> {
     let fn_handle = match name {
         ASSERT_FN => assert,
         ASSERT_EQ_FN => assert_eq,
+        LOG_FN => log,
+        CONCAT_FN => concat,
+        LESS_THAN_FN => less_than,
+        ROTATE_LEFT_FN => rotate_left,
+        ROTATE_RIGHT_FN => rotate_right,
         _ => return None,
     };
 
@@ -63,6 +88,40 @@ pub fn get_builtin_fn<B: Backend>(name: &str) -> Option<FnInfo<B>> {
     })
 }
 
+/// Builtin assertions can be passed a message as last argument (e.g. `assert(cond, "msg")`),
+/// which is reported if the assertion fails.
+/// Returns the arguments of a function call, and the message if there is one.
+pub fn split_assert_message<'a, B: Backend>(
+    fn_info: &FnInfo<B>,
+    args: &'a [Expr],
+) -> (&'a [Expr], Option<&'a str>) {
+    let sig = match &fn_info.kind {
+        FnKind::BuiltIn(sig, _) if matches!(sig.name.value.as_str(), "assert" | "assert_eq") => sig,
+        _ => return (args, None),
+    };
+
+    match args.split_last() {
+        Some((
+            Expr {
+                kind: ExprKind::StringLiteral(message),
+                ..
+            },
+            args,
+        )) if args.len() == sig.arguments.len() => (args, Some(message)),
+        _ => (args, None),
+    }
+}
+
+/// Returns true if the function is the `log` builtin.
+pub fn is_log<B: Backend>(fn_info: &FnInfo<B>) -> bool {
+    matches!(&fn_info.kind, FnKind::BuiltIn(sig, _) if sig.name.value == "log")
+}
+
+/// Returns true if the function is the `concat` builtin.
+pub fn is_concat<B: Backend>(fn_info: &FnInfo<B>) -> bool {
+    matches!(&fn_info.kind, FnKind::BuiltIn(sig, _) if sig.name.value == "concat")
+}
+
 /// a function returns builtin functions
 pub fn builtin_fns<B: Backend>() -> Vec<FnInfo<B>> {
     BUILTIN_SIGS
@@ -106,6 +165,14 @@ fn assert_eq<B: Backend>(
     assert_eq!(rhs_var.len(), 1);
     let rhs_cvar = &rhs_var[0];
 
+    // within an `if`/`else` branch, we enforce `condition * (lhs - rhs) = 0` instead
+    if let Some(condition) = compiler.condition.clone() {
+        let diff = field::sub(compiler, lhs_cvar, rhs_cvar, span);
+        let res = field::mul(compiler, &condition, &diff[0], span);
+        assert_zero(compiler, &res[0], span)?;
+        return Ok(None);
+    }
+
     match (lhs_cvar, rhs_cvar) {
         // two constants
         (ConstOrCell::Const(a), ConstOrCell::Const(b)) => {
@@ -149,9 +216,23 @@ fn assert<B: Backend>(
     assert_eq!(var.len(), 1);
     let cond = &var[0];
 
+    // within an `if`/`else` branch, we enforce `condition * (1 - cond) = 0` instead
+    if let Some(condition) = compiler.condition.clone() {
+        let not_cond = boolean::not(compiler, cond, span);
+        let res = field::mul(compiler, &condition, &not_cond[0], span);
+        assert_zero(compiler, &res[0], span)?;
+        return Ok(None);
+    }
+
     match cond {
         ConstOrCell::Const(cst) => {
-            assert!(cst.is_one());
+            if !cst.is_one() {
+                return Err(Error::new(
+                    "constraint-generation",
+                    ErrorKind::AssertionFailed,
+                    span,
+                ));
+            }
         }
         ConstOrCell::Cell(cvar) => {
             let one = B::Field::one();
@@ -161,3 +242,137 @@ fn assert<B: Backend>(
 
     Ok(None)
 }
+
+/// Asserts that a value is zero.
+/// Constants are checked at compile time.
+fn assert_zero<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    var: &ConstOrCell<B::Field, B::Var>,
+    span: Span,
+) -> Result<()> {
+    match var {
+        ConstOrCell::Const(cst) => {
+            if !cst.is_zero() {
+                return Err(Error::new(
+                    "constraint-generation",
+                    ErrorKind::AssertionFailed,
+                    span,
+                ));
+            }
+        }
+        ConstOrCell::Cell(cvar) => {
+            compiler
+                .backend
+                .assert_eq_const(cvar, B::Field::zero(), span);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether `lhs < rhs`, where both field elements are range checked to `bitlen` bits.
+/// This is cheaper than the `<` operator on field elements,
+/// which has to range check its operands to the largest bit length that the field allows.
+fn less_than<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    vars: &[VarInfo<B::Field, B::Var>],
+    span: Span,
+) -> Result<Option<Var<B::Field, B::Var>>> {
+    // we get two field elements and a bit length
+    assert_eq!(vars.len(), 3);
+    let lhs = &vars[0].var[0];
+    let rhs = &vars[1].var[0];
+
+    // the bit length must be known at compile time
+    let max_bitlen = comparison::max_bitlen::<B::Field>();
+    let bitlen: BigUint = vars[2]
+        .var
+        .constant()
+        .ok_or_else(|| compiler.error(ErrorKind::InvalidComparisonBitlen(max_bitlen), span))?
+        .into();
+
+    let bitlen = match usize::try_from(bitlen) {
+        Ok(bitlen) if (1..=max_bitlen).contains(&bitlen) => bitlen,
+        _ => {
+            return Err(compiler.error(ErrorKind::InvalidComparisonBitlen(max_bitlen), span));
+        }
+    };
+
+    uint::range_check(compiler, lhs, bitlen, span)?;
+    uint::range_check(compiler, rhs, bitlen, span)?;
+
+    let res = comparison::less_than(compiler, lhs, rhs, bitlen, span);
+
+    Ok(Some(res))
+}
+
+/// Rotates the bits of a 64-bit integer to the left by a constant amount.
+fn rotate_left<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    vars: &[VarInfo<B::Field, B::Var>],
+    span: Span,
+) -> Result<Option<Var<B::Field, B::Var>>> {
+    // we get an integer and an amount
+    assert_eq!(vars.len(), 2);
+    let amount = compiler.compute_shift(&vars[1].var, 64, span)?;
+
+    let res = bitwise::shift(
+        compiler,
+        &vars[0].var[0],
+        Shift::RotateLeft(amount),
+        64,
+        span,
+    );
+
+    Ok(Some(res))
+}
+
+/// Rotates the bits of a 64-bit integer to the right by a constant amount.
+fn rotate_right<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    vars: &[VarInfo<B::Field, B::Var>],
+    span: Span,
+) -> Result<Option<Var<B::Field, B::Var>>> {
+    // we get an integer and an amount
+    assert_eq!(vars.len(), 2);
+    let amount = compiler.compute_shift(&vars[1].var, 64, span)?;
+
+    // which is a rotation to the left by the remaining bits
+    let shift = Shift::RotateLeft((64 - amount) % 64);
+    let res = bitwise::shift(compiler, &vars[0].var[0], shift, 64, span);
+
+    Ok(Some(res))
+}
+
+/// Logs a value, which is printed during witness generation if logs are enabled.
+/// This doesn't add any constraint.
+fn log<B: Backend>(
+    compiler: &mut CircuitWriter<B>,
+    vars: &[VarInfo<B::Field, B::Var>],
+    span: Span,
+) -> Result<Option<Var<B::Field, B::Var>>> {
+    // we get a single var, of any type
+    assert_eq!(vars.len(), 1);
+
+    compiler.log(vars[0].clone(), span);
+
+    Ok(None)
+}
+
+/// Concatenates two arrays.
+/// This doesn't add any constraint.
+fn concat<B: Backend>(
+    _compiler: &mut CircuitWriter<B>,
+    vars: &[VarInfo<B::Field, B::Var>],
+    span: Span,
+) -> Result<Option<Var<B::Field, B::Var>>> {
+    // we get two arrays
+    assert_eq!(vars.len(), 2);
+
+    let cvars = vars
+        .iter()
+        .flat_map(|var_info| var_info.var.cvars.clone())
+        .collect();
+
+    Ok(Some(Var::new(cvars, span)))
+}
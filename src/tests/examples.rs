@@ -261,6 +261,18 @@ fn test_for_loop(#[case] backend: BackendKind) -> miette::Result<()> {
     Ok(())
 }
 
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_for_in(#[case] backend: BackendKind) -> miette::Result<()> {
+    let private_inputs = r#"{"xx": ["2", "3", "4"]}"#;
+    let public_inputs = r#"{"expected": "9"}"#;
+
+    test_file("for_in", public_inputs, private_inputs, vec!["11"], backend)?;
+
+    Ok(())
+}
+
 #[rstest]
 #[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
 #[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
@@ -472,3 +484,401 @@ fn test_types_array_output(#[case] backend: BackendKind) -> miette::Result<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_division(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"public_input": "2"}"#;
+    let private_inputs = r#"{"private_input": "6"}"#;
+
+    test_file("division", public_inputs, private_inputs, vec![], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_negation(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"public_input": "3"}"#;
+    let private_inputs = r#"{"private_input": "2"}"#;
+
+    test_file("negation", public_inputs, private_inputs, vec![], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_comparison(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "3"}"#;
+    let private_inputs = r#"{"yy": "5"}"#;
+
+    test_file("comparison", public_inputs, private_inputs, vec![], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_uint(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "5"}"#;
+    let private_inputs = r#"{"yy": 7}"#;
+
+    test_file("uint", public_inputs, private_inputs, vec!["24"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_tuple(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"public_input": "1"}"#;
+    let private_inputs = r#"{"private_input": ["2", "3"]}"#;
+
+    test_file("tuple", public_inputs, private_inputs, vec!["8"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_generics(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"public_input": "9"}"#;
+    let private_inputs = r#"{"private_input": ["2", "3", "4"]}"#;
+
+    test_file("generics", public_inputs, private_inputs, vec![], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_generic_structs(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"expected": "9"}"#;
+    let private_inputs = r#"{"bag": {"items": ["2", "3", "4"]}}"#;
+
+    test_file(
+        "generic_structs",
+        public_inputs,
+        private_inputs,
+        vec![],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_dynamic_index(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"expected": "8"}"#;
+    let private_inputs =
+        r#"{"private_input": ["1", "2", "3", "4", "5", "6", "7", "8"], "idx": "3"}"#;
+
+    test_file(
+        "dynamic_index",
+        public_inputs,
+        private_inputs,
+        vec![],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_if_statement(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"public_input": "3"}"#;
+    let private_inputs = r#"{"private_input": "2"}"#;
+
+    test_file(
+        "if_statement",
+        public_inputs,
+        private_inputs,
+        vec!["5"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_early_return(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"public_input": "3"}"#;
+    let private_inputs = r#"{"private_input": ["1", "12", "20"]}"#;
+
+    test_file(
+        "early_return",
+        public_inputs,
+        private_inputs,
+        vec!["10"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_loop_bounds(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"threshold": "10"}"#;
+    let private_inputs = r#"{"private_input": ["1", "2", "3", "4"]}"#;
+
+    test_file(
+        "loop_bounds",
+        public_inputs,
+        private_inputs,
+        vec!["6"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_bitwise(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "12"}"#;
+    let private_inputs = r#"{"yy": "10"}"#;
+
+    test_file(
+        "bitwise",
+        public_inputs,
+        private_inputs,
+        vec!["197"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_rotations(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"word": "12"}"#;
+    let private_inputs = r#"{"other": "10"}"#;
+
+    test_file(
+        "rotations",
+        public_inputs,
+        private_inputs,
+        vec!["13835058055282163914"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_int_division(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"timestamp": "1000"}"#;
+    let private_inputs = r#"{"bucket_size": "60"}"#;
+
+    test_file(
+        "int_division",
+        public_inputs,
+        private_inputs,
+        vec!["960"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_mut_args(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"balance": "15"}"#;
+    let private_inputs = r#"{"amounts": ["3", "5", "2"]}"#;
+
+    test_file(
+        "mut_args",
+        public_inputs,
+        private_inputs,
+        vec!["5"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_nested_assignment(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"idx": "2"}"#;
+    let private_inputs = r#"{"amount": "7"}"#;
+
+    test_file(
+        "nested_assignment",
+        public_inputs,
+        private_inputs,
+        vec!["14"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_enums(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"tx": {"Transfer": ["1", "10"]}}"#;
+    let private_inputs = r#"{"balance": "5"}"#;
+
+    test_file("enums", public_inputs, private_inputs, vec!["28"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_traits(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"point": {"xx": "2", "yy": "5"}}"#;
+    let private_inputs =
+        r#"{"accounts": [{"id": "1", "balance": "2"}, {"id": "3", "balance": "4"}]}"#;
+
+    test_file("traits", public_inputs, private_inputs, vec!["74"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_struct_array_input(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"points": [{"xx": "1", "yy": "2", "visible": true}, {"xx": "3", "yy": "4", "visible": false}]}"#;
+    let private_inputs = r#"{"scale": "5"}"#;
+
+    test_file(
+        "struct_array_input",
+        public_inputs,
+        private_inputs,
+        vec!["26"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_hint(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "5"}"#;
+    let private_inputs = r#"{"yy": "100", "divisor": "7", "points": [{"xx": "4", "yy": "10"}, {"xx": "2", "yy": "20"}, {"xx": "9", "yy": "30"}]}"#;
+
+    test_file("hint", public_inputs, private_inputs, vec!["34"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_log(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"bound": "100"}"#;
+    let private_inputs = r#"{"points": [{"xx": "1", "yy": "2"}, {"xx": "3", "yy": "4"}]}"#;
+
+    test_file("log", public_inputs, private_inputs, vec!["14"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_bytes(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"prefix": ["109", "105", "110", "97"]}"#;
+    let private_inputs = r#"{"msg": "5"}"#;
+
+    test_file(
+        "bytes",
+        public_inputs,
+        private_inputs,
+        vec!["1835626086"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_const_exprs(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "1"}"#;
+    let private_inputs = r#"{"idx": "1"}"#;
+
+    test_file(
+        "const_exprs",
+        public_inputs,
+        private_inputs,
+        vec!["40"],
+        backend,
+    )?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_slices(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "1"}"#;
+    let private_inputs = r#"{"yy": ["1", "2", "3", "4"]}"#;
+
+    test_file("slices", public_inputs, private_inputs, vec!["24"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_alias(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"root": "6"}"#;
+    let private_inputs = r#"{"path": ["1", "2", "3"]}"#;
+
+    test_file("alias", public_inputs, private_inputs, vec!["7"], backend)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[case::kimchi_vesta(BackendKind::KimchiVesta(KimchiVesta::new(false)))]
+#[case::r1cs(BackendKind::R1csBls12_381(R1CS::new()))]
+fn test_annotations(#[case] backend: BackendKind) -> miette::Result<()> {
+    let public_inputs = r#"{"xx": "2"}"#;
+    let private_inputs = r#"{"yy": "4"}"#;
+
+    test_file(
+        "annotations",
+        public_inputs,
+        private_inputs,
+        vec!["1009"],
+        backend,
+    )?;
+
+    Ok(())
+}
@@ -15,7 +15,7 @@ struct Lol {
     aa: Field,
 }
 
-fn Lol.match(self, bb: Field) {
+fn Lol.check(self, bb: Field) {
     assert_eq(self.aa, bb);
 }
 
@@ -43,6 +43,13 @@ fn add(xx: Field, yy: Field) -> Field {
     return xx + yy;
 }
 
+// a type alias, used in the main module
+type Pair = [Field; 2];
+
+fn sum(pair: Pair) -> Field {
+    return pair[0] + pair[1];
+}
+
 fn Lib.tt(self) -> Field {
     return self.tt.inner;
 }
@@ -58,7 +65,7 @@ fn new_liblib() -> liblib::Lol {
 }
 
 fn test_liblib(ff: Field, lol: liblib::Lol) {
-    lol.match(ff);
+    lol.check(ff);
 }
 "#;
 
@@ -75,6 +82,10 @@ fn main(pub xx: Field, yy: Field) {
     let zz = lib::add(y3, 1);
     assert_eq(zz, xx);
 
+    // use a library's type alias
+    let pair: lib::Pair = [xx, yy];
+    assert_eq(lib::sum(pair), 2);
+
     // use a transitive dependency
     let lol = lib::new_liblib();
     lib::test_liblib(1, lol);
@@ -8,9 +8,10 @@ use crate::{
     error::{ErrorKind, Result},
     imports::FnKind,
     parser::{
-        types::{FnSig, FunctionDef, Stmt, StmtKind, Ty, TyKind},
-        CustomType, Expr, ExprKind, Op2,
+        types::{ArraySize, FnSig, FunctionDef, Pattern, Stmt, StmtKind, Ty, TyKind},
+        CustomType, Expr, ExprKind, MatchPattern, Op2,
     },
+    stdlib::{is_concat, is_log, split_assert_message},
     syntax::is_type,
 };
 
@@ -41,6 +42,64 @@ pub struct StructInfo {
     pub name: String,
     pub fields: Vec<(String, TyKind)>,
     pub methods: HashMap<String, FunctionDef>,
+    /// The traits implemented by the struct.
+    pub traits: Vec<FullyQualified>,
+    /// The generic array sizes used by the fields (e.g. `N` in `inner: [Field; N]`),
+    /// in the order in which they must be given (e.g. `Sudoku<81>`).
+    pub generics: Vec<String>,
+}
+
+impl StructInfo {
+    /// Binds the generic array sizes of the struct to the ones of an instance of it.
+    pub fn bind_sizes(&self, sizes: &[ArraySize]) -> HashMap<String, ArraySize> {
+        self.generics
+            .iter()
+            .cloned()
+            .zip(sizes.iter().cloned())
+            .collect()
+    }
+
+    /// Returns the fields of an instance of the struct, given the sizes of its generic arrays.
+    pub fn fields_of(&self, sizes: &[ArraySize]) -> Vec<(String, TyKind)> {
+        let bindings = self.bind_sizes(sizes);
+        self.fields
+            .iter()
+            .map(|(name, typ)| (name.clone(), typ.resolve_generic_sizes(&bindings)))
+            .collect()
+    }
+}
+
+/// Keeps track of the variants of a user-defined enum.
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct EnumInfo {
+    pub name: String,
+    pub variants: Vec<(String, Vec<TyKind>)>,
+}
+
+impl EnumInfo {
+    /// Returns the tag of a variant (its position in the enum declaration),
+    /// and the types of the values it holds.
+    pub fn variant(&self, name: &str) -> Option<(usize, &[TyKind])> {
+        self.variants
+            .iter()
+            .enumerate()
+            .find(|(_, (variant, _))| variant == name)
+            .map(|(tag, (_, payload))| (tag, payload.as_slice()))
+    }
+}
+
+/// Keeps track of the methods declared by a trait.
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct TraitInfo {
+    pub name: String,
+    pub methods: Vec<FnSig>,
+}
+
+impl TraitInfo {
+    /// Returns the signature of a method of the trait.
+    pub fn method(&self, name: &str) -> Option<&FnSig> {
+        self.methods.iter().find(|sig| sig.name.value == name)
+    }
 }
 
 /// Information that we need to pass around between expression nodes when type checking.
@@ -88,8 +147,12 @@ impl<B: Backend> TypeChecker<B> {
                     .expect("type-checker bug: field access on an empty var");
 
                 // obtain the type of the field
-                let (module, struct_name) = match lhs_node.typ {
-                    TyKind::Custom { module, name } => (module, name),
+                let (module, struct_name, sizes) = match lhs_node.typ {
+                    TyKind::Custom {
+                        module,
+                        name,
+                        sizes,
+                    } => (module, name, sizes),
                     _ => panic!("field access must be done on a custom struct"),
                 };
 
@@ -101,10 +164,10 @@ impl<B: Backend> TypeChecker<B> {
 
                 // find field type
                 let res = struct_info
-                    .fields
-                    .iter()
+                    .fields_of(&sizes)
+                    .into_iter()
                     .find(|(name, _)| name == &rhs.value)
-                    .map(|(_, typ)| typ.clone())
+                    .map(|(_, typ)| typ)
                     .expect("could not find field");
 
                 Some(ExprTyInfo::new(lhs_node.var_name, res))
@@ -126,11 +189,27 @@ impl<B: Backend> TypeChecker<B> {
                 })?;
                 let fn_sig = fn_info.sig().clone();
 
-                // type check the function call
-                let method_call = false;
-                let res = self.check_fn_call(typed_fn_env, method_call, fn_sig, args, expr.span)?;
+                // `log` accepts a value of any type
+                if is_log(fn_info) {
+                    self.check_log_call(typed_fn_env, args, expr.span)?;
+                    return Ok(None);
+                }
+
+                // `concat` returns an array whose size depends on the ones of its arguments
+                if is_concat(fn_info) {
+                    let typ = self.check_concat_call(typed_fn_env, args, expr.span)?;
+                    Some(ExprTyInfo::new_anon(typ))
+                } else {
+                    // assertions can be passed a message, which is not part of their signature
+                    let (args, _message) = split_assert_message(fn_info, args);
+
+                    // type check the function call
+                    let method_call = false;
+                    let res =
+                        self.check_fn_call(typed_fn_env, method_call, fn_sig, args, expr.span)?;
 
-                res.map(ExprTyInfo::new_anon)
+                    res.map(ExprTyInfo::new_anon)
+                }
             }
 
             // `lhs.method_name(args)`
@@ -139,35 +218,95 @@ impl<B: Backend> TypeChecker<B> {
                 method_name,
                 args,
             } => {
-                // retrieve struct name on the lhs
+                // retrieve the signature of the method,
+                // from the struct on the lhs or from the trait bounding its generic type
                 let lhs_type = self.compute_type(lhs, typed_fn_env)?;
-                let (module, struct_name) = match lhs_type.map(|t| t.typ) {
-                    Some(TyKind::Custom { module, name }) => (module, name),
+                let method_sig = match lhs_type.map(|t| t.typ) {
+                    // the length of an array is known at compile time
+                    Some(TyKind::Array(..) | TyKind::GenericSizedArray(..)) => {
+                        if method_name.value != "len" {
+                            return Err(self.error(
+                                ErrorKind::UndefinedArrayMethod(method_name.value.clone()),
+                                method_name.span,
+                            ));
+                        }
+
+                        if !args.is_empty() {
+                            return Err(self.error(
+                                ErrorKind::MismatchFunctionArguments(args.len(), 0),
+                                expr.span,
+                            ));
+                        }
+
+                        let res = ExprTyInfo::new_anon(TyKind::BigInt);
+                        self.node_types.insert(expr.node_id, res.typ.clone());
+                        return Ok(Some(res));
+                    }
+                    Some(TyKind::Custom {
+                        module,
+                        name,
+                        sizes,
+                    }) => {
+                        let qualified = FullyQualified::new(&module, &name);
+                        let struct_info = self.struct_info(&qualified).ok_or(
+                            self.error(ErrorKind::UndefinedStruct(name.clone()), lhs.span),
+                        )?;
+
+                        let mut sig = struct_info
+                            .methods
+                            .get(&method_name.value)
+                            .expect("method not found on custom struct (TODO: better error)")
+                            .sig
+                            .clone();
+
+                        // the generic array sizes of the struct are the ones of `self`
+                        let bindings = struct_info.bind_sizes(&sizes);
+                        for arg in &mut sig.arguments {
+                            arg.typ.kind = arg.typ.kind.resolve_generic_sizes(&bindings);
+                        }
+                        if let Some(typ) = &mut sig.return_type {
+                            typ.kind = typ.kind.resolve_generic_sizes(&bindings);
+                        }
+
+                        sig
+                    }
+                    Some(TyKind::Generic {
+                        trait_module,
+                        trait_name,
+                        ..
+                    }) => {
+                        let qualified = FullyQualified::new(&trait_module, &trait_name);
+                        let trait_info = self.trait_info(&qualified).ok_or_else(|| {
+                            self.error(ErrorKind::UndefinedTrait(trait_name.clone()), lhs.span)
+                        })?;
+
+                        trait_info
+                            .method(&method_name.value)
+                            .ok_or_else(|| {
+                                self.error(
+                                    ErrorKind::MethodNotInTrait(
+                                        method_name.value.clone(),
+                                        trait_name.clone(),
+                                    ),
+                                    method_name.span,
+                                )
+                            })?
+                            .clone()
+                    }
                     _ => return Err(self.error(ErrorKind::MethodCallOnNonCustomStruct, expr.span)),
                 };
 
-                // get struct info
-                let qualified = FullyQualified::new(&module, &struct_name);
-                let struct_info = self
-                    .struct_info(&qualified)
-                    .ok_or(self.error(ErrorKind::UndefinedStruct(struct_name.clone()), lhs.span))?
-                    .clone();
-
-                // get method info
-                let method_type = struct_info
-                    .methods
-                    .get(&method_name.value)
-                    .expect("method not found on custom struct (TODO: better error)");
+                // a `mut self` must be a mutable place
+                if let Some(self_arg) = method_sig.arguments.first() {
+                    if self_arg.name.value == "self" && self_arg.is_mutable() {
+                        self.check_mutable_place(lhs, typed_fn_env)?;
+                    }
+                }
 
                 // type check the method call
                 let method_call = true;
-                let res = self.check_fn_call(
-                    typed_fn_env,
-                    method_call,
-                    method_type.sig.clone(),
-                    args,
-                    expr.span,
-                )?;
+                let res =
+                    self.check_fn_call(typed_fn_env, method_call, method_sig, args, expr.span)?;
 
                 res.map(|ty| ExprTyInfo::new(None, ty))
             }
@@ -178,55 +317,29 @@ impl<B: Backend> TypeChecker<B> {
                     .compute_type(lhs, typed_fn_env)?
                     .expect("type-checker bug: lhs access on an empty var");
 
-                // lhs can be a local variable or a path to an array
-                let lhs_name = match &lhs.kind {
-                    // `name = <rhs>`
-                    ExprKind::Variable { module, name } => {
-                        // we first check if it's a constant
-                        // note: the only way to check that atm is to check in the constants hashmap
-                        // this is because we don't differentiate const vars from normal variables
-                        // (perhaps we should)
-                        let qualified = FullyQualified::new(&module, &name.value);
-                        if let Some(_cst_info) = self.const_info(&qualified) {
-                            return Err(self.error(
-                                ErrorKind::UnexpectedError("cannot assign to an external variable"),
-                                lhs.span,
-                            ));
-                        }
-
-                        name.value.clone()
-                    }
-
-                    // `array[idx] = <rhs>`
-                    ExprKind::ArrayAccess { array, idx } => {
-                        // get variable behind array
-                        let array_node = self
-                            .compute_type(array, typed_fn_env)?
-                            .expect("type-checker bug: array access on an empty var");
-
-                        array_node
-                            .var_name
-                            .expect("anonymous array access cannot be mutated")
-                    }
-
-                    // `struct.field = <rhs>`
-                    ExprKind::FieldAccess { lhs, rhs } => {
-                        // get variable behind lhs
-                        let lhs_node = self
-                            .compute_type(lhs, typed_fn_env)?
-                            .expect("type-checker bug: lhs access on an empty var");
-
-                        lhs_node
-                            .var_name
-                            .expect("anonymous lhs access cannot be mutated")
-                    }
-                    _ => panic!("bad expression assignment (TODO: replace with error)"),
+                // lhs can be a local variable, or a path to one of its fields or elements
+                // (e.g. `state.accounts[2].balance = <rhs>`)
+                let (module, lhs_name) = match &self.place_root(lhs)?.kind {
+                    ExprKind::Variable { module, name } => (module, name),
+                    _ => unreachable!(),
                 };
 
+                // we first check if it's a constant
+                // note: the only way to check that atm is to check in the constants hashmap
+                // this is because we don't differentiate const vars from normal variables
+                // (perhaps we should)
+                let qualified = FullyQualified::new(module, &lhs_name.value);
+                if let Some(_cst_info) = self.const_info(&qualified) {
+                    return Err(self.error(
+                        ErrorKind::UnexpectedError("cannot assign to an external variable"),
+                        lhs.span,
+                    ));
+                }
+
                 // check that the var exists locally
                 let lhs_info = typed_fn_env
-                    .get_type_info(&lhs_name)
-                    .expect("variable not found (TODO: replace with error")
+                    .get_type_info(&lhs_name.value)
+                    .ok_or_else(|| self.error(ErrorKind::UndefinedVariable, lhs_name.span))?
                     .clone();
 
                 // and is mutable
@@ -238,7 +351,10 @@ impl<B: Backend> TypeChecker<B> {
                 let rhs_typ = self.compute_type(rhs, typed_fn_env)?.unwrap();
 
                 if !rhs_typ.typ.match_expected(&lhs_node.typ) {
-                    panic!("lhs type doesn't match rhs type (TODO: replace with error)");
+                    return Err(self.error(
+                        ErrorKind::MismatchType(lhs_node.typ, rhs_typ.typ),
+                        expr.span,
+                    ));
                 }
 
                 None
@@ -252,10 +368,32 @@ impl<B: Backend> TypeChecker<B> {
                     .compute_type(rhs, typed_fn_env)?
                     .expect("type-checker bug");
 
-                if lhs_node.typ != rhs_node.typ {
-                    // only allow bigint mixed with field
+                // an integer can only be shifted by an amount known at compile time
+                if matches!(op, Op2::ShiftLeft | Op2::ShiftRight) {
+                    if lhs_node.typ.int_bitlen().is_none() {
+                        return Err(
+                            self.error(ErrorKind::InvalidBitwiseOperand(lhs_node.typ), expr.span)
+                        );
+                    }
+
+                    if !matches!(rhs_node.typ, TyKind::BigInt)
+                        || !self.is_constant_expr(rhs, typed_fn_env)
+                    {
+                        return Err(self.error(ErrorKind::InvalidShiftAmount, rhs.span));
+                    }
+                }
+
+                if lhs_node.typ != rhs_node.typ && !matches!(op, Op2::ShiftLeft | Op2::ShiftRight) {
+                    // only allow bigint mixed with field,
+                    // or integers mixed with literals
                     match (&lhs_node.typ, &rhs_node.typ) {
                         (TyKind::BigInt, TyKind::Field) | (TyKind::Field, TyKind::BigInt) => (),
+                        (TyKind::BigInt, typ)
+                            if typ.int_bitlen().is_some()
+                                && matches!(lhs.kind, ExprKind::BigUInt(_)) => {}
+                        (typ, TyKind::BigInt)
+                            if typ.int_bitlen().is_some()
+                                && matches!(rhs.kind, ExprKind::BigUInt(_)) => {}
                         _ => {
                             return Err(self.error(
                                 ErrorKind::MismatchType(lhs_node.typ.clone(), rhs_node.typ.clone()),
@@ -265,15 +403,73 @@ impl<B: Backend> TypeChecker<B> {
                     }
                 }
 
+                // a literal takes the type of the integer it is mixed with
+                let operand_typ = match (lhs_node.typ, rhs_node.typ) {
+                    (TyKind::BigInt, typ) if typ.int_bitlen().is_some() => typ,
+                    (typ, _) => typ,
+                };
+                let is_int = operand_typ.int_bitlen().is_some();
+
+                // that literal must then fit in the integer
+                // (the shift amount is not an operand, and is checked during circuit generation)
+                if let Some(bitlen) = operand_typ.int_bitlen() {
+                    let operands: &[&Expr] = match op {
+                        Op2::ShiftLeft | Op2::ShiftRight => &[&**lhs],
+                        _ => &[&**lhs, &**rhs],
+                    };
+                    for operand in operands {
+                        if let ExprKind::BigUInt(value) = &operand.kind {
+                            if value.bits() as usize > bitlen {
+                                return Err(
+                                    self.error(ErrorKind::IntegerOverflow(bitlen), operand.span)
+                                );
+                            }
+                        }
+                    }
+                }
+
                 let typ = match op {
                     Op2::Equality => TyKind::Bool,
                     Op2::Inequality => TyKind::Bool,
+                    Op2::LessThan
+                    | Op2::LessThanOrEqual
+                    | Op2::GreaterThan
+                    | Op2::GreaterThanOrEqual => {
+                        // only field elements and integers can be ordered
+                        if !matches!(operand_typ, TyKind::Field | TyKind::BigInt) && !is_int {
+                            return Err(self.error(
+                                ErrorKind::MismatchType(TyKind::Field, operand_typ),
+                                expr.span,
+                            ));
+                        }
+
+                        TyKind::Bool
+                    }
+                    // the remainder only makes sense on integers
+                    // (on which division is the euclidean division)
+                    Op2::Remainder if !is_int => {
+                        return Err(
+                            self.error(ErrorKind::InvalidRemainderOperand(operand_typ), expr.span)
+                        );
+                    }
+                    // bitwise operations only make sense on integers
+                    Op2::BitAnd | Op2::BitOr | Op2::BitXor if !is_int => {
+                        return Err(
+                            self.error(ErrorKind::InvalidBitwiseOperand(operand_typ), expr.span)
+                        );
+                    }
                     Op2::Addition
                     | Op2::Subtraction
                     | Op2::Multiplication
                     | Op2::Division
+                    | Op2::Remainder
                     | Op2::BoolAnd
-                    | Op2::BoolOr => lhs_node.typ,
+                    | Op2::BoolOr
+                    | Op2::BitAnd
+                    | Op2::BitOr
+                    | Op2::BitXor
+                    | Op2::ShiftLeft
+                    | Op2::ShiftRight => operand_typ,
                 };
 
                 Some(ExprTyInfo::new_anon(typ))
@@ -305,13 +501,27 @@ impl<B: Backend> TypeChecker<B> {
 
             ExprKind::BigUInt(_) => Some(ExprTyInfo::new_anon(TyKind::BigInt)),
 
+            ExprKind::ByteString(bytes) => {
+                let len: u32 = bytes.len().try_into().expect("byte string too large");
+                Some(ExprTyInfo::new_anon(TyKind::Array(
+                    Box::new(TyKind::Field),
+                    len,
+                )))
+            }
+
+            // only valid as the message of an assertion, which is handled with the function call
+            ExprKind::StringLiteral(_) => {
+                return Err(self.error(ErrorKind::UnexpectedStringLiteral, expr.span))
+            }
+
             ExprKind::Bool(_) => Some(ExprTyInfo::new_anon(TyKind::Bool)),
 
             // mod::path.of.var
             ExprKind::Variable { module, name } => {
                 let qualified = FullyQualified::new(module, &name.value);
 
-                if is_type(&name.value) {
+                // (generic array sizes look like types, but are stored as constants in the fn env)
+                if is_type(&name.value) && typed_fn_env.get_type(&name.value).is_none() {
                     // if it's a type, make sure it exists
                     let _struct_info = self
                         .struct_info(&qualified)
@@ -321,6 +531,7 @@ impl<B: Backend> TypeChecker<B> {
                     let res = ExprTyInfo::new_anon(TyKind::Custom {
                         module: module.clone(),
                         name: name.value.clone(),
+                        sizes: vec![],
                     });
                     Some(res)
                 } else {
@@ -357,20 +568,22 @@ impl<B: Backend> TypeChecker<B> {
                 let typ = self.compute_type(array, typed_fn_env)?.unwrap();
 
                 // check that it is an array
-                if !matches!(typ.typ, TyKind::Array(..)) {
+                if !matches!(typ.typ, TyKind::Array(..) | TyKind::GenericSizedArray(..)) {
                     return Err(self.error(ErrorKind::ArrayAccessOnNonArray, expr.span));
                 }
 
-                // check that expression is a bigint
+                // check that expression is a bigint or an integer
+                // (variables are allowed, the circuit writer then uses a selector)
                 let idx_typ = self.compute_type(idx, typed_fn_env)?;
                 match idx_typ.map(|t| t.typ) {
                     Some(TyKind::BigInt) => (),
+                    Some(typ) if typ.int_bitlen().is_some() => (),
                     _ => return Err(self.error(ErrorKind::ExpectedConstant, expr.span)),
                 };
 
                 // get type of element
                 let el_typ = match typ.typ {
-                    TyKind::Array(typkind, _) => *typkind,
+                    TyKind::Array(typkind, _) | TyKind::GenericSizedArray(typkind, _) => *typkind,
                     _ => panic!("not an array"),
                 };
 
@@ -378,6 +591,62 @@ impl<B: Backend> TypeChecker<B> {
                 Some(res)
             }
 
+            ExprKind::ArraySlice { array, start, end } => {
+                // get type of lhs
+                let typ = self.compute_type(array, typed_fn_env)?.unwrap();
+
+                // check that it is an array
+                // (the size of a generic array is only known when the function is called)
+                let (el_typ, array_len) = match typ.typ {
+                    TyKind::Array(typkind, len) => (*typkind, Some(len)),
+                    TyKind::GenericSizedArray(typkind, _) => (*typkind, None),
+                    _ => return Err(self.error(ErrorKind::ArrayAccessOnNonArray, expr.span)),
+                };
+
+                // the bounds must be known at compile time, as they determine the size of the slice
+                for bound in [start, end] {
+                    self.compute_type(bound, typed_fn_env)?;
+                }
+                let start = self.eval_const_size(start)?;
+                let end = self.eval_const_size(end)?;
+
+                if start > end || array_len.is_some_and(|len| end > len) {
+                    return Err(self.error(
+                        ErrorKind::InvalidSliceBounds(
+                            start as usize,
+                            end as usize,
+                            array_len.unwrap_or_default() as usize,
+                        ),
+                        expr.span,
+                    ));
+                }
+
+                let res = ExprTyInfo::new_anon(TyKind::Array(Box::new(el_typ), end - start));
+                Some(res)
+            }
+
+            ExprKind::RepeatedArrayDeclaration { item, size } => {
+                let item_typ = self
+                    .compute_type(item, typed_fn_env)?
+                    .expect("expected a value");
+
+                // the size can also be a generic array size (e.g. `[0; N]`)
+                let typ = match &size.kind {
+                    ExprKind::Variable { name, .. }
+                        if is_type(&name.value) && typed_fn_env.get_type(&name.value).is_some() =>
+                    {
+                        TyKind::GenericSizedArray(Box::new(item_typ.typ), name.value.clone())
+                    }
+                    _ => {
+                        self.compute_type(size, typed_fn_env)?;
+                        let size = self.eval_const_size(size)?;
+                        TyKind::Array(Box::new(item_typ.typ), size)
+                    }
+                };
+
+                Some(ExprTyInfo::new_anon(typ))
+            }
+
             ExprKind::ArrayDeclaration(items) => {
                 let len: u32 = items.len().try_into().expect("array too large");
 
@@ -421,6 +690,7 @@ impl<B: Backend> TypeChecker<B> {
                     ExprKind::Variable { .. }
                         | ExprKind::FieldAccess { .. }
                         | ExprKind::ArrayAccess { .. }
+                        | ExprKind::TupleAccess { .. }
                 ) {
                     panic!("`if` branch must be a variable, a field access, or an array access. It can't be logic that creates constraints.");
                 }
@@ -430,6 +700,7 @@ impl<B: Backend> TypeChecker<B> {
                     ExprKind::Variable { .. }
                         | ExprKind::FieldAccess { .. }
                         | ExprKind::ArrayAccess { .. }
+                        | ExprKind::TupleAccess { .. }
                 ) {
                     panic!("`else` branch must be a variable, a field access, or an array access. It can't be logic that creates constraints.");
                 }
@@ -463,6 +734,7 @@ impl<B: Backend> TypeChecker<B> {
                 })?;
 
                 let defined_fields = &struct_info.fields.clone();
+                let generics = struct_info.generics.clone();
 
                 if defined_fields.len() != fields.len() {
                     return Err(
@@ -470,6 +742,7 @@ impl<B: Backend> TypeChecker<B> {
                     );
                 }
 
+                let mut bindings = HashMap::new();
                 for (defined, observed) in defined_fields.iter().zip(fields) {
                     if defined.0 != observed.0.value {
                         return Err(self.error(
@@ -485,10 +758,129 @@ impl<B: Backend> TypeChecker<B> {
                         .compute_type(&observed.1, typed_fn_env)?
                         .expect("expected a value (TODO: better error)");
 
-                    if !observed_typ.typ.match_expected(&defined.1) {
+                    // the generic array sizes of the struct are inferred from its fields
+                    defined
+                        .1
+                        .bind_generic_sizes(&observed_typ.typ, &mut bindings)
+                        .map_err(|generic| {
+                            self.error(ErrorKind::ConflictingGenericSize(generic), observed.1.span)
+                        })?;
+                    let expected = defined.1.resolve_generic_sizes(&bindings);
+
+                    if !observed_typ.typ.match_expected(&expected) {
                         return Err(self.error(
-                            ErrorKind::InvalidStructFieldType(defined.1.clone(), observed_typ.typ),
+                            ErrorKind::InvalidStructFieldType(expected, observed_typ.typ),
+                            expr.span,
+                        ));
+                    }
+                }
+
+                let sizes = generics
+                    .iter()
+                    .map(|generic| bindings[generic].clone())
+                    .collect();
+
+                let res = ExprTyInfo::new_anon(TyKind::Custom {
+                    module: module.clone(),
+                    name: name.clone(),
+                    sizes,
+                });
+                Some(res)
+            }
+
+            ExprKind::Cast { lhs, typ } => {
+                let lhs_node = self
+                    .compute_type(lhs, typed_fn_env)?
+                    .expect("type-checker bug: cannot convert an empty var");
+
+                // only conversions between field elements and integers are supported
+                let convertible = |typ: &TyKind| {
+                    matches!(typ, TyKind::Field | TyKind::BigInt) || typ.int_bitlen().is_some()
+                };
+                if !convertible(&lhs_node.typ) || !convertible(&typ.kind) {
+                    return Err(self.error(
+                        ErrorKind::InvalidCast(lhs_node.typ, typ.kind.clone()),
+                        expr.span,
+                    ));
+                }
+
+                Some(ExprTyInfo::new_anon(typ.kind.clone()))
+            }
+
+            ExprKind::TupleDeclaration(items) => {
+                let mut typs = Vec::with_capacity(items.len());
+                for item in items {
+                    let item_typ = self
+                        .compute_type(item, typed_fn_env)?
+                        .expect("expected a value");
+                    typs.push(item_typ.typ);
+                }
+
+                Some(ExprTyInfo::new_anon(TyKind::Tuple(typs)))
+            }
+
+            ExprKind::TupleAccess { lhs, idx } => {
+                let lhs_node = self
+                    .compute_type(lhs, typed_fn_env)?
+                    .expect("type-checker bug: tuple access on an empty var");
+
+                // obtain the type of the element
+                let typ = match &lhs_node.typ {
+                    TyKind::Tuple(typs) => typs.get(*idx).cloned().ok_or_else(|| {
+                        self.error(
+                            ErrorKind::TupleIndexOutOfBounds(*idx, typs.len()),
                             expr.span,
+                        )
+                    })?,
+                    _ => return Err(self.error(ErrorKind::TupleAccessOnNonTuple, expr.span)),
+                };
+
+                Some(ExprTyInfo::new(lhs_node.var_name, typ))
+            }
+
+            ExprKind::EnumVariant {
+                custom,
+                variant,
+                args,
+            } => {
+                let CustomType {
+                    module,
+                    name,
+                    span: _,
+                } = custom;
+                let qualified = FullyQualified::new(module, name);
+                let enum_info = self
+                    .enum_info(&qualified)
+                    .ok_or_else(|| self.error(ErrorKind::UndefinedEnum(name.clone()), custom.span))?
+                    .clone();
+
+                let (_, payload) = enum_info.variant(&variant.value).ok_or_else(|| {
+                    self.error(
+                        ErrorKind::UndefinedVariant(name.clone(), variant.value.clone()),
+                        variant.span,
+                    )
+                })?;
+
+                if payload.len() != args.len() {
+                    return Err(self.error(
+                        ErrorKind::MismatchVariantPayload(
+                            variant.value.clone(),
+                            payload.len(),
+                            args.len(),
+                        ),
+                        expr.span,
+                    ));
+                }
+
+                for (expected, arg) in payload.iter().zip(args) {
+                    let arg_typ = self
+                        .compute_type(arg, typed_fn_env)?
+                        .expect("expected a value (TODO: better error)");
+
+                    if !arg_typ.typ.match_expected(expected) {
+                        return Err(self.error(
+                            ErrorKind::InvalidVariantPayloadType(expected.clone(), arg_typ.typ),
+                            arg.span,
                         ));
                     }
                 }
@@ -496,9 +888,135 @@ impl<B: Backend> TypeChecker<B> {
                 let res = ExprTyInfo::new_anon(TyKind::Custom {
                     module: module.clone(),
                     name: name.clone(),
+                    sizes: vec![],
                 });
                 Some(res)
             }
+
+            ExprKind::Match {
+                expr: scrutinee,
+                arms,
+            } => {
+                // only enums can be matched on
+                let scrutinee_typ = self
+                    .compute_type(scrutinee, typed_fn_env)?
+                    .expect("type-checker bug: match on an empty var")
+                    .typ;
+
+                let enum_info = match &scrutinee_typ {
+                    TyKind::Custom { module, name, .. } => {
+                        self.enum_info(&FullyQualified::new(module, name)).cloned()
+                    }
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    self.error(
+                        ErrorKind::MatchOnNonEnum(scrutinee_typ.clone()),
+                        scrutinee.span,
+                    )
+                })?;
+
+                let mut covered = vec![false; enum_info.variants.len()];
+                let mut res: Option<Option<TyKind>> = None;
+
+                for arm in arms {
+                    // an arm is useless if all variants are already covered
+                    if covered.iter().all(|covered| *covered) {
+                        return Err(self.error(ErrorKind::UnreachableMatchArm, arm.span));
+                    }
+
+                    // the values held by the variant are only visible in the arm
+                    typed_fn_env.nest();
+
+                    match &arm.pattern {
+                        MatchPattern::Variant {
+                            custom,
+                            variant,
+                            bindings,
+                        } => {
+                            let pattern_typ = TyKind::Custom {
+                                module: custom.module.clone(),
+                                name: custom.name.clone(),
+                                sizes: vec![],
+                            };
+                            if !pattern_typ.same_as(&scrutinee_typ) {
+                                return Err(self.error(
+                                    ErrorKind::MismatchType(scrutinee_typ, pattern_typ),
+                                    custom.span,
+                                ));
+                            }
+
+                            let (tag, payload) =
+                                enum_info.variant(&variant.value).ok_or_else(|| {
+                                    self.error(
+                                        ErrorKind::UndefinedVariant(
+                                            custom.name.clone(),
+                                            variant.value.clone(),
+                                        ),
+                                        variant.span,
+                                    )
+                                })?;
+
+                            if covered[tag] {
+                                return Err(self.error(ErrorKind::UnreachableMatchArm, arm.span));
+                            }
+                            covered[tag] = true;
+
+                            if payload.len() != bindings.len() {
+                                return Err(self.error(
+                                    ErrorKind::MismatchVariantPayload(
+                                        variant.value.clone(),
+                                        payload.len(),
+                                        bindings.len(),
+                                    ),
+                                    arm.pattern.span(),
+                                ));
+                            }
+
+                            for (binding, typ) in bindings.iter().zip(payload) {
+                                typed_fn_env.store_type(
+                                    binding.value.clone(),
+                                    TypeInfo::new(typ.clone(), binding.span),
+                                )?;
+                            }
+                        }
+
+                        // a wildcard covers all the remaining variants
+                        MatchPattern::Wildcard(_) => covered.fill(true),
+                    }
+
+                    let arm_typ = self.compute_type(&arm.expr, typed_fn_env)?.map(|t| t.typ);
+
+                    typed_fn_env.pop();
+
+                    // all arms must have the same type
+                    // (literals take the type of the other arms)
+                    res = match (res, arm_typ) {
+                        (None, typ) => Some(typ),
+                        (Some(None), None) => Some(None),
+                        (Some(Some(TyKind::BigInt)), Some(typ))
+                            if typ.same_as(&TyKind::BigInt) || typ.int_bitlen().is_some() =>
+                        {
+                            Some(Some(typ))
+                        }
+                        (Some(Some(typ)), Some(TyKind::BigInt)) if typ.int_bitlen().is_some() => {
+                            Some(Some(typ))
+                        }
+                        (Some(Some(typ)), Some(other)) if typ.same_as(&other) => Some(Some(typ)),
+                        _ => return Err(self.error(ErrorKind::MatchArmTypeMismatch, arm.span)),
+                    };
+                }
+
+                // all variants must be covered
+                if let Some(tag) = covered.iter().position(|covered| !covered) {
+                    return Err(self.error(
+                        ErrorKind::NonExhaustiveMatch(enum_info.variants[tag].0.clone()),
+                        expr.span,
+                    ));
+                }
+
+                res.flatten().map(ExprTyInfo::new_anon)
+            }
         };
 
         // save the type of that expression in our typed global env
@@ -510,90 +1028,302 @@ impl<B: Backend> TypeChecker<B> {
         Ok(typ)
     }
 
+    /// Returns true if the expression is known at compile time:
+    /// a literal, a constant, a `const` argument, a generic size, or arithmetic on those.
+    fn is_constant_expr(&self, expr: &Expr, typed_fn_env: &TypedFnEnv) -> bool {
+        match &expr.kind {
+            ExprKind::BigUInt(_) => true,
+            ExprKind::Variable { module, name } => {
+                let qualified = FullyQualified::new(module, &name.value);
+                self.const_info(&qualified).is_some()
+                    || typed_fn_env
+                        .get_type_info(&name.value)
+                        .map(|type_info| type_info.constant)
+                        .unwrap_or(false)
+            }
+            ExprKind::BinaryOp { op, lhs, rhs, .. } => {
+                matches!(op, Op2::Addition | Op2::Subtraction | Op2::Multiplication)
+                    && self.is_constant_expr(lhs, typed_fn_env)
+                    && self.is_constant_expr(rhs, typed_fn_env)
+            }
+            // the length of an array is known at compile time
+            ExprKind::MethodCall {
+                lhs, method_name, ..
+            } => {
+                method_name.value == "len"
+                    && matches!(
+                        self.expr_type(lhs),
+                        Some(TyKind::Array(..) | TyKind::GenericSizedArray(..))
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the variable at the root of the left-hand side of an assignment
+    /// (e.g. `state` in `state.accounts[2].balance`), which is the variable being mutated.
+    fn place_root<'a>(&self, expr: &'a Expr) -> Result<&'a Expr> {
+        match &expr.kind {
+            ExprKind::Variable { .. } => Ok(expr),
+            ExprKind::FieldAccess { lhs, .. } | ExprKind::TupleAccess { lhs, .. } => {
+                self.place_root(lhs)
+            }
+            ExprKind::ArrayAccess { array, .. } => self.place_root(array),
+            _ => Err(self.error(ErrorKind::InvalidAssignmentTarget, expr.span)),
+        }
+    }
+
+    /// Makes sure that an expression passed as a `mut` argument can be written back to:
+    /// a mutable variable, or a field, a tuple element, or an element at a constant index of one.
+    fn check_mutable_place(&self, expr: &Expr, typed_fn_env: &TypedFnEnv) -> Result<()> {
+        match &expr.kind {
+            ExprKind::Variable { module, name } => {
+                let qualified = FullyQualified::new(module, &name.value);
+                let mutable = self.const_info(&qualified).is_none()
+                    && typed_fn_env.mutable(&name.value).unwrap_or(false);
+                if !mutable {
+                    return Err(self.error(ErrorKind::ImmutableArgument, expr.span));
+                }
+                Ok(())
+            }
+            ExprKind::FieldAccess { lhs, .. } | ExprKind::TupleAccess { lhs, .. } => {
+                self.check_mutable_place(lhs, typed_fn_env)
+            }
+            ExprKind::ArrayAccess { array, idx } => {
+                if !self.is_constant_expr(idx, typed_fn_env) {
+                    return Err(self.error(ErrorKind::ImmutableArgument, expr.span));
+                }
+                self.check_mutable_place(array, typed_fn_env)
+            }
+            _ => Err(self.error(ErrorKind::ImmutableArgument, expr.span)),
+        }
+    }
+
+    /// Type checks the body of a function,
+    /// making sure that all paths return the expected type (if any).
     pub fn check_block(
         &mut self,
         typed_fn_env: &mut TypedFnEnv,
         stmts: &[Stmt],
         expected_return: Option<&Ty>,
     ) -> Result<()> {
+        let returns = self.check_scoped_block(typed_fn_env, stmts, expected_return)?;
+
+        if let Some(expected) = expected_return {
+            if !returns {
+                return Err(self.error(ErrorKind::MissingReturn, expected.span));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Type checks a block of statements in a new scope.
+    /// Returns true if all paths of the block return.
+    fn check_scoped_block(
+        &mut self,
+        typed_fn_env: &mut TypedFnEnv,
+        stmts: &[Stmt],
+        expected_return: Option<&Ty>,
+    ) -> Result<bool> {
         // enter the scope
         typed_fn_env.nest();
 
-        let mut return_typ = None;
+        let mut returns = false;
 
         for stmt in stmts {
-            if return_typ.is_some() {
-                panic!("early return detected: we don't allow that for now (TODO: return error");
+            if returns {
+                return Err(self.error(ErrorKind::UnreachableStatement, stmt.span));
             }
 
-            return_typ = self.check_stmt(typed_fn_env, stmt)?;
+            returns = self.check_stmt(typed_fn_env, stmt, expected_return)?;
         }
 
-        // check the return
-        match (expected_return, return_typ) {
-            (None, None) => (),
-            (Some(expected), None) => {
-                return Err(self.error(ErrorKind::MissingReturn, expected.span))
-            }
-            (None, Some(_)) => {
-                return Err(self.error(ErrorKind::NoReturnExpected, stmts.last().unwrap().span))
-            }
-            (Some(expected), Some(observed)) => {
-                if !observed.match_expected(&expected.kind) {
-                    return Err(self.error(
-                        ErrorKind::ReturnTypeMismatch(expected.kind.clone(), observed.clone()),
-                        expected.span,
-                    ));
-                }
-            }
-        };
-
         // exit the scope
         typed_fn_env.pop();
 
-        Ok(())
+        Ok(returns)
     }
 
+    /// Type checks a statement.
+    /// Returns true if all paths of the statement return.
     pub fn check_stmt(
         &mut self,
         typed_fn_env: &mut TypedFnEnv,
         stmt: &Stmt,
-    ) -> Result<Option<TyKind>> {
+        expected_return: Option<&Ty>,
+    ) -> Result<bool> {
         match &stmt.kind {
-            StmtKind::Assign { mutable, lhs, rhs } => {
+            StmtKind::Assign {
+                mutable,
+                lhs,
+                typ,
+                rhs,
+            } => {
                 // inferance can be easy: we can do it the Golang way and just use the type that rhs has (in `let` assignments)
 
                 // but first we need to compute the type of the rhs expression
-                let node = self.compute_type(rhs, typed_fn_env)?.unwrap();
+                let mut node = self.compute_type(rhs, typed_fn_env)?.unwrap();
+
+                // if the type is specified, the rhs must match it
+                if let Some(typ) = typ {
+                    // an integer literal can also be assigned to an integer it fits in
+                    let int_literal = match (&rhs.kind, typ.kind.int_bitlen()) {
+                        (ExprKind::BigUInt(value), Some(bitlen)) => {
+                            if value.bits() as usize > bitlen {
+                                return Err(
+                                    self.error(ErrorKind::IntegerOverflow(bitlen), rhs.span)
+                                );
+                            }
+                            true
+                        }
+                        _ => false,
+                    };
 
-                let type_info = if *mutable {
-                    TypeInfo::new_mut(node.typ, lhs.span)
-                } else {
-                    TypeInfo::new(node.typ, lhs.span)
+                    if !int_literal && !node.typ.match_expected(&typ.kind) {
+                        return Err(self.error(
+                            ErrorKind::MismatchType(typ.kind.clone(), node.typ),
+                            typ.span.merge_with(rhs.span),
+                        ));
+                    }
+
+                    node.typ = typ.kind.clone();
+                }
+
+                let type_info = |typ, span| {
+                    if *mutable {
+                        TypeInfo::new_mut(typ, span)
+                    } else {
+                        TypeInfo::new(typ, span)
+                    }
                 };
 
-                // store the type of lhs in the env
-                typed_fn_env.store_type(lhs.value.clone(), type_info)?;
+                match lhs {
+                    Pattern::Ident(lhs) => {
+                        // store the type of lhs in the env
+                        typed_fn_env
+                            .store_type(lhs.value.clone(), type_info(node.typ, lhs.span))?;
+                    }
+                    Pattern::Tuple(names) => {
+                        // the rhs must be a tuple with as many elements
+                        let typs = match node.typ {
+                            TyKind::Tuple(typs) if typs.len() == names.len() => typs,
+                            typ => {
+                                return Err(self.error(
+                                    ErrorKind::InvalidDestructuring(typ, names.len()),
+                                    stmt.span,
+                                ))
+                            }
+                        };
+
+                        // store the type of each element in the env
+                        for (name, typ) in names.iter().zip(typs) {
+                            typed_fn_env
+                                .store_type(name.value.clone(), type_info(typ, name.span))?;
+                        }
+                    }
+                }
             }
             StmtKind::ForLoop { var, range, body } => {
                 // enter a new scope
                 typed_fn_env.nest();
 
+                // the bounds must be known at compile time
+                for bound in [&range.start, &range.end] {
+                    let bound_node = self.compute_type(bound, typed_fn_env)?.ok_or_else(|| {
+                        self.error(ErrorKind::CannotComputeExpression, bound.span)
+                    })?;
+
+                    if !matches!(bound_node.typ, TyKind::BigInt) {
+                        return Err(self.error(
+                            ErrorKind::MismatchType(TyKind::Field, bound_node.typ),
+                            bound.span,
+                        ));
+                    }
+
+                    if !self.is_constant_expr(bound, typed_fn_env) {
+                        return Err(self.error(ErrorKind::NonConstantRangeBound, bound.span));
+                    }
+                }
+
                 // create var (for now it's always a bigint)
-                typed_fn_env
-                    .store_type(var.value.clone(), TypeInfo::new(TyKind::BigInt, var.span))?;
+                // (it is a constant, so it can be used in the bounds of nested loops)
+                typed_fn_env.store_type(
+                    var.value.clone(),
+                    TypeInfo::new_cst(TyKind::BigInt, var.span),
+                )?;
 
                 // ensure start..end makes sense
-                if range.end < range.start {
-                    panic!("end can't be smaller than start (TODO: better error)");
+                if let (ExprKind::BigUInt(start), ExprKind::BigUInt(end)) =
+                    (&range.start.kind, &range.end.kind)
+                {
+                    if end < start {
+                        panic!("end can't be smaller than start (TODO: better error)");
+                    }
                 }
 
                 // check block
-                self.check_block(typed_fn_env, body, None)?;
+                // (a loop might not run, so it doesn't count as returning)
+                self.check_scoped_block(typed_fn_env, body, expected_return)?;
 
                 // exit the scope
                 typed_fn_env.pop();
             }
+            StmtKind::ForIn {
+                index,
+                var,
+                array,
+                body,
+            } => {
+                // the loop iterates over the elements of an array
+                let array_node = self
+                    .compute_type(array, typed_fn_env)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, array.span))?;
+
+                let elem_typ = match array_node.typ {
+                    TyKind::Array(typ, _) | TyKind::GenericSizedArray(typ, _) => *typ,
+                    typ => return Err(self.error(ErrorKind::IterationOnNonArray(typ), array.span)),
+                };
+
+                // enter a new scope
+                typed_fn_env.nest();
+
+                // the index is a constant, like the variable of a loop over a range
+                if let Some(index) = index {
+                    typed_fn_env.store_type(
+                        index.value.clone(),
+                        TypeInfo::new_cst(TyKind::BigInt, index.span),
+                    )?;
+                }
+
+                // the element is a copy of the array's element
+                typed_fn_env.store_type(var.value.clone(), TypeInfo::new(elem_typ, var.span))?;
+
+                // check block
+                // (a loop might not run, so it doesn't count as returning)
+                self.check_scoped_block(typed_fn_env, body, expected_return)?;
+
+                // exit the scope
+                typed_fn_env.pop();
+            }
+            StmtKind::IfElse { cond, then_, else_ } => {
+                // cond can only be a boolean
+                let cond_node = self
+                    .compute_type(cond, typed_fn_env)?
+                    .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, cond.span))?;
+                if !matches!(cond_node.typ, TyKind::Bool) {
+                    return Err(self.error(
+                        ErrorKind::IfElseInvalidConditionType(cond_node.typ),
+                        cond.span,
+                    ));
+                }
+
+                // each branch is checked in its own scope
+                let then_returns = self.check_scoped_block(typed_fn_env, then_, expected_return)?;
+                let else_returns = self.check_scoped_block(typed_fn_env, else_, expected_return)?;
+
+                return Ok(then_returns && else_returns);
+            }
             StmtKind::Expr(expr) => {
                 // make sure the expression does not return any type
                 // (it's a statement expression, it should only work via side effect)
@@ -606,12 +1336,81 @@ impl<B: Backend> TypeChecker<B> {
             StmtKind::Return(res) => {
                 let node = self.compute_type(res, typed_fn_env)?.unwrap();
 
-                return Ok(Some(node.typ));
+                // check the returned type
+                match expected_return {
+                    None => return Err(self.error(ErrorKind::NoReturnExpected, stmt.span)),
+                    Some(expected) => {
+                        if !node.typ.match_expected(&expected.kind) {
+                            return Err(self.error(
+                                ErrorKind::ReturnTypeMismatch(expected.kind.clone(), node.typ),
+                                expected.span,
+                            ));
+                        }
+                    }
+                }
+
+                return Ok(true);
             }
             StmtKind::Comment(_) => (),
         }
 
-        Ok(None)
+        Ok(false)
+    }
+
+    /// Type checks a call to the `log` builtin, which takes a single value of any type.
+    fn check_log_call(
+        &mut self,
+        typed_fn_env: &mut TypedFnEnv,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<()> {
+        // hint functions are run out of circuit, so they can only call other hint functions
+        if typed_fn_env.is_hint() {
+            return Err(self.error(ErrorKind::ConstraintInHint("log".to_string()), span));
+        }
+
+        if args.len() != 1 {
+            return Err(self.error(ErrorKind::MismatchFunctionArguments(args.len(), 1), span));
+        }
+
+        if self.compute_type(&args[0], typed_fn_env)?.is_none() {
+            return Err(self.error(ErrorKind::CannotComputeExpression, args[0].span));
+        }
+
+        Ok(())
+    }
+
+    /// Type checks a call to `concat`, and returns the type of the resulting array.
+    fn check_concat_call(
+        &mut self,
+        typed_fn_env: &mut TypedFnEnv,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<TyKind> {
+        if args.len() != 2 {
+            return Err(self.error(ErrorKind::MismatchFunctionArguments(args.len(), 2), span));
+        }
+
+        let mut arrays = Vec::with_capacity(2);
+        for arg in args {
+            let typ = self
+                .compute_type(arg, typed_fn_env)?
+                .ok_or_else(|| self.error(ErrorKind::CannotComputeExpression, arg.span))?;
+
+            match typ.typ {
+                TyKind::Array(el_typ, len) => arrays.push((*el_typ, len)),
+                typ => return Err(self.error(ErrorKind::InvalidConcatArgument(typ), arg.span)),
+            }
+        }
+
+        let (rhs_typ, rhs_len) = arrays.pop().unwrap();
+        let (lhs_typ, lhs_len) = arrays.pop().unwrap();
+
+        if !rhs_typ.same_as(&lhs_typ) {
+            return Err(self.error(ErrorKind::MismatchType(lhs_typ, rhs_typ), args[1].span));
+        }
+
+        Ok(TyKind::Array(Box::new(lhs_typ), lhs_len + rhs_len))
     }
 
     /// type checks a function call.
@@ -624,6 +1423,11 @@ impl<B: Backend> TypeChecker<B> {
         args: &[Expr],
         span: Span,
     ) -> Result<Option<TyKind>> {
+        // hint functions are run out of circuit, so they can only call other hint functions
+        if typed_fn_env.is_hint() && !fn_sig.hint {
+            return Err(self.error(ErrorKind::ConstraintInHint(fn_sig.name.value), span));
+        }
+
         // check if a function names is in use already by another variable
         match typed_fn_env.get_type_info(&fn_sig.name.value) {
             Some(_) => {
@@ -664,17 +1468,80 @@ impl<B: Backend> TypeChecker<B> {
             ));
         }
 
+        // infer the generic array sizes from the arguments
+        let mut generic_sizes = HashMap::new();
+        for (sig_arg, (typ, span)) in expected.iter().zip(&observed) {
+            sig_arg
+                .typ
+                .kind
+                .bind_generic_sizes(typ, &mut generic_sizes)
+                .map_err(|generic| self.error(ErrorKind::ConflictingGenericSize(generic), *span))?;
+        }
+
+        // infer the generic types from the arguments,
+        // and check that they implement the traits they are bound to
+        let mut generic_types = HashMap::new();
+        for (sig_arg, (typ, span)) in expected.iter().zip(&observed) {
+            sig_arg
+                .typ
+                .kind
+                .bind_generic_types(typ, &mut generic_types)
+                .map_err(|generic| self.error(ErrorKind::ConflictingGenericType(generic), *span))?;
+        }
+
+        for type_param in &fn_sig.type_params {
+            if let Some(typ) = generic_types.get(&type_param.name.value) {
+                let bound = FullyQualified::new(&type_param.bound.module, &type_param.bound.name);
+                if !self.implements(typ, &bound) {
+                    return Err(self.error(
+                        ErrorKind::TraitNotImplemented(typ.clone(), type_param.bound.name.clone()),
+                        span,
+                    ));
+                }
+            }
+        }
+
         // compare argument types with the function signature
         for (sig_arg, (typ, span)) in expected.iter().zip(observed) {
-            if !typ.match_expected(&sig_arg.typ.kind) {
-                return Err(self.error(
-                    ErrorKind::ArgumentTypeMismatch(sig_arg.typ.kind.clone(), typ),
-                    span,
-                ));
+            let expected_typ = sig_arg
+                .typ
+                .kind
+                .resolve_generic_sizes(&generic_sizes)
+                .resolve_generic_types(&generic_types);
+            if !typ.match_expected(&expected_typ) {
+                return Err(self.error(ErrorKind::ArgumentTypeMismatch(expected_typ, typ), span));
+            }
+        }
+
+        // the values of `mut` arguments are written back, so they must be mutable places
+        for (sig_arg, arg) in expected.iter().zip(args) {
+            if sig_arg.is_mutable() {
+                self.check_mutable_place(arg, typed_fn_env)?;
             }
         }
 
         // return the return type of the function
-        Ok(fn_sig.return_type.as_ref().map(|ty| ty.kind.clone()))
+        Ok(fn_sig.return_type.as_ref().map(|ty| {
+            ty.kind
+                .resolve_generic_sizes(&generic_sizes)
+                .resolve_generic_types(&generic_types)
+        }))
+    }
+
+    /// Returns whether a type implements a trait.
+    fn implements(&self, typ: &TyKind, bound: &FullyQualified) -> bool {
+        match typ {
+            TyKind::Custom { module, name, .. } => self
+                .struct_info(&FullyQualified::new(module, name))
+                .map(|struct_info| struct_info.traits.contains(bound))
+                .unwrap_or(false),
+            // (when a generic function calls another one)
+            TyKind::Generic {
+                trait_module,
+                trait_name,
+                ..
+            } => &FullyQualified::new(trait_module, trait_name) == bound,
+            _ => false,
+        }
     }
 }
@@ -0,0 +1,322 @@
+//! Evaluation of constant declarations.
+//!
+//! The value of a `const` is computed at compile time,
+//! and is stored in a [ConstInfo] as the list of field elements it is made of
+//! (following the same layout as variables, see [TypeChecker::size_of]).
+//! The same evaluation is used for the array sizes and slice bounds that must be known at compile time.
+
+use std::collections::HashMap;
+
+use ark_ff::{Field, One};
+use num_bigint::BigUint;
+
+use crate::{
+    backends::Backend,
+    constants::Span,
+    error::{ErrorKind, Result},
+    parser::{
+        types::{ConstDef, Ty, TyKind},
+        CustomType, Expr, ExprKind, Op2,
+    },
+};
+
+use super::{ConstInfo, FullyQualified, TypeChecker};
+
+impl<B: Backend> TypeChecker<B> {
+    /// Evaluates a constant declaration,
+    /// and checks its value against the type it was declared with (if any).
+    pub(crate) fn eval_const_def(&self, cst: &ConstDef) -> Result<ConstInfo<B::Field>> {
+        let (typ, value) = self.eval_const(&cst.value)?;
+
+        let typ = match &cst.typ {
+            Some(expected) => {
+                self.check_const_type(&typ, &value, &expected.kind, cst.value.span)?;
+                expected.clone()
+            }
+            // literals are field elements by default
+            None => Ty {
+                kind: default_literals(typ),
+                span: cst.span,
+            },
+        };
+
+        Ok(ConstInfo { value, typ })
+    }
+
+    /// Computes the type and the value of a constant expression.
+    /// Numeric literals are typed as [TyKind::BigInt] until they are checked against a type.
+    fn eval_const(&self, expr: &Expr) -> Result<(TyKind, Vec<B::Field>)> {
+        match &expr.kind {
+            ExprKind::BigUInt(b) => {
+                let ff = B::Field::try_from(b.to_owned()).map_err(|_| {
+                    self.error(ErrorKind::CannotConvertToField(b.to_string()), expr.span)
+                })?;
+                Ok((TyKind::BigInt, vec![ff]))
+            }
+
+            ExprKind::Bool(b) => Ok((TyKind::Bool, vec![bool_to_field(*b)])),
+
+            ExprKind::ByteString(bytes) => {
+                let value = bytes
+                    .iter()
+                    .map(|byte| B::Field::from(*byte as u64))
+                    .collect();
+                Ok((
+                    TyKind::Array(Box::new(TyKind::Field), bytes.len() as u32),
+                    value,
+                ))
+            }
+
+            // constants can only refer to constants declared before them
+            ExprKind::Variable { module, name } => {
+                let qualified = FullyQualified::new(module, &name.value);
+                let cst = self
+                    .const_info(&qualified)
+                    .ok_or_else(|| self.error(ErrorKind::InvalidConstType, expr.span))?;
+
+                let typ = if cst.typ.kind == TyKind::Field {
+                    TyKind::BigInt
+                } else {
+                    cst.typ.kind.clone()
+                };
+                Ok((typ, cst.value.clone()))
+            }
+
+            ExprKind::Negated(inner) => {
+                let value = self.eval_const_field(inner)?;
+                Ok((TyKind::BigInt, vec![-value]))
+            }
+
+            ExprKind::Not(inner) => {
+                let value = self.eval_const_bool(inner)?;
+                Ok((TyKind::Bool, vec![bool_to_field(!value)]))
+            }
+
+            ExprKind::BinaryOp { op, lhs, rhs, .. } => match op {
+                Op2::Addition | Op2::Subtraction | Op2::Multiplication | Op2::Division => {
+                    let lhs = self.eval_const_field(lhs)?;
+                    let rhs = self.eval_const_field(rhs)?;
+
+                    let res = match op {
+                        Op2::Addition => lhs + rhs,
+                        Op2::Subtraction => lhs - rhs,
+                        Op2::Multiplication => lhs * rhs,
+                        Op2::Division => {
+                            let inv = rhs
+                                .inverse()
+                                .ok_or_else(|| self.error(ErrorKind::DivisionByZero, expr.span))?;
+                            lhs * inv
+                        }
+                        _ => unreachable!(),
+                    };
+                    Ok((TyKind::BigInt, vec![res]))
+                }
+
+                Op2::BoolAnd | Op2::BoolOr => {
+                    let lhs = self.eval_const_bool(lhs)?;
+                    let rhs = self.eval_const_bool(rhs)?;
+
+                    let res = match op {
+                        Op2::BoolAnd => lhs && rhs,
+                        Op2::BoolOr => lhs || rhs,
+                        _ => unreachable!(),
+                    };
+                    Ok((TyKind::Bool, vec![bool_to_field(res)]))
+                }
+
+                Op2::Equality | Op2::Inequality => {
+                    let (lhs_typ, lhs) = self.eval_const(lhs)?;
+                    let (rhs_typ, rhs) = self.eval_const(rhs)?;
+
+                    if !lhs_typ.same_as(&rhs_typ) {
+                        return Err(
+                            self.error(ErrorKind::MismatchType(lhs_typ, rhs_typ), expr.span)
+                        );
+                    }
+
+                    let equal = lhs == rhs;
+                    let res = if matches!(op, Op2::Equality) {
+                        equal
+                    } else {
+                        !equal
+                    };
+                    Ok((TyKind::Bool, vec![bool_to_field(res)]))
+                }
+
+                _ => Err(self.error(ErrorKind::InvalidConstType, expr.span)),
+            },
+
+            // lookup tables, round constants, etc.
+            ExprKind::ArrayDeclaration(items) => {
+                let mut elem_typ = None;
+                let mut value = vec![];
+
+                for item in items {
+                    let (typ, item_value) = self.eval_const(item)?;
+
+                    match &elem_typ {
+                        None => elem_typ = Some(typ),
+                        Some(expected) => {
+                            if !typ.same_as(expected) {
+                                return Err(self.error(
+                                    ErrorKind::MismatchType(expected.clone(), typ),
+                                    item.span,
+                                ));
+                            }
+                        }
+                    }
+
+                    value.extend(item_value);
+                }
+
+                let elem_typ =
+                    elem_typ.ok_or_else(|| self.error(ErrorKind::InvalidConstType, expr.span))?;
+                Ok((TyKind::Array(Box::new(elem_typ), items.len() as u32), value))
+            }
+
+            ExprKind::RepeatedArrayDeclaration { item, size } => {
+                let (elem_typ, item_value) = self.eval_const(item)?;
+                let size = self.eval_const_size(size)?;
+                let value = item_value.repeat(size as usize);
+                Ok((TyKind::Array(Box::new(elem_typ), size), value))
+            }
+
+            ExprKind::CustomTypeDeclaration { custom, fields } => {
+                let CustomType { module, name, .. } = custom;
+                let qualified = FullyQualified::new(module, name);
+                let struct_info = self.struct_info(&qualified).ok_or_else(|| {
+                    self.error(ErrorKind::UndefinedStruct(name.clone()), expr.span)
+                })?;
+
+                if struct_info.fields.len() != fields.len() {
+                    return Err(
+                        self.error(ErrorKind::MismatchStructFields(name.clone()), expr.span)
+                    );
+                }
+
+                // the generic array sizes of the struct are inferred from its fields
+                let mut bindings = HashMap::new();
+                let mut value = vec![];
+                for ((defined_name, defined_typ), (observed_name, observed)) in
+                    struct_info.fields.iter().zip(fields)
+                {
+                    if defined_name != &observed_name.value {
+                        return Err(self.error(
+                            ErrorKind::InvalidStructField(
+                                defined_name.clone(),
+                                observed_name.value.clone(),
+                            ),
+                            expr.span,
+                        ));
+                    }
+
+                    let (typ, field_value) = self.eval_const(observed)?;
+                    defined_typ
+                        .bind_generic_sizes(&typ, &mut bindings)
+                        .map_err(|generic| {
+                            self.error(ErrorKind::ConflictingGenericSize(generic), observed.span)
+                        })?;
+                    let defined_typ = defined_typ.resolve_generic_sizes(&bindings);
+                    self.check_const_type(&typ, &field_value, &defined_typ, observed.span)?;
+                    value.extend(field_value);
+                }
+
+                let typ = TyKind::Custom {
+                    module: module.clone(),
+                    name: name.clone(),
+                    sizes: struct_info
+                        .generics
+                        .iter()
+                        .map(|generic| bindings[generic].clone())
+                        .collect(),
+                };
+                Ok((typ, value))
+            }
+
+            _ => Err(self.error(ErrorKind::InvalidConstType, expr.span)),
+        }
+    }
+
+    /// Evaluates an array size or a slice bound, which must be known at compile time.
+    pub(crate) fn eval_const_size(&self, expr: &Expr) -> Result<u32> {
+        let value: BigUint = self
+            .eval_const_field(expr)
+            .map_err(|_| self.error(ErrorKind::NonConstantArraySize, expr.span))?
+            .into();
+
+        u32::try_from(value).map_err(|_| self.error(ErrorKind::InvalidArraySize, expr.span))
+    }
+
+    /// Evaluates a constant expression that must be a field element.
+    fn eval_const_field(&self, expr: &Expr) -> Result<B::Field> {
+        let (typ, value) = self.eval_const(expr)?;
+        if !typ.match_expected(&TyKind::Field) {
+            return Err(self.error(ErrorKind::MismatchType(TyKind::Field, typ), expr.span));
+        }
+        Ok(value[0])
+    }
+
+    /// Evaluates a constant expression that must be a boolean.
+    fn eval_const_bool(&self, expr: &Expr) -> Result<bool> {
+        let (typ, value) = self.eval_const(expr)?;
+        if typ != TyKind::Bool {
+            return Err(self.error(ErrorKind::MismatchType(TyKind::Bool, typ), expr.span));
+        }
+        Ok(value[0].is_one())
+    }
+
+    /// Checks that a constant value of type `observed` can be used as the `expected` type.
+    /// Unlike with variables, literals can be used as integers, as long as they fit.
+    fn check_const_type(
+        &self,
+        observed: &TyKind,
+        value: &[B::Field],
+        expected: &TyKind,
+        span: Span,
+    ) -> Result<()> {
+        match (observed, expected) {
+            (TyKind::BigInt, expected) if expected.int_bitlen().is_some() => {
+                let bitlen = expected.int_bitlen().unwrap();
+                let int: BigUint = value[0].into();
+                if int.bits() as usize > bitlen {
+                    return Err(self.error(ErrorKind::IntegerOverflow(bitlen), span));
+                }
+                Ok(())
+            }
+
+            (TyKind::Array(observed_elem, observed_len), TyKind::Array(expected_elem, len))
+                if observed_len == len =>
+            {
+                let elem_size = self.size_of(expected_elem);
+                for elem in value.chunks(elem_size) {
+                    self.check_const_type(observed_elem, elem, expected_elem, span)?;
+                }
+                Ok(())
+            }
+
+            _ if observed.match_expected(expected) => Ok(()),
+
+            _ => Err(self.error(
+                ErrorKind::MismatchType(expected.clone(), observed.clone()),
+                span,
+            )),
+        }
+    }
+}
+
+/// Types the numeric literals of a constant, whose type wasn't specified, as field elements.
+fn default_literals(typ: TyKind) -> TyKind {
+    match typ {
+        TyKind::BigInt => TyKind::Field,
+        TyKind::Array(elem, len) => TyKind::Array(Box::new(default_literals(*elem)), len),
+        typ => typ,
+    }
+}
+
+fn bool_to_field<F: Field>(b: bool) -> F {
+    if b {
+        F::one()
+    } else {
+        F::zero()
+    }
+}
@@ -65,6 +65,10 @@ pub struct TypedFnEnv {
     /// This needs to be garbage collected when we exit a scope.
     // TODO: there's an output_type field that's a reserved keyword?
     vars: HashMap<String, (usize, TypeInfo)>,
+
+    /// Set when type checking the body of a hint function,
+    /// which is only run at witness generation time and can't create constraints.
+    hint: bool,
 }
 
 impl TypedFnEnv {
@@ -73,6 +77,19 @@ impl TypedFnEnv {
         Self::default()
     }
 
+    /// Creates a new TypeEnv for the body of a hint function.
+    pub fn new_hint() -> Self {
+        Self {
+            hint: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if we're type checking the body of a hint function.
+    pub fn is_hint(&self) -> bool {
+        self.hint
+    }
+
     /// Enters a scoped block.
     pub fn nest(&mut self) {
         self.current_scope += 1;
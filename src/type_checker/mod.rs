@@ -8,20 +8,21 @@ use crate::{
     imports::FnKind,
     name_resolution::NAST,
     parser::{
-        types::{FuncOrMethod, FunctionDef, ModulePath, RootKind, Ty, TyKind},
-        CustomType, Expr, StructDef,
+        types::{ArraySize, FnSig, FuncOrMethod, FunctionDef, ModulePath, RootKind, Ty, TyKind},
+        CustomType, EnumDef, Expr, ImplDef, StructDef, TraitDef, TypeAliasDef,
     },
     stdlib::{builtin_fns, crypto::crypto_fns, QUALIFIED_BUILTINS},
 };
 
 use ark_ff::Field;
-pub use checker::{FnInfo, StructInfo};
+pub use checker::{EnumInfo, FnInfo, StructInfo, TraitInfo};
 pub use fn_env::{TypeInfo, TypedFnEnv};
 
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 pub mod checker;
+pub mod consts;
 pub mod fn_env;
 
 const RESERVED_ARGS: [&str; 1] = ["public_output"];
@@ -75,9 +76,19 @@ where
     /// Custom structs type information and ASTs for methods.
     structs: HashMap<FullyQualified, StructInfo>,
 
+    /// Custom enums type information.
+    enums: HashMap<FullyQualified, EnumInfo>,
+
+    /// Traits and the signatures of their methods.
+    traits: HashMap<FullyQualified, TraitInfo>,
+
     /// Constants declared in this module.
     constants: HashMap<FullyQualified, ConstInfo<B::Field>>,
 
+    /// Type aliases, and the type they stand for.
+    /// They are replaced during name resolution, but are kept to resolve the modules importing them.
+    type_aliases: HashMap<FullyQualified, TyKind>,
+
     /// Mapping from node id to TyKind.
     /// This can be used by the circuit-writer when it needs type information.
     // TODO: I think we should get rid of this if we can
@@ -98,6 +109,14 @@ impl<B: Backend> TypeChecker<B> {
         self.structs.get(qualified)
     }
 
+    pub(crate) fn enum_info(&self, qualified: &FullyQualified) -> Option<&EnumInfo> {
+        self.enums.get(qualified)
+    }
+
+    pub(crate) fn trait_info(&self, qualified: &FullyQualified) -> Option<&TraitInfo> {
+        self.traits.get(qualified)
+    }
+
     pub(crate) fn fn_info(&self, qualified: &FullyQualified) -> Option<&FnInfo<B>> {
         self.functions.get(qualified)
     }
@@ -106,20 +125,41 @@ impl<B: Backend> TypeChecker<B> {
         self.constants.get(&qualified)
     }
 
+    pub(crate) fn type_aliases(&self) -> &HashMap<FullyQualified, TyKind> {
+        &self.type_aliases
+    }
+
     /// Returns the number of field elements contained in the given type.
     // TODO: might want to memoize that at some point
     pub(crate) fn size_of(&self, typ: &TyKind) -> usize {
         match typ {
             TyKind::Field => 1,
-            TyKind::Custom { module, name } => {
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => {
                 let qualified = FullyQualified::new(&module, &name);
+
+                // an enum is laid out as its tag, followed by the values held by its largest variant
+                if let Some(enum_info) = self.enum_info(&qualified) {
+                    let payload_size = enum_info
+                        .variants
+                        .iter()
+                        .map(|(_, payload)| payload.iter().map(|t| self.size_of(t)).sum::<usize>())
+                        .max()
+                        .unwrap_or(0);
+
+                    return 1 + payload_size;
+                }
+
                 let struct_info = self
                     .struct_info(&qualified)
                     .expect("bug in the type checker: cannot find struct info");
 
                 let mut sum = 0;
 
-                for (_, t) in &struct_info.fields {
+                for (_, t) in &struct_info.fields_of(sizes) {
                     sum += self.size_of(t);
                 }
 
@@ -127,7 +167,15 @@ impl<B: Backend> TypeChecker<B> {
             }
             TyKind::BigInt => 1,
             TyKind::Array(typ, len) => (*len as usize) * self.size_of(typ),
+            TyKind::GenericSizedArray(..) => {
+                unreachable!("generic array sizes must be resolved before computing sizes")
+            }
             TyKind::Bool => 1,
+            TyKind::U8 | TyKind::U16 | TyKind::U32 | TyKind::U64 => 1,
+            TyKind::Tuple(typs) => typs.iter().map(|typ| self.size_of(typ)).sum(),
+            TyKind::Generic { .. } => {
+                unreachable!("generic types must be resolved before computing sizes")
+            }
         }
     }
 }
@@ -138,7 +186,10 @@ impl<B: Backend> TypeChecker<B> {
         let mut type_checker = Self {
             functions: HashMap::new(),
             structs: HashMap::new(),
+            enums: HashMap::new(),
+            traits: HashMap::new(),
             constants: HashMap::new(),
+            type_aliases: HashMap::new(),
             node_types: HashMap::new(),
         };
 
@@ -179,18 +230,200 @@ impl<B: Backend> TypeChecker<B> {
     /// This takes the AST produced by the parser, and performs two things:
     /// - resolves imports
     /// - type checks
-    pub fn analyze(&mut self, nast: NAST<B>, is_lib: bool) -> Result<()> {
+    pub fn analyze(&mut self, nast: NAST, is_lib: bool) -> Result<()> {
+        //
+        // Type check structs
+        //
+
+        for root in &nast.ast.0 {
+            match &root.kind {
+                // `use user::repo;`
+                RootKind::StructDef(struct_def) => {
+                    let StructDef {
+                        module,
+                        name,
+                        fields,
+                        ..
+                    } = struct_def;
+
+                    // the generic array sizes of the struct are given by its instances
+                    // (e.g. `Sudoku<81>`), in the order in which they appear in its fields
+                    let mut generics = vec![];
+                    for generic in fields.iter().flat_map(|(_, typ)| typ.kind.generic_sizes()) {
+                        if !generics.contains(&generic) {
+                            generics.push(generic);
+                        }
+                    }
+
+                    let fields: Vec<_> = fields
+                        .iter()
+                        .map(|field| {
+                            let (name, typ) = field;
+                            (name.value.clone(), typ.kind.clone())
+                        })
+                        .collect();
+
+                    let struct_info = StructInfo {
+                        name: name.name.clone(),
+                        fields,
+                        methods: HashMap::new(),
+                        traits: vec![],
+                        generics,
+                    };
+
+                    let qualified = FullyQualified::new(module, &name.name);
+                    self.structs.insert(qualified, struct_info);
+                }
+
+                // `enum Foo { A(Field), B }`
+                RootKind::EnumDef(enum_def) => {
+                    let EnumDef {
+                        module,
+                        name,
+                        variants,
+                        ..
+                    } = enum_def;
+
+                    // unlike structs, enums can't have generic array sizes
+                    for typ in variants.iter().flat_map(|(_, payload)| payload) {
+                        if let Some(generic) = typ.kind.generic_sizes().into_iter().next() {
+                            return Err(Error::new(
+                                "type-checker",
+                                ErrorKind::GenericInEnum(generic),
+                                typ.span,
+                            ));
+                        }
+                    }
+
+                    let mut enum_info = EnumInfo {
+                        name: name.name.clone(),
+                        variants: vec![],
+                    };
+
+                    for (variant, payload) in variants {
+                        if enum_info.variant(&variant.value).is_some() {
+                            return Err(Error::new(
+                                "type-checker",
+                                ErrorKind::DuplicateDefinition(variant.value.clone()),
+                                variant.span,
+                            ));
+                        }
+
+                        let payload = payload.iter().map(|typ| typ.kind.clone()).collect();
+                        enum_info.variants.push((variant.value.clone(), payload));
+                    }
+
+                    // an enum can't share its name with a struct
+                    let qualified = FullyQualified::new(module, &name.name);
+                    if self.structs.contains_key(&qualified)
+                        || self.enums.insert(qualified, enum_info).is_some()
+                    {
+                        return Err(Error::new(
+                            "type-checker",
+                            ErrorKind::DuplicateDefinition(name.name.clone()),
+                            name.span,
+                        ));
+                    }
+                }
+
+                // `trait Hashable { fn hash(self) -> Field; }`
+                RootKind::TraitDef(trait_def) => {
+                    let TraitDef {
+                        module,
+                        name,
+                        methods,
+                        ..
+                    } = trait_def;
+
+                    let mut trait_info = TraitInfo {
+                        name: name.name.clone(),
+                        methods: vec![],
+                    };
+
+                    for sig in methods {
+                        if trait_info.method(&sig.name.value).is_some() {
+                            return Err(Error::new(
+                                "type-checker",
+                                ErrorKind::DuplicateDefinition(sig.name.value.clone()),
+                                sig.name.span,
+                            ));
+                        }
+
+                        trait_info.methods.push(sig.clone());
+                    }
+
+                    let qualified = FullyQualified::new(module, &name.name);
+                    if self.traits.insert(qualified, trait_info).is_some() {
+                        return Err(Error::new(
+                            "type-checker",
+                            ErrorKind::DuplicateDefinition(name.name.clone()),
+                            name.span,
+                        ));
+                    }
+                }
+
+                // `type Path = [Field; 16];`
+                RootKind::TypeAliasDef(alias_def) => {
+                    let TypeAliasDef {
+                        module, name, typ, ..
+                    } = alias_def;
+
+                    // an alias can't share its name with a struct or an enum
+                    let qualified = FullyQualified::new(module, &name.name);
+                    if self.structs.contains_key(&qualified)
+                        || self.enums.contains_key(&qualified)
+                        || self
+                            .type_aliases
+                            .insert(qualified, typ.kind.clone())
+                            .is_some()
+                    {
+                        return Err(Error::new(
+                            "type-checker",
+                            ErrorKind::DuplicateDefinition(name.name.clone()),
+                            name.span,
+                        ));
+                    }
+                }
+
+                RootKind::ConstDef(_)
+                | RootKind::Use(_)
+                | RootKind::FunctionDef(_)
+                | RootKind::ImplDef(_)
+                | RootKind::Comment(_) => (),
+            }
+        }
+
+        // the structs used by other structs and enums must be given their generic array sizes
+        for root in &nast.ast.0 {
+            let typs: Vec<&Ty> = match &root.kind {
+                RootKind::StructDef(struct_def) => {
+                    struct_def.fields.iter().map(|(_, typ)| typ).collect()
+                }
+                RootKind::EnumDef(enum_def) => enum_def
+                    .variants
+                    .iter()
+                    .flat_map(|(_, payload)| payload)
+                    .collect(),
+                _ => continue,
+            };
+
+            for typ in typs {
+                self.check_struct_sizes(&typ.kind, typ.span)?;
+            }
+        }
+
         //
         // Process constants
+        // (after structs and enums, which constants can be made of)
         //
 
-        // we detect struct or function definition
+        // we detect function definitions
         let mut abort = None;
 
         for root in &nast.ast.0 {
             match &root.kind {
                 RootKind::ConstDef(cst) => {
-                    // important: no struct or function definition must appear before a constant declaration
+                    // important: no function definition must appear before a constant declaration
                     if let Some(span) = abort {
                         return Err(Error::new(
                             "type-checker",
@@ -200,21 +433,9 @@ impl<B: Backend> TypeChecker<B> {
                     }
 
                     let qualified = FullyQualified::new(&cst.module, &cst.name.value);
+                    let const_info = self.eval_const_def(cst)?;
 
-                    if self
-                        .constants
-                        .insert(
-                            qualified,
-                            ConstInfo {
-                                value: vec![cst.value],
-                                typ: Ty {
-                                    kind: TyKind::Field,
-                                    span: cst.span,
-                                },
-                            },
-                        )
-                        .is_some()
-                    {
+                    if self.constants.insert(qualified, const_info).is_some() {
                         return Err(Error::new(
                             "type-checker",
                             ErrorKind::DuplicateDefinition(cst.name.value.clone()),
@@ -224,48 +445,128 @@ impl<B: Backend> TypeChecker<B> {
                 }
 
                 RootKind::FunctionDef(FunctionDef { span, .. })
-                | RootKind::StructDef(StructDef { span, .. }) => abort = Some(*span),
+                | RootKind::ImplDef(ImplDef { span, .. }) => abort = Some(*span),
 
-                RootKind::Use(_) | RootKind::Comment(_) => (),
+                RootKind::Use(_)
+                | RootKind::StructDef(_)
+                | RootKind::EnumDef(_)
+                | RootKind::TraitDef(_)
+                | RootKind::TypeAliasDef(_)
+                | RootKind::Comment(_) => (),
             }
         }
 
         //
-        // Type check structs
+        // Register trait implementations
+        // (before type checking functions, which might rely on them through generic types)
         //
 
         for root in &nast.ast.0 {
             match &root.kind {
-                // `use user::repo;`
-                RootKind::StructDef(struct_def) => {
-                    let StructDef {
-                        module,
-                        name,
-                        fields,
-                        ..
-                    } = struct_def;
+                // `impl Hashable for Foo { ... }`
+                RootKind::ImplDef(impl_def) => {
+                    let ImplDef {
+                        trait_name,
+                        custom,
+                        methods,
+                        span,
+                    } = impl_def;
+
+                    let trait_qualified = FullyQualified::new(&trait_name.module, &trait_name.name);
+                    let trait_info = self
+                        .traits
+                        .get(&trait_qualified)
+                        .ok_or_else(|| {
+                            Error::new(
+                                "type-checker",
+                                ErrorKind::UndefinedTrait(trait_name.name.clone()),
+                                trait_name.span,
+                            )
+                        })?
+                        .clone();
+
+                    let struct_qualified = FullyQualified::new(&custom.module, &custom.name);
+                    let struct_info = self.structs.get_mut(&struct_qualified).ok_or_else(|| {
+                        Error::new(
+                            "type-checker",
+                            ErrorKind::UndefinedStruct(custom.name.clone()),
+                            custom.span,
+                        )
+                    })?;
 
-                    let fields: Vec<_> = fields
-                        .iter()
-                        .map(|field| {
-                            let (name, typ) = field;
-                            (name.value.clone(), typ.kind.clone())
-                        })
-                        .collect();
+                    if struct_info.traits.contains(&trait_qualified) {
+                        return Err(Error::new(
+                            "type-checker",
+                            ErrorKind::DuplicateTraitImpl(
+                                trait_name.name.clone(),
+                                custom.name.clone(),
+                            ),
+                            *span,
+                        ));
+                    }
 
-                    let struct_info = StructInfo {
-                        name: name.name.clone(),
-                        fields,
-                        methods: HashMap::new(),
-                    };
+                    // the methods must be the ones declared by the trait
+                    for method in methods {
+                        let method_name = &method.sig.name;
+                        let expected = trait_info.method(&method_name.value).ok_or_else(|| {
+                            Error::new(
+                                "type-checker",
+                                ErrorKind::MethodNotInTrait(
+                                    method_name.value.clone(),
+                                    trait_name.name.clone(),
+                                ),
+                                method_name.span,
+                            )
+                        })?;
+
+                        if !same_method_sig(expected, &method.sig) {
+                            return Err(Error::new(
+                                "type-checker",
+                                ErrorKind::MismatchTraitMethod(method_name.value.clone()),
+                                method.span,
+                            ));
+                        }
 
-                    let qualified = FullyQualified::new(module, &name.name);
-                    self.structs.insert(qualified, struct_info);
+                        if struct_info
+                            .methods
+                            .insert(
+                                method_name.value.clone(),
+                                with_self_sizes(method.clone(), struct_info),
+                            )
+                            .is_some()
+                        {
+                            return Err(Error::new(
+                                "type-checker",
+                                ErrorKind::DuplicateDefinition(method_name.value.clone()),
+                                method_name.span,
+                            ));
+                        }
+                    }
+
+                    // and all of them must be implemented
+                    for sig in &trait_info.methods {
+                        if !methods.iter().any(|method| method.sig.name == sig.name) {
+                            return Err(Error::new(
+                                "type-checker",
+                                ErrorKind::MissingTraitMethod(
+                                    sig.name.value.clone(),
+                                    trait_name.name.clone(),
+                                ),
+                                *span,
+                            ));
+                        }
+                    }
+
+                    struct_info.traits.push(trait_qualified);
                 }
 
                 RootKind::ConstDef(_)
                 | RootKind::Use(_)
                 | RootKind::FunctionDef(_)
+                | RootKind::StructDef(_)
+                | RootKind::EnumDef(_)
+                | RootKind::TraitDef(_)
+                | RootKind::TypeAliasDef(_)
                 | RootKind::Comment(_) => (),
             }
         }
@@ -278,12 +579,8 @@ impl<B: Backend> TypeChecker<B> {
             match &root.kind {
                 // `fn main() { ... }`
                 RootKind::FunctionDef(function) => {
-                    // create a new typed fn environment to type check the function
-                    let mut typed_fn_env = TypedFnEnv::default();
-
                     // if we're expecting a library, this should not be the main function
-                    let is_main = function.is_main();
-                    if is_main && is_lib {
+                    if function.is_main() && is_lib {
                         return Err(Error::new(
                             "type-checker",
                             ErrorKind::MainFunctionInLib,
@@ -291,15 +588,6 @@ impl<B: Backend> TypeChecker<B> {
                         ));
                     }
 
-                    // if this is the main function check that it has arguments
-                    if is_main && function.sig.arguments.is_empty() {
-                        return Err(Error::new(
-                            "type-checker",
-                            ErrorKind::NoArgsInMain,
-                            function.span,
-                        ));
-                    }
-
                     // save the function in the typed global env
                     let fn_kind = FnKind::Native(function.clone());
                     let fn_info = FnInfo {
@@ -320,98 +608,338 @@ impl<B: Backend> TypeChecker<B> {
                                 .get_mut(&qualified)
                                 .expect("couldn't find the struct for storing the method");
 
-                            struct_info
+                            let method = with_self_sizes(function.clone(), struct_info);
+                            if struct_info
                                 .methods
-                                .insert(function.sig.name.value.clone(), function.clone());
+                                .insert(function.sig.name.value.clone(), method.clone())
+                                .is_some()
+                            {
+                                return Err(Error::new(
+                                    "type-checker",
+                                    ErrorKind::DuplicateDefinition(function.sig.name.value.clone()),
+                                    function.sig.name.span,
+                                ));
+                            }
+
+                            self.check_fn_def(&method)?;
                         }
                         FuncOrMethod::Function(module) => {
                             let qualified = FullyQualified::new(module, &function.sig.name.value);
                             self.functions.insert(qualified, fn_info);
-                        }
-                    };
 
-                    // store variables and their types in the fn_env
-                    for arg in &function.sig.arguments {
-                        // public_output is a reserved name,
-                        // associated automatically to the public output of the main function
-                        if RESERVED_ARGS.contains(&arg.name.value.as_str()) {
-                            return Err(Error::new(
-                                "type-checker",
-                                ErrorKind::PublicOutputReserved(arg.name.value.to_string()),
-                                arg.name.span,
-                            ));
-                        }
-
-                        // `pub` arguments are only for the main function
-                        if !is_main && arg.is_public() {
-                            return Err(Error::new(
-                                "type-checker",
-                                ErrorKind::PubArgumentOutsideMain,
-                                arg.attribute.as_ref().unwrap().span,
-                            ));
-                        }
-
-                        // `const` arguments are only for non-main functions
-                        if is_main && arg.is_constant() {
-                            return Err(Error::new(
-                                "type-checker",
-                                ErrorKind::ConstArgumentNotForMain,
-                                arg.name.span,
-                            ));
+                            self.check_fn_def(function)?;
                         }
+                    };
+                }
 
-                        // store the args' type in the fn environment
-                        let arg_typ = arg.typ.kind.clone();
-
-                        if arg.is_constant() {
-                            typed_fn_env.store_type(
-                                arg.name.value.clone(),
-                                TypeInfo::new_cst(arg_typ, arg.span),
-                            )?;
-                        } else {
-                            typed_fn_env.store_type(
-                                arg.name.value.clone(),
-                                TypeInfo::new(arg_typ, arg.span),
-                            )?;
-                        }
-                    }
+                // `impl Hashable for Foo { ... }`
+                // (its methods were registered along with the implementation)
+                RootKind::ImplDef(impl_def) => {
+                    let qualified =
+                        FullyQualified::new(&impl_def.custom.module, &impl_def.custom.name);
+                    let methods = &self.structs[&qualified].methods;
+                    let methods: Vec<_> = impl_def
+                        .methods
+                        .iter()
+                        .map(|method| methods[&method.sig.name.value].clone())
+                        .collect();
 
-                    // the output value returned by the main function is also a main_args with a special name (public_output)
-                    if let Some(typ) = &function.sig.return_type {
-                        if is_main {
-                            match typ.kind {
-                                TyKind::Field => {
-                                    typed_fn_env.store_type(
-                                        "public_output".to_string(),
-                                        TypeInfo::new_mut(typ.kind.clone(), typ.span),
-                                    )?;
-                                }
-                                TyKind::Array(_, _) => {
-                                    typed_fn_env.store_type(
-                                        "public_output".to_string(),
-                                        TypeInfo::new_mut(typ.kind.clone(), typ.span),
-                                    )?;
-                                }
-                                _ => unimplemented!(),
-                            }
-                        }
+                    for method in &methods {
+                        self.check_fn_def(method)?;
                     }
-
-                    // type system pass on the function body
-                    self.check_block(
-                        &mut typed_fn_env,
-                        &function.body,
-                        function.sig.return_type.as_ref(),
-                    )?;
                 }
 
                 RootKind::Use(_)
                 | RootKind::ConstDef(_)
                 | RootKind::StructDef(_)
+                | RootKind::EnumDef(_)
+                | RootKind::TraitDef(_)
+                | RootKind::TypeAliasDef(_)
                 | RootKind::Comment(_) => (),
             };
         }
 
         Ok(())
     }
+
+    /// Makes sure that the structs appearing in a type are given as many sizes
+    /// as they have generic array sizes (e.g. `Sudoku<81>` for `struct Sudoku { inner: [Field; N] }`).
+    fn check_struct_sizes(&self, typ: &TyKind, span: Span) -> Result<()> {
+        match typ {
+            TyKind::Custom {
+                module,
+                name,
+                sizes,
+            } => {
+                let expected = self
+                    .struct_info(&FullyQualified::new(module, name))
+                    .map(|struct_info| struct_info.generics.len())
+                    .unwrap_or(0);
+
+                if sizes.len() != expected {
+                    return Err(Error::new(
+                        "type-checker",
+                        ErrorKind::MismatchStructSizes(name.clone(), expected, sizes.len()),
+                        span,
+                    ));
+                }
+
+                Ok(())
+            }
+            TyKind::Array(typ, _) | TyKind::GenericSizedArray(typ, _) => {
+                self.check_struct_sizes(typ, span)
+            }
+            TyKind::Tuple(typs) => {
+                for typ in typs {
+                    self.check_struct_sizes(typ, span)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Type checks the signature and the body of a function or method.
+    fn check_fn_def(&mut self, function: &FunctionDef) -> Result<()> {
+        // create a new typed fn environment to type check the function
+        let mut typed_fn_env = if function.sig.hint {
+            TypedFnEnv::new_hint()
+        } else {
+            TypedFnEnv::default()
+        };
+
+        // if this is the main function check that it has arguments
+        let is_main = function.is_main();
+        if is_main && function.sig.arguments.is_empty() {
+            return Err(Error::new(
+                "type-checker",
+                ErrorKind::NoArgsInMain,
+                function.span,
+            ));
+        }
+
+        // a hint function computes values out of circuit for its caller to constrain
+        if function.sig.hint {
+            if is_main {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::HintInMain,
+                    function.sig.name.span,
+                ));
+            }
+
+            if function.sig.return_type.is_none() {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::HintWithoutReturn,
+                    function.sig.name.span,
+                ));
+            }
+
+            if let Some(arg) = function.sig.arguments.iter().find(|arg| arg.is_mutable()) {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::MutableArgInHint(arg.name.value.clone()),
+                    arg.name.span,
+                ));
+            }
+        }
+
+        // store variables and their types in the fn_env
+        for arg in &function.sig.arguments {
+            // public_output is a reserved name,
+            // associated automatically to the public output of the main function
+            if RESERVED_ARGS.contains(&arg.name.value.as_str()) {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::PublicOutputReserved(arg.name.value.to_string()),
+                    arg.name.span,
+                ));
+            }
+
+            // `pub` arguments are only for the main function
+            if !is_main && arg.is_public() {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::PubArgumentOutsideMain,
+                    arg.attribute.as_ref().unwrap().span,
+                ));
+            }
+
+            // `const` arguments are only for non-main functions
+            if is_main && arg.is_constant() {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::ConstArgumentNotForMain,
+                    arg.name.span,
+                ));
+            }
+
+            self.check_struct_sizes(&arg.typ.kind, arg.typ.span)?;
+
+            // store the args' type in the fn environment
+            let arg_typ = arg.typ.kind.clone();
+
+            if arg.is_constant() {
+                typed_fn_env
+                    .store_type(arg.name.value.clone(), TypeInfo::new_cst(arg_typ, arg.span))?;
+            } else if arg.is_mutable() {
+                typed_fn_env
+                    .store_type(arg.name.value.clone(), TypeInfo::new_mut(arg_typ, arg.span))?;
+            } else {
+                typed_fn_env
+                    .store_type(arg.name.value.clone(), TypeInfo::new(arg_typ, arg.span))?;
+            }
+        }
+
+        // generic array sizes (e.g. `N` in `[Field; N]`) are inferred from the arguments,
+        // and can be used as constants in the function body
+        let mut generics: Vec<String> = vec![];
+        for arg in &function.sig.arguments {
+            for generic in arg.typ.kind.generic_sizes() {
+                if is_main {
+                    return Err(Error::new(
+                        "type-checker",
+                        ErrorKind::GenericInMain,
+                        arg.typ.span,
+                    ));
+                }
+
+                if !generics.contains(&generic) {
+                    typed_fn_env.store_type(
+                        generic.clone(),
+                        TypeInfo::new_cst(TyKind::Field, arg.typ.span),
+                    )?;
+                    generics.push(generic);
+                }
+            }
+        }
+
+        if let Some(typ) = &function.sig.return_type {
+            self.check_struct_sizes(&typ.kind, typ.span)?;
+
+            for generic in typ.kind.generic_sizes() {
+                if !generics.contains(&generic) {
+                    return Err(Error::new(
+                        "type-checker",
+                        ErrorKind::UndefinedGeneric(generic),
+                        typ.span,
+                    ));
+                }
+            }
+        }
+
+        // generic types (e.g. `T` in `fn digest<T: Hashable>(value: T)`) are inferred from the arguments,
+        // and can only be used through the methods of the traits they are bound to
+        for type_param in &function.sig.type_params {
+            if is_main {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::GenericInMain,
+                    type_param.name.span,
+                ));
+            }
+
+            let bound = FullyQualified::new(&type_param.bound.module, &type_param.bound.name);
+            if !self.traits.contains_key(&bound) {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::UndefinedTrait(type_param.bound.name.clone()),
+                    type_param.bound.span,
+                ));
+            }
+
+            let inferable = function.sig.arguments.iter().any(|arg| {
+                arg.typ
+                    .kind
+                    .generic_types()
+                    .contains(&type_param.name.value)
+            });
+            if !inferable {
+                return Err(Error::new(
+                    "type-checker",
+                    ErrorKind::UndefinedGeneric(type_param.name.value.clone()),
+                    type_param.name.span,
+                ));
+            }
+        }
+
+        // the output value returned by the main function is also a main_args with a special name (public_output)
+        if let Some(typ) = &function.sig.return_type {
+            if is_main {
+                match typ.kind {
+                    TyKind::Field => {
+                        typed_fn_env.store_type(
+                            "public_output".to_string(),
+                            TypeInfo::new_mut(typ.kind.clone(), typ.span),
+                        )?;
+                    }
+                    TyKind::Array(_, _) | TyKind::U8 | TyKind::U16 | TyKind::U32 | TyKind::U64 => {
+                        typed_fn_env.store_type(
+                            "public_output".to_string(),
+                            TypeInfo::new_mut(typ.kind.clone(), typ.span),
+                        )?;
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            "type-checker",
+                            ErrorKind::InvalidMainReturnType(typ.kind.clone()),
+                            typ.span,
+                        ));
+                    }
+                }
+            }
+        }
+
+        // type system pass on the function body
+        self.check_block(
+            &mut typed_fn_env,
+            &function.body,
+            function.sig.return_type.as_ref(),
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Gives the `self` argument of a method the generic array sizes of its struct
+/// (e.g. `self: Sudoku<N>` for `struct Sudoku { inner: [Field; N] }`),
+/// so that they are inferred from the struct the method is called on.
+fn with_self_sizes(mut method: FunctionDef, struct_info: &StructInfo) -> FunctionDef {
+    for arg in &mut method.sig.arguments {
+        if let (true, TyKind::Custom { sizes, .. }) = (arg.name.value == "self", &mut arg.typ.kind)
+        {
+            *sizes = struct_info
+                .generics
+                .iter()
+                .cloned()
+                .map(ArraySize::Generic)
+                .collect();
+        }
+    }
+
+    method
+}
+
+/// Returns whether the signature of a method matches the one declared in a trait
+/// (the type of `self` aside).
+fn same_method_sig(expected: &FnSig, observed: &FnSig) -> bool {
+    let same_args = expected.arguments.len() == observed.arguments.len()
+        && expected
+            .arguments
+            .iter()
+            .zip(&observed.arguments)
+            .all(|(expected, observed)| {
+                expected.is_mutable() == observed.is_mutable()
+                    && expected.is_constant() == observed.is_constant()
+                    && (expected.name.value == "self" || expected.typ.kind == observed.typ.kind)
+            });
+
+    let same_return_type = match (&expected.return_type, &observed.return_type) {
+        (Some(expected), Some(observed)) => expected.kind == observed.kind,
+        (None, None) => true,
+        _ => false,
+    };
+
+    same_args && same_return_type
 }
@@ -9,7 +9,7 @@ pub fn display_source(
     sources: &crate::compiler::Sources,
     debug_infos: &[crate::circuit_writer::DebugInfo],
 ) {
-    for crate::circuit_writer::DebugInfo { span, note: _ } in debug_infos {
+    for crate::circuit_writer::DebugInfo { span, .. } in debug_infos {
         // find filename and source
         let (file, source) = sources.get(&span.filename_id).expect("source not found");
 
@@ -41,7 +41,7 @@ pub fn display_source(
     res.push('\n');
 }
 
-fn find_exact_line(source: &str, span: crate::constants::Span) -> (usize, usize, &str) {
+pub(crate) fn find_exact_line(source: &str, span: crate::constants::Span) -> (usize, usize, &str) {
     let ss = source.as_bytes();
     let mut start = span.start;
     let mut end = span.end();
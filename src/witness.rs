@@ -21,6 +21,10 @@ where
     pub var_values: HashMap<String, Vec<F>>,
 
     pub cached_values: HashMap<usize, F>,
+
+    /// The values returned by each hint call (indexed by the id of the call),
+    /// as a hint is run only once for all the cells it returns.
+    pub hint_values: HashMap<usize, Vec<F>>,
 }
 
 impl<F: Field> WitnessEnv<F> {
@@ -57,9 +61,29 @@ impl<B: Backend> CompiledCircuit<B> {
     }
 
     pub fn generate_witness(
+        &self,
+        public_inputs: JsonInputs,
+        private_inputs: JsonInputs,
+    ) -> Result<B::GeneratedWitness> {
+        self.generate_witness_inner(public_inputs, private_inputs, None)
+    }
+
+    /// Generates the witness, and prints the values passed to `log`
+    /// along with their location in the given sources.
+    pub fn generate_witness_with_logs(
+        &self,
+        public_inputs: JsonInputs,
+        private_inputs: JsonInputs,
+        sources: &Sources,
+    ) -> Result<B::GeneratedWitness> {
+        self.generate_witness_inner(public_inputs, private_inputs, Some(sources))
+    }
+
+    fn generate_witness_inner(
         &self,
         mut public_inputs: JsonInputs,
         mut private_inputs: JsonInputs,
+        logs: Option<&Sources>,
     ) -> Result<B::GeneratedWitness> {
         let mut env = WitnessEnv::default();
 
@@ -108,6 +132,6 @@ impl<B: Backend> CompiledCircuit<B> {
             ));
         }
 
-        self.circuit.generate_witness(&mut env)
+        self.circuit.generate_witness(&mut env, logs)
     }
 }